@@ -1,5 +1,5 @@
 use near_sdk::require;
-use near_sdk::{env, near, Promise, PromiseError};
+use near_sdk::{env, near, AccountId, NearToken, Promise, PromiseError};
 
 const A_VALUE: u8 = 8;
 
@@ -18,6 +18,14 @@ impl Callback {
             .then(Self::ext(env::current_account_id()).handle_callbacks())
     }
 
+    /// Transfers `amount` to every receiver and counts the successful transfers in a single
+    /// `handle_transfers` callback.
+    pub fn transfer_all(receivers: Vec<AccountId>, amount: NearToken) -> Promise {
+        let transfers =
+            receivers.into_iter().map(|receiver| Promise::new(receiver).transfer(amount));
+        Promise::join(transfers).then(Self::ext(env::current_account_id()).handle_transfers())
+    }
+
     /// Calls function c with a value that will always succeed
     pub fn a() -> Promise {
         Self::ext(env::current_account_id()).c(A_VALUE)
@@ -59,10 +67,78 @@ impl Callback {
         }
         (b.is_err(), c.is_err(), d.is_err())
     }
+
+    /// Receives the results of all the transfers made in `transfer_all`.
+    #[private]
+    pub fn handle_transfers() -> u64 {
+        (0..env::promise_results_count()).filter(|&i| env::promise_result(i).ok().is_some()).count()
+            as u64
+    }
 }
 
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
+    use super::*;
+    use near_sdk::serde::de::DeserializeOwned;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{serde_json, test_vm_config, testing_env, PromiseResult, RuntimeFeesConfig};
+
+    /// Reads the promise result at `idx` the same way the `#[callback_result]` wrapper does.
+    fn callback_result<T: DeserializeOwned>(idx: u64) -> Result<T, PromiseError> {
+        match env::promise_result(idx) {
+            PromiseResult::Successful(data) if data.is_empty() => {
+                Ok(serde_json::from_slice(b"null").unwrap())
+            }
+            PromiseResult::Successful(data) => Ok(serde_json::from_slice(&data).unwrap()),
+            PromiseResult::Failed => Err(PromiseError::Failed),
+        }
+    }
+
+    fn handle_callbacks_with(results: Vec<PromiseResult>) -> (bool, bool, bool) {
+        testing_env!(
+            VMContextBuilder::new().build(),
+            test_vm_config(),
+            RuntimeFeesConfig::test(),
+            Default::default(),
+            results,
+        );
+        assert_eq!(env::promise_results_count(), 4);
+
+        Callback::handle_callbacks(
+            callback_result(0).unwrap(),
+            callback_result(1),
+            callback_result(2),
+            callback_result(3),
+        )
+    }
+
+    fn ok<T: near_sdk::serde::Serialize>(value: T) -> PromiseResult {
+        PromiseResult::Successful(serde_json::to_vec(&value).unwrap())
+    }
+
+    #[test]
+    fn handle_callbacks_unit() {
+        let unit = || PromiseResult::Successful(vec![]);
+
+        assert_eq!(
+            handle_callbacks_with(vec![ok(A_VALUE), ok("Some string"), ok(1u8), unit()]),
+            (false, false, false)
+        );
+        assert_eq!(
+            handle_callbacks_with(vec![ok(A_VALUE), PromiseResult::Failed, ok(1u8), unit()]),
+            (true, false, false)
+        );
+        assert_eq!(
+            handle_callbacks_with(vec![
+                ok(A_VALUE),
+                ok("Some string"),
+                PromiseResult::Failed,
+                PromiseResult::Failed
+            ]),
+            (false, true, true)
+        );
+    }
+
     #[tokio::test]
     async fn workspaces_test() -> anyhow::Result<()> {
         let wasm = near_workspaces::compile_project("./").await?;
@@ -120,4 +196,51 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn workspaces_test_transfer_all() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let mut receivers = Vec::new();
+        for i in 0..5 {
+            let receiver = contract
+                .as_account()
+                .create_subaccount(&format!("receiver{i}"))
+                .initial_balance(NearToken::from_near(1))
+                .transact()
+                .await?
+                .into_result()?;
+            receivers.push(receiver);
+        }
+        let amount = NearToken::from_millinear(100);
+
+        let res = contract
+            .call("transfer_all")
+            .args_json((receivers.iter().map(|r| r.id()).collect::<Vec<_>>(), amount))
+            .max_gas()
+            .transact()
+            .await?;
+        assert_eq!(res.json::<u64>()?, 5);
+        for receiver in &receivers {
+            assert_eq!(
+                receiver.view_account().await?.balance,
+                NearToken::from_near(1).saturating_add(amount)
+            );
+        }
+
+        // A transfer to an account that doesn't exist fails, the callback still runs once.
+        let mut receiver_ids: Vec<String> = receivers.iter().map(|r| r.id().to_string()).collect();
+        receiver_ids.push(format!("missing.{}", contract.id()));
+        let res = contract
+            .call("transfer_all")
+            .args_json((receiver_ids, amount))
+            .max_gas()
+            .transact()
+            .await?;
+        assert_eq!(res.json::<u64>()?, 5);
+
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
 use near_sdk::serde_json;
-use near_sdk::{env, near, require, Gas, NearToken, PromiseResult};
+use near_sdk::{env, near, require, Gas, NearToken};
 
 // Prepaid gas for a single (not inclusive of recursion) `factorial` call.
 const FACTORIAL_CALL_GAS: Gas = Gas::from_tgas(20);
@@ -43,10 +43,10 @@ impl CrossContract {
     pub fn factorial_mult(&self, n: u32) {
         require!(env::current_account_id() == env::predecessor_account_id());
         require!(env::promise_results_count() == 1);
-        let cur = match env::promise_result(0) {
-            PromiseResult::Successful(x) => serde_json::from_slice::<u32>(&x).unwrap(),
-            _ => env::panic_str("Promise with index 0 failed"),
-        };
+        let cur = env::promise_result(0)
+            .ok()
+            .map(|x| serde_json::from_slice::<u32>(&x).unwrap())
+            .unwrap_or_else(|| env::panic_str("Promise with index 0 failed"));
         env::value_return(&serde_json::to_vec(&(cur * n)).unwrap());
     }
 }
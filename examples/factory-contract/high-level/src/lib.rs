@@ -1,5 +1,5 @@
 use near_sdk::PromiseError;
-use near_sdk::{env, ext_contract, near, AccountId, NearToken, Promise};
+use near_sdk::{env, ext_contract, near, AccountId, Gas, NearToken, Promise};
 
 #[derive(Default)]
 #[near(contract_state)]
@@ -25,6 +25,27 @@ impl FactoryContract {
             );
     }
 
+    pub fn deploy_status_message_with_status(
+        &self,
+        account_id: AccountId,
+        amount: NearToken,
+        message: String,
+    ) {
+        // The status is set by the same receipt that deploys the contract.
+        Promise::new(account_id)
+            .create_account()
+            .transfer(amount)
+            .add_full_access_key(env::signer_account_pk())
+            .deploy_contract_and_init(
+                include_bytes!(env!("BUILD_RS_SUB_BUILD_STATUS-MESSAGE")).to_vec(),
+                "set_status".to_string(),
+                near_sdk::serde_json::to_vec(&near_sdk::serde_json::json!({ "message": message }))
+                    .unwrap(),
+                NearToken::from_near(0),
+                Gas::from_tgas(20),
+            );
+    }
+
     pub fn simple_call(&mut self, account_id: AccountId, message: String) {
         ext_status_message::ext(account_id).set_status(message);
     }
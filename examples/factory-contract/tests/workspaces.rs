@@ -30,3 +30,73 @@ async fn test_deploy_status_message(contract_path: &str) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_deploy_status_message_with_status() -> anyhow::Result<()> {
+    let wasm = near_workspaces::compile_project("./high-level").await?;
+    let worker = near_workspaces::sandbox().await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+
+    let status_id: AccountId = format!("status.{}", contract.id()).parse()?;
+    let message = "hello world from deploy";
+    let res = contract
+        .call("deploy_status_message_with_status")
+        .args_json((&status_id, NearToken::from_near(20), message))
+        .max_gas()
+        .deposit(NearToken::from_near(50))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    // The account creation, deployment and initialization happen in a single receipt.
+    assert_eq!(res.receipt_outcomes().iter().filter(|o| o.executor_id == status_id).count(), 1);
+
+    let status = worker
+        .view(&status_id, "get_status")
+        .args_json((contract.id(),))
+        .await?
+        .json::<Option<String>>()?;
+    assert_eq!(status.as_deref(), Some(message));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_result_promise() -> anyhow::Result<()> {
+    let wasm = near_workspaces::compile_project("./high-level").await?;
+    let worker = near_workspaces::sandbox().await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+
+    // `Ok(promise)`: the promise is scheduled and its result is returned by the call.
+    let status_id: AccountId = format!("status.{}", contract.id()).parse()?;
+    let res = contract
+        .call("deploy_status_message")
+        .args_json((&status_id, NearToken::from_near(20)))
+        .max_gas()
+        .deposit(NearToken::from_near(50))
+        .transact()
+        .await?;
+    assert!(res.is_success());
+
+    let res = contract
+        .call("complex_call")
+        .args_json((&status_id, "ok arm"))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(res.is_success());
+    assert!(res.receipt_outcomes().iter().any(|o| o.executor_id == status_id && o.is_success()));
+    assert_eq!(res.json::<Option<String>>()?.as_deref(), Some("ok arm"));
+
+    // `Err(e)`: setting the status on a missing account fails and the callback panics with `e`.
+    let missing_id: AccountId = format!("missing.{}", contract.id()).parse()?;
+    let res = contract
+        .call("complex_call")
+        .args_json((&missing_id, "err arm"))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(res.is_failure());
+    assert!(format!("{:?}", res.into_result().unwrap_err()).contains("Failed to set status"));
+
+    Ok(())
+}
@@ -42,10 +42,7 @@ impl FungibleTokenReceiver for DeFi {
         msg: String,
     ) -> PromiseOrValue<U128> {
         // Verifying that we were called by fungible token contract that we expect.
-        require!(
-            env::predecessor_account_id() == self.fungible_token_account_id,
-            "Only supports the one fungible token contract"
-        );
+        env::assert_predecessor(&self.fungible_token_account_id);
         log!("in {} tokens from @{} ft_on_transfer, msg = {}", amount.0, sender_id, msg);
         match msg.as_str() {
             "take-my-money" => PromiseOrValue::Value(U128::from(0)),
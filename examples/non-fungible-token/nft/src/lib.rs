@@ -105,6 +105,11 @@ impl Contract {
         assert_eq!(env::predecessor_account_id(), self.tokens.owner_id, "Unauthorized");
         self.tokens.internal_mint(token_id, token_owner_id, Some(token_metadata))
     }
+
+    /// Lists all accounts approved for `token_id` with their approval IDs.
+    pub fn nft_approvals(&self, token_id: TokenId) -> Vec<(AccountId, u64)> {
+        self.tokens.nft_approvals(token_id)
+    }
 }
 
 #[near]
@@ -435,4 +440,47 @@ mod tests {
             .build());
         assert!(!contract.nft_is_approved(token_id.clone(), accounts(1), Some(1)));
     }
+
+    #[test]
+    fn test_nft_approvals() {
+        let mut context = get_context(accounts(0));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(0).into());
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(MINT_STORAGE_COST)
+            .predecessor_account_id(accounts(0))
+            .build());
+        let token_id = "0".to_string();
+        contract.nft_mint(token_id.clone(), accounts(0), sample_token_metadata());
+        assert_eq!(contract.nft_approvals(token_id.clone()), vec![]);
+
+        // alice approves bob and charlie, then re-approves bob
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(NearToken::from_millinear(1))
+            .predecessor_account_id(accounts(0))
+            .build());
+        contract.nft_approve(token_id.clone(), accounts(1), None);
+        contract.nft_approve(token_id.clone(), accounts(2), None);
+        contract.nft_approve(token_id.clone(), accounts(1), None);
+        assert_eq!(
+            contract.nft_approvals(token_id.clone()),
+            vec![(accounts(2), 2), (accounts(1), 3)]
+        );
+
+        // alice revokes bob
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(NearToken::from_yoctonear(1))
+            .predecessor_account_id(accounts(0))
+            .build());
+        contract.nft_revoke(token_id.clone(), accounts(1));
+        assert_eq!(contract.nft_approvals(token_id.clone()), vec![(accounts(2), 2)]);
+
+        // alice revokes everyone
+        contract.nft_revoke_all(token_id.clone());
+        assert_eq!(contract.nft_approvals(token_id.clone()), vec![]);
+    }
 }
@@ -3,7 +3,7 @@ A stub contract that implements nft_on_approve for e2e testing nft_approve.
 */
 use near_contract_standards::non_fungible_token::approval::NonFungibleTokenApprovalReceiver;
 use near_contract_standards::non_fungible_token::TokenId;
-use near_sdk::{env, log, near, require, AccountId, Gas, PanicOnDefault, PromiseOrValue};
+use near_sdk::{env, log, near, AccountId, Gas, PanicOnDefault, PromiseOrValue};
 
 /// It is estimated that we need to attach 5 TGas for the code execution and 5 TGas for cross-contract call
 const GAS_FOR_NFT_ON_APPROVE: Gas = Gas::from_tgas(10);
@@ -43,10 +43,7 @@ impl NonFungibleTokenApprovalReceiver for ApprovalReceiver {
         msg: String,
     ) -> PromiseOrValue<String> {
         // Verifying that we were called by non-fungible token contract that we expect.
-        require!(
-            env::predecessor_account_id() == self.non_fungible_token_account_id,
-            "Only supports the one non-fungible token contract"
-        );
+        env::assert_predecessor(&self.non_fungible_token_account_id);
         log!(
             "in nft_on_approve; sender_id={}, previous_owner_id={}, token_id={}, msg={}",
             &token_id,
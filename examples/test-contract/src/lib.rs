@@ -1,8 +1,15 @@
-use near_sdk::{env, near};
+use near_sdk::json_types::U64;
+use near_sdk::{env, log, near, Gas, GasWeight, NearToken, Promise};
 
 #[near(contract_state)]
 pub struct TestContract {}
 
+/// State shape of an earlier version of the contract, used to test `#[near(migrate)]`.
+#[near]
+pub struct LegacyTestContract {
+    greeting: String,
+}
+
 impl Default for TestContract {
     fn default() -> Self {
         Self {}
@@ -28,9 +35,68 @@ impl TestContract {
         Self {}
     }
 
+    #[near(migrate(from = LegacyTestContract))]
+    pub fn migrate_from_legacy(old: LegacyTestContract) -> Self {
+        log!("Dropped greeting: {}", old.greeting);
+        Self {}
+    }
+
     pub fn test_panic_macro(&mut self) {
         panic!("PANIC!");
     }
+
+    #[max_input_bytes(64)]
+    pub fn bounded_echo(&self, value: String) -> String {
+        value
+    }
+
+    #[payable(min = "NearToken::from_millinear(1)")]
+    pub fn pay_at_least_millinear(&mut self) {}
+
+    #[deprecated_method(use = "bounded_echo")]
+    pub fn echo(&self, value: String) -> String {
+        self.bounded_echo(value)
+    }
+
+    #[near(deprecated_log = "use `bounded_echo` instead")]
+    pub fn shout(&self, value: String) -> String {
+        self.bounded_echo(value.to_uppercase())
+    }
+
+    pub fn greet(&self, name: String) -> String {
+        self.shout(format!("hello, {}", name))
+    }
+
+    /// Calls `prepaid_gas` with a resolver that doesn't take any of the unused gas.
+    pub fn chain_unweighted_resolver(&self) -> Promise {
+        let call = |gas| {
+            Promise::new(env::current_account_id()).function_call_weight(
+                "prepaid_gas".to_string(),
+                vec![],
+                NearToken::from_near(0),
+                gas,
+                GasWeight::default(),
+            )
+        };
+        call(Gas::from_tgas(5)).then_weighted(call(Gas::from_tgas(7)), GasWeight(0))
+    }
+
+    #[private]
+    pub fn prepaid_gas(&self) -> U64 {
+        env::prepaid_gas().as_gas().into()
+    }
+}
+
+#[near(deny_unknown_arguments)]
+impl TestContract {
+    pub fn strict_echo(&self, value: u32) -> u32 {
+        value
+    }
+
+    #[near(deny_unknown_arguments = false)]
+    pub fn lenient_echo(&self, value: u32) -> u32 {
+        value
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -38,7 +104,7 @@ impl TestContract {
 mod tests {
     use super::*;
     use near_abi::AbiRoot;
-    use near_sdk::serde_json;
+    use near_sdk::{borsh, serde_json};
 
     #[test]
     #[should_panic(expected = "PANIC!")]
@@ -47,6 +113,165 @@ mod tests {
         contract.test_panic_macro();
     }
 
+    #[tokio::test]
+    async fn init_ignore_state_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        // `migrate_state` reads the previous state, so it fails before any initialization.
+        assert!(contract.call("migrate_state").transact().await?.is_failure());
+        assert!(contract.call("new").transact().await?.is_success());
+
+        // A plain `#[init]` can't run over existing state, `#[init(ignore_state)]` can.
+        let res = contract.call("new").transact().await?;
+        assert!(format!("{:?}", res.into_result().unwrap_err())
+            .contains("The contract has already been initialized"));
+        assert!(contract.call("migrate_state").transact().await?.is_success());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn migrate_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        // Missing old state is reported instead of falling back to a default.
+        let res = contract.call("migrate_from_legacy").transact().await?;
+        assert!(format!("{:?}", res.into_result().unwrap_err())
+            .contains("Cannot migrate: the contract has no state to migrate from"));
+
+        let legacy = LegacyTestContract { greeting: "hello".to_string() };
+        worker.patch_state(contract.id(), b"STATE", &borsh::to_vec(&legacy)?).await?;
+
+        // Only the contract itself can run the migration.
+        let user = worker.dev_create_account().await?;
+        let res = user.call(contract.id(), "migrate_from_legacy").transact().await?;
+        assert!(format!("{:?}", res.into_result().unwrap_err())
+            .contains("Method migrate_from_legacy is private"));
+
+        let res = contract.call("migrate_from_legacy").transact().await?.into_result()?;
+        assert_eq!(res.logs(), vec!["Dropped greeting: hello"]);
+        let state = contract.view_state().prefix(b"STATE").await?;
+        assert_eq!(state.get(b"STATE".as_slice()).map(Vec::as_slice), Some(&[][..]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deny_unknown_arguments_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let args = serde_json::json!({ "value": 7 });
+        let unknown_args = serde_json::json!({ "value": 7, "extra": true });
+
+        let res = contract.view("strict_echo").args_json(&args).await?;
+        assert_eq!(res.json::<u32>()?, 7);
+        let res = contract.view("strict_echo").args_json(&unknown_args).await;
+        assert!(format!("{:?}", res.unwrap_err()).contains("Failed to deserialize input from JSON"));
+
+        let res = contract.view("lenient_echo").args_json(&unknown_args).await?;
+        assert_eq!(res.json::<u32>()?, 7);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_input_bytes_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let res =
+            contract.view("bounded_echo").args_json(serde_json::json!({ "value": "hi" })).await?;
+        assert_eq!(res.json::<String>()?, "hi");
+
+        let oversized = serde_json::json!({ "value": "a".repeat(64) });
+        let res = contract.view("bounded_echo").args_json(oversized).await;
+        assert!(format!("{:?}", res.unwrap_err())
+            .contains("Input exceeds the maximum size of 64 bytes"));
+
+        // Malformed input is rejected by the size check before it is deserialized.
+        let res = contract.view("bounded_echo").args(vec![b'{'; 65]).await;
+        assert!(format!("{:?}", res.unwrap_err())
+            .contains("Input exceeds the maximum size of 64 bytes"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn payable_min_deposit_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let call = |deposit| contract.call("pay_at_least_millinear").deposit(deposit).transact();
+
+        let res = call(NearToken::from_yoctonear(0)).await?;
+        assert!(format!("{:?}", res.into_result().unwrap_err()).contains(
+            "Method pay_at_least_millinear requires an attached deposit of at least \
+             1000000000000000000000 yoctoNEAR"
+        ));
+        let res = call(NearToken::from_millinear(1).saturating_sub(NearToken::from_yoctonear(1)));
+        assert!(res.await?.is_failure());
+
+        assert!(call(NearToken::from_millinear(1)).await?.is_success());
+        assert!(call(NearToken::from_near(1)).await?.is_success());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deprecated_method_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let args = serde_json::json!({ "value": "hi" });
+        let res = contract.view("echo").args_json(&args).await?;
+        assert_eq!(res.logs, vec!["DEPRECATED: call `bounded_echo` instead"]);
+        assert_eq!(res.json::<String>()?, "hi");
+
+        let res = contract.view("bounded_echo").args_json(&args).await?;
+        assert!(res.logs.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deprecated_log_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let res = contract.view("shout").args_json(serde_json::json!({ "value": "hi" })).await?;
+        assert_eq!(res.logs, vec!["DEPRECATED: use `bounded_echo` instead"]);
+        assert_eq!(res.json::<String>()?, "HI");
+
+        // Only calls to the exported function log the notice.
+        let res = contract.view("greet").args_json(serde_json::json!({ "name": "bob" })).await?;
+        assert!(res.logs.is_empty());
+        assert_eq!(res.json::<String>()?, "HELLO, BOB");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn then_weighted_test() -> anyhow::Result<()> {
+        let wasm = near_workspaces::compile_project("./").await?;
+        let worker = near_workspaces::sandbox().await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let res = contract.call("chain_unweighted_resolver").max_gas().transact().await?;
+        assert_eq!(res.json::<U64>()?, U64(Gas::from_tgas(7).as_gas()));
+
+        Ok(())
+    }
+
     // this only tests that contract can be built with ABI and responds to __contract_abi
     // view call
     #[tokio::test]
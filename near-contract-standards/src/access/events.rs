@@ -0,0 +1,115 @@
+//! Events emitted when an [`AllowList`](super::AllowList) or [`DenyList`](super::DenyList)
+//! is modified.
+//!
+//! There is no NEP for access lists, so these events use the custom `access_list` standard
+//! on top of the events format (nep-297):
+//! <https://github.com/near/NEPs/blob/master/specs/Standards/EventsFormat.md>
+//!
+//! The two events are [`AccessListAdd`] and [`AccessListRemove`].
+
+use crate::event::NearEvent;
+use near_sdk::serde::Serialize;
+use near_sdk::AccountIdRef;
+
+/// Data to log when an account is added to an access list. To log this event,
+/// call [`.emit()`](AccessListAdd::emit).
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccessListAdd<'a> {
+    pub list: &'static str,
+    pub account_id: &'a AccountIdRef,
+}
+
+impl AccessListAdd<'_> {
+    /// Logs the event to the host. This is required to ensure that the event is triggered
+    /// and to consume the event.
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    /// Emits an access list add event, through [`env::log_str`](near_sdk::env::log_str),
+    /// where each [`AccessListAdd`] represents the data of each added account.
+    pub fn emit_many(data: &[AccessListAdd<'_>]) {
+        new_access_list_v1(AccessListEventKind::AccessListAdd(data)).emit()
+    }
+}
+
+/// Data to log when an account is removed from an access list. To log this event,
+/// call [`.emit()`](AccessListRemove::emit).
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccessListRemove<'a> {
+    pub list: &'static str,
+    pub account_id: &'a AccountIdRef,
+}
+
+impl AccessListRemove<'_> {
+    /// Logs the event to the host. This is required to ensure that the event is triggered
+    /// and to consume the event.
+    pub fn emit(self) {
+        Self::emit_many(&[self])
+    }
+
+    /// Emits an access list remove event, through [`env::log_str`](near_sdk::env::log_str),
+    /// where each [`AccessListRemove`] represents the data of each removed account.
+    pub fn emit_many(data: &[AccessListRemove<'_>]) {
+        new_access_list_v1(AccessListEventKind::AccessListRemove(data)).emit()
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub(crate) struct AccessListEvent<'a> {
+    version: &'static str,
+    #[serde(flatten)]
+    event_kind: AccessListEventKind<'a>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+enum AccessListEventKind<'a> {
+    AccessListAdd(&'a [AccessListAdd<'a>]),
+    AccessListRemove(&'a [AccessListRemove<'a>]),
+}
+
+fn new_access_list<'a>(
+    version: &'static str,
+    event_kind: AccessListEventKind<'a>,
+) -> NearEvent<'a> {
+    NearEvent::AccessList(AccessListEvent { version, event_kind })
+}
+
+fn new_access_list_v1(event_kind: AccessListEventKind) -> NearEvent {
+    new_access_list("1.0.0", event_kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils;
+
+    #[test]
+    fn access_list_add() {
+        let account_id = AccountIdRef::new_or_panic("bob");
+        AccessListAdd { list: "allow", account_id }.emit();
+        assert_eq!(
+            test_utils::get_logs()[0],
+            r#"EVENT_JSON:{"standard":"access_list","version":"1.0.0","event":"access_list_add","data":[{"list":"allow","account_id":"bob"}]}"#
+        );
+    }
+
+    #[test]
+    fn access_list_remove() {
+        let account_id = AccountIdRef::new_or_panic("bob");
+        AccessListRemove { list: "deny", account_id }.emit();
+        assert_eq!(
+            test_utils::get_logs()[0],
+            r#"EVENT_JSON:{"standard":"access_list","version":"1.0.0","event":"access_list_remove","data":[{"list":"deny","account_id":"bob"}]}"#
+        );
+    }
+}
@@ -0,0 +1,41 @@
+/// Exposes an [`AccessList`](crate::access::AccessList) stored in `$contract.$list` as
+/// contract methods: `access_list_add` and `access_list_remove` for the owner, and
+/// `access_list_contains`, `access_list_is_allowed`, `access_list_len` and
+/// `access_list_accounts` as views.
+///
+/// Only one list can be exposed per contract, since the method names are fixed.
+#[macro_export]
+macro_rules! impl_access_list {
+    ($contract: ident, $list: ident) => {
+        #[near_sdk::near]
+        impl $contract {
+            pub fn access_list_add(&mut self, account_id: near_sdk::AccountId) -> bool {
+                self.$list.add(account_id)
+            }
+
+            pub fn access_list_remove(&mut self, account_id: near_sdk::AccountId) -> bool {
+                self.$list.remove(&account_id)
+            }
+
+            pub fn access_list_contains(&self, account_id: near_sdk::AccountId) -> bool {
+                self.$list.contains(&account_id)
+            }
+
+            pub fn access_list_is_allowed(&self, account_id: near_sdk::AccountId) -> bool {
+                self.$list.is_allowed(&account_id)
+            }
+
+            pub fn access_list_len(&self) -> u32 {
+                self.$list.len()
+            }
+
+            pub fn access_list_accounts(
+                &self,
+                from_index: Option<u32>,
+                limit: Option<u32>,
+            ) -> Vec<near_sdk::AccountId> {
+                self.$list.accounts(from_index, limit)
+            }
+        }
+    };
+}
@@ -0,0 +1,281 @@
+//! Allow lists and deny lists of accounts, managed by an owner.
+//!
+//! [`AllowList`] only lets through accounts that were explicitly added, while [`DenyList`]
+//! lets through every account except the ones that were added. Both are backed by an
+//! [`IterableSet`], so the listed accounts can be enumerated, and only the list's owner can
+//! modify them through [`add`](AccessList::add) and [`remove`](AccessList::remove). Every
+//! change is logged as an [`events::AccessListAdd`] or [`events::AccessListRemove`] event.
+//!
+//! The [`impl_access_list!`](crate::impl_access_list) macro exposes the list as contract
+//! methods.
+
+pub mod events;
+mod macros;
+
+use std::marker::PhantomData;
+
+use events::{AccessListAdd, AccessListRemove};
+use near_sdk::store::IterableSet;
+use near_sdk::{env, near, require, AccountId, AccountIdRef, IntoStorageKey};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The kind of an [`AccessList`]: either [`Allow`] or [`Deny`].
+pub trait AccessListKind: private::Sealed {
+    /// Name of the list kind, used in emitted events.
+    const NAME: &'static str;
+    /// Whether accounts in the list are the allowed ones.
+    const ALLOWS_LISTED: bool;
+}
+
+/// Marker for an [`AccessList`] where only listed accounts are allowed.
+pub enum Allow {}
+
+/// Marker for an [`AccessList`] where listed accounts are denied.
+pub enum Deny {}
+
+impl private::Sealed for Allow {}
+impl private::Sealed for Deny {}
+
+impl AccessListKind for Allow {
+    const NAME: &'static str = "allow";
+    const ALLOWS_LISTED: bool = true;
+}
+
+impl AccessListKind for Deny {
+    const NAME: &'static str = "deny";
+    const ALLOWS_LISTED: bool = false;
+}
+
+/// A list of accounts that are allowed access; everyone else is denied.
+pub type AllowList = AccessList<Allow>;
+
+/// A list of accounts that are denied access; everyone else is allowed.
+pub type DenyList = AccessList<Deny>;
+
+/// A set of accounts that are either allowed or denied access, depending on `K`.
+///
+/// Use the [`AllowList`] and [`DenyList`] aliases rather than naming this type directly.
+#[near]
+pub struct AccessList<K: AccessListKind> {
+    /// Account allowed to modify the list.
+    pub owner_id: AccountId,
+    accounts: IterableSet<AccountId>,
+    #[borsh(skip)]
+    kind: PhantomData<K>,
+}
+
+impl<K: AccessListKind> AccessList<K> {
+    pub fn new<S>(prefix: S, owner_id: AccountId) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { owner_id, accounts: IterableSet::new(prefix), kind: PhantomData }
+    }
+
+    /// Panics if the predecessor is not the owner of the list.
+    pub fn assert_owner(&self) {
+        require!(env::predecessor_account_id() == self.owner_id, "Owner must be predecessor");
+    }
+
+    /// Adds `account_id` to the list. Returns `false` if it was already listed.
+    /// Can only be called by the owner.
+    pub fn add(&mut self, account_id: AccountId) -> bool {
+        self.assert_owner();
+        self.internal_add(account_id)
+    }
+
+    /// Removes `account_id` from the list. Returns `false` if it was not listed.
+    /// Can only be called by the owner.
+    pub fn remove(&mut self, account_id: &AccountIdRef) -> bool {
+        self.assert_owner();
+        self.internal_remove(account_id)
+    }
+
+    /// Adds `account_id` to the list without checking the predecessor.
+    pub fn internal_add(&mut self, account_id: AccountId) -> bool {
+        let added = self.accounts.insert(account_id.clone());
+        if added {
+            AccessListAdd { list: K::NAME, account_id: &account_id }.emit();
+        }
+        added
+    }
+
+    /// Removes `account_id` from the list without checking the predecessor.
+    pub fn internal_remove(&mut self, account_id: &AccountIdRef) -> bool {
+        let removed = self.accounts.remove(account_id);
+        if removed {
+            AccessListRemove { list: K::NAME, account_id }.emit();
+        }
+        removed
+    }
+
+    /// Returns `true` if `account_id` is in the list.
+    pub fn contains(&self, account_id: &AccountIdRef) -> bool {
+        self.accounts.contains(account_id)
+    }
+
+    /// Returns `true` if `account_id` is allowed access by this list.
+    pub fn is_allowed(&self, account_id: &AccountIdRef) -> bool {
+        self.contains(account_id) == K::ALLOWS_LISTED
+    }
+
+    /// Panics if `account_id` is not allowed access by this list.
+    pub fn assert_allowed(&self, account_id: &AccountIdRef) {
+        if !self.is_allowed(account_id) {
+            env::panic_str(&format!("The account {} is not allowed", account_id))
+        }
+    }
+
+    /// Number of accounts in the list.
+    pub fn len(&self) -> u32 {
+        self.accounts.len()
+    }
+
+    /// Returns `true` if the list contains no accounts.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Returns up to `limit` listed accounts, starting at `from_index`.
+    pub fn accounts(&self, from_index: Option<u32>, limit: Option<u32>) -> Vec<AccountId> {
+        let start_index = from_index.unwrap_or_default();
+        require!(self.len() >= start_index, "Out of bounds, please use a smaller from_index.");
+        let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+        require!(limit != 0, "Cannot provide limit of 0.");
+        self.accounts.iter().skip(start_index as usize).take(limit).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, get_logs, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    fn set_predecessor(account_id: AccountId) {
+        testing_env!(VMContextBuilder::new().predecessor_account_id(account_id).build());
+    }
+
+    #[near(contract_state)]
+    #[derive(near_sdk::PanicOnDefault)]
+    struct Contract {
+        allowed: AllowList,
+    }
+
+    crate::impl_access_list!(Contract, allowed);
+
+    #[test]
+    fn test_add_remove_enumerate() {
+        set_predecessor(accounts(0));
+        let mut list = AllowList::new(b"a", accounts(0));
+        assert!(list.is_empty());
+
+        assert!(list.add(accounts(1)));
+        assert!(list.add(accounts(2)));
+        assert!(!list.add(accounts(1)));
+        assert_eq!(list.len(), 2);
+        assert!(list.contains(&accounts(1)));
+        assert_eq!(list.accounts(None, None), vec![accounts(1), accounts(2)]);
+        assert_eq!(list.accounts(Some(1), None), vec![accounts(2)]);
+        assert_eq!(list.accounts(None, Some(1)), vec![accounts(1)]);
+
+        assert!(list.remove(&accounts(1)));
+        assert!(!list.remove(&accounts(1)));
+        assert!(!list.contains(&accounts(1)));
+        assert_eq!(list.accounts(None, None), vec![accounts(2)]);
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"access_list","version":"1.0.0","event":"access_list_add","data":[{"list":"allow","account_id":"bob"}]}"#,
+                r#"EVENT_JSON:{"standard":"access_list","version":"1.0.0","event":"access_list_add","data":[{"list":"allow","account_id":"charlie"}]}"#,
+                r#"EVENT_JSON:{"standard":"access_list","version":"1.0.0","event":"access_list_remove","data":[{"list":"allow","account_id":"bob"}]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allow_list() {
+        set_predecessor(accounts(0));
+        let mut list = AllowList::new(b"a", accounts(0));
+        list.add(accounts(1));
+        assert!(list.is_allowed(&accounts(1)));
+        assert!(!list.is_allowed(&accounts(2)));
+        list.assert_allowed(&accounts(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "The account charlie is not allowed")]
+    fn test_allow_list_assert_allowed_unlisted() {
+        set_predecessor(accounts(0));
+        let mut list = AllowList::new(b"a", accounts(0));
+        list.add(accounts(1));
+        list.assert_allowed(&accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "The account bob is not allowed")]
+    fn test_deny_list_assert_allowed_denied() {
+        set_predecessor(accounts(0));
+        let mut list = DenyList::new(b"d", accounts(0));
+        list.add(accounts(1));
+        list.assert_allowed(&accounts(2));
+        list.assert_allowed(&accounts(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Owner must be predecessor")]
+    fn test_add_not_owner() {
+        set_predecessor(accounts(1));
+        let mut list = DenyList::new(b"d", accounts(0));
+        list.add(accounts(2));
+    }
+
+    #[test]
+    fn test_internal_add_skips_owner_check() {
+        set_predecessor(accounts(1));
+        let mut list = DenyList::new(b"d", accounts(0));
+        assert!(list.internal_add(accounts(2)));
+        assert!(!list.is_allowed(&accounts(2)));
+        assert!(list.internal_remove(&accounts(2)));
+        assert!(list.is_allowed(&accounts(2)));
+    }
+
+    #[test]
+    fn test_impl_access_list() {
+        set_predecessor(accounts(0));
+        let mut contract = Contract { allowed: AllowList::new(b"a", accounts(0)) };
+        assert!(contract.access_list_add(accounts(1)));
+        assert!(contract.access_list_add(accounts(2)));
+        assert!(contract.access_list_contains(accounts(1)));
+        assert!(contract.access_list_is_allowed(accounts(2)));
+        assert!(!contract.access_list_is_allowed(accounts(3)));
+        assert_eq!(contract.access_list_len(), 2);
+        assert_eq!(contract.access_list_accounts(Some(1), None), vec![accounts(2)]);
+
+        assert!(contract.access_list_remove(accounts(1)));
+        assert!(!contract.access_list_contains(accounts(1)));
+        assert_eq!(contract.access_list_accounts(None, None), vec![accounts(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Owner must be predecessor")]
+    fn test_impl_access_list_add_not_owner() {
+        set_predecessor(accounts(1));
+        let mut contract = Contract { allowed: AllowList::new(b"a", accounts(0)) };
+        contract.access_list_add(accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Owner must be predecessor")]
+    fn test_impl_access_list_remove_not_owner() {
+        set_predecessor(accounts(0));
+        let mut contract = Contract { allowed: AllowList::new(b"a", accounts(0)) };
+        contract.access_list_add(accounts(2));
+        set_predecessor(accounts(1));
+        contract.access_list_remove(accounts(2));
+    }
+}
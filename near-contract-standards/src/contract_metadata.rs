@@ -7,6 +7,10 @@ pub struct ContractSourceMetadata {
     pub version: Option<String>,
     pub link: Option<String>,
     pub standards: Vec<Standard>,
+    /// Set by `#[near]` when the contract is built with the NEP-330 build details, e.g. by
+    /// `cargo near build`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_info: Option<BuildInfo>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -15,3 +19,85 @@ pub struct Standard {
     pub standard: String,
     pub version: String,
 }
+
+/// Details on how the contract was built, as defined by the build details extension of NEP-330.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BuildInfo {
+    pub build_environment: String,
+    pub build_command: Vec<String>,
+    pub contract_path: String,
+    pub source_code_snapshot: String,
+}
+
+/// Adjusts the contract source metadata returned by the `contract_source_metadata` view method.
+///
+/// The hook is only called for contracts declared with `hook` in their `contract_metadata`,
+/// otherwise the metadata set at compile time is returned as is.
+///
+/// # Examples
+/// ```
+/// use near_contract_standards::contract_metadata::{
+///     ContractSourceMetadata, ContractSourceMetadataHook,
+/// };
+/// use near_sdk::near;
+///
+/// #[near(contract_state, contract_metadata(hook))]
+/// #[derive(Default)]
+/// pub struct Contract {
+///     revision: String,
+/// }
+///
+/// impl ContractSourceMetadataHook for Contract {
+///     fn contract_source_metadata_hook(
+///         &self,
+///         mut metadata: ContractSourceMetadata,
+///     ) -> ContractSourceMetadata {
+///         metadata.version = Some(self.revision.clone());
+///         metadata
+///     }
+/// }
+/// ```
+pub trait ContractSourceMetadataHook {
+    /// Returns the metadata to report, given the one set at compile time.
+    fn contract_source_metadata_hook(
+        &self,
+        metadata: ContractSourceMetadata,
+    ) -> ContractSourceMetadata;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::near;
+    use near_sdk::test_utils::get_return_json;
+
+    #[near(
+        contract_state,
+        contract_metadata(standard(standard = "nep171", version = "1.0.0"), hook)
+    )]
+    #[derive(Default)]
+    struct Contract {}
+
+    impl ContractSourceMetadataHook for Contract {
+        fn contract_source_metadata_hook(
+            &self,
+            mut metadata: ContractSourceMetadata,
+        ) -> ContractSourceMetadata {
+            metadata
+                .standards
+                .push(Standard { standard: "nep999".to_string(), version: "0.1.0".to_string() });
+            metadata
+        }
+    }
+
+    #[test]
+    fn test_hook() {
+        Contract::default().contract_source_metadata();
+
+        let metadata = get_return_json::<ContractSourceMetadata>().unwrap();
+        let standards: Vec<_> = metadata.standards.iter().map(|s| s.standard.as_str()).collect();
+        assert_eq!(standards, ["nep171", "nep330", "nep999"]);
+        assert_eq!(metadata.build_info, None);
+    }
+}
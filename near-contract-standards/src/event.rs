@@ -10,6 +10,7 @@ use near_sdk::serde_json;
 pub(crate) enum NearEvent<'a> {
     Nep171(crate::non_fungible_token::events::Nep171Event<'a>),
     Nep141(crate::fungible_token::events::Nep141Event<'a>),
+    AccessList(crate::access::events::AccessListEvent<'a>),
 }
 
 impl<'a> NearEvent<'a> {
@@ -1,7 +1,7 @@
 use near_sdk::ext_contract;
 use near_sdk::json_types::U128;
-use near_sdk::AccountId;
-use near_sdk::PromiseOrValue;
+use near_sdk::serde::Serialize;
+use near_sdk::{env, serde_json, AccountId, Promise, PromiseOrValue};
 /// The core methods for a basic fungible token. Extension standards may be
 /// added in addition to this trait.
 ///
@@ -49,6 +49,8 @@ use near_sdk::PromiseOrValue;
 /// }
 /// ```
 ///
+/// The trait is object safe, so code working across several token implementations can take a
+/// `&dyn FungibleTokenCore` or a `Box<dyn FungibleTokenCore>`.
 #[ext_contract(ext_ft_core)]
 pub trait FungibleTokenCore {
     /// Transfers positive `amount` of tokens from the `env::predecessor_account_id` to `receiver_id`.
@@ -100,3 +102,99 @@ pub trait FungibleTokenCore {
     /// Returns the balance of the account. If the account doesn't exist must returns `"0"`.
     fn ft_balance_of(&self, account_id: AccountId) -> U128;
 }
+
+impl ext_ft_core::FungibleTokenCoreExt {
+    /// Same as `ft_transfer_call`, but takes a typed `msg` that is serialized to JSON before being
+    /// passed to the receiver's `ft_on_transfer`. The receiver can read it back with
+    /// [`parse_msg`](crate::fungible_token::receiver::parse_msg).
+    pub fn ft_transfer_call_typed<M: Serialize>(
+        self,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+        msg: M,
+    ) -> Promise {
+        let msg = serde_json::to_string(&msg)
+            .unwrap_or_else(|_| env::panic_str("Failed to serialize the transfer message"));
+        self.ft_transfer_call(receiver_id, amount, memo, msg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FungibleTokenCore;
+    use crate::fungible_token::FungibleToken;
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{env, require, testing_env, AccountId, NearToken, PromiseOrValue};
+
+    /// A token with a fixed supply held by a single account, which can only be transferred as a
+    /// whole, to mix implementations.
+    struct SingleHolder {
+        holder: AccountId,
+        supply: u128,
+    }
+
+    impl FungibleTokenCore for SingleHolder {
+        fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, _memo: Option<String>) {
+            require!(env::predecessor_account_id() == self.holder, "Only the holder can transfer");
+            require!(amount.0 == self.supply, "The whole supply must be transferred");
+            self.holder = receiver_id;
+        }
+
+        fn ft_transfer_call(
+            &mut self,
+            receiver_id: AccountId,
+            amount: U128,
+            memo: Option<String>,
+            _msg: String,
+        ) -> PromiseOrValue<U128> {
+            // There is no receiver to notify, so the whole amount is used right away.
+            self.ft_transfer(receiver_id, amount, memo);
+            PromiseOrValue::Value(amount)
+        }
+
+        fn ft_total_supply(&self) -> U128 {
+            U128(self.supply)
+        }
+
+        fn ft_balance_of(&self, account_id: AccountId) -> U128 {
+            U128(if account_id == self.holder { self.supply } else { 0 })
+        }
+    }
+
+    fn total_balance_of(tokens: &[Box<dyn FungibleTokenCore>], account_id: &AccountId) -> u128 {
+        tokens.iter().map(|token| token.ft_balance_of(account_id.clone()).0).sum()
+    }
+
+    #[test]
+    fn dyn_fungible_token_core() {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(accounts(0))
+            .attached_deposit(NearToken::from_yoctonear(1))
+            .build());
+
+        let mut token = FungibleToken::new(b"t".to_vec());
+        token.internal_register_account(&accounts(0));
+        token.internal_register_account(&accounts(1));
+        token.internal_deposit(&accounts(0), 100);
+
+        let mut tokens: Vec<Box<dyn FungibleTokenCore>> =
+            vec![Box::new(token), Box::new(SingleHolder { holder: accounts(1), supply: 50 })];
+
+        tokens[0].ft_transfer(accounts(1), U128(30), None);
+
+        assert_eq!(tokens[0].ft_total_supply(), U128(100));
+        assert_eq!(tokens[1].ft_total_supply(), U128(50));
+        assert_eq!(total_balance_of(&tokens, &accounts(0)), 70);
+        assert_eq!(total_balance_of(&tokens, &accounts(1)), 80);
+
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(accounts(1))
+            .attached_deposit(NearToken::from_yoctonear(1))
+            .build());
+        tokens[1].ft_transfer(accounts(2), U128(50), None);
+        assert_eq!(total_balance_of(&tokens, &accounts(1)), 30);
+        assert_eq!(total_balance_of(&tokens, &accounts(2)), 50);
+    }
+}
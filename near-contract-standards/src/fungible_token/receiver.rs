@@ -1,5 +1,6 @@
 use near_sdk::json_types::U128;
-use near_sdk::{ext_contract, AccountId, PromiseOrValue};
+use near_sdk::serde::de::DeserializeOwned;
+use near_sdk::{env, ext_contract, serde_json, AccountId, PromiseOrValue};
 
 #[ext_contract(ext_ft_receiver)]
 pub trait FungibleTokenReceiver {
@@ -28,3 +29,60 @@ pub trait FungibleTokenReceiver {
         msg: String,
     ) -> PromiseOrValue<U128>;
 }
+
+/// Deserializes the JSON `msg` passed to [`FungibleTokenReceiver::ft_on_transfer`], e.g. one sent
+/// with [`ft_transfer_call_typed`](crate::fungible_token::core::ext_ft_core::FungibleTokenCoreExt::ft_transfer_call_typed).
+///
+/// Panics if `msg` isn't a valid JSON representation of `M`, which makes the token contract
+/// refund the full transfer amount.
+pub fn parse_msg<M: DeserializeOwned>(msg: &str) -> M {
+    serde_json::from_str(msg)
+        .unwrap_or_else(|err| env::panic_str(&format!("Invalid transfer message: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fungible_token::core::ext_ft_core;
+    use near_sdk::mock::MockAction;
+    use near_sdk::serde::{Deserialize, Serialize};
+    use near_sdk::{test_utils, testing_env, AccountIdRef};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(crate = "near_sdk::serde")]
+    enum Action {
+        Swap { token_out: AccountId, min_amount_out: U128 },
+        Deposit,
+    }
+
+    #[test]
+    fn typed_msg_round_trip() {
+        testing_env!(test_utils::VMContextBuilder::new().build());
+        let action = Action::Swap {
+            token_out: AccountIdRef::new_or_panic("wrap.near").into(),
+            min_amount_out: U128(10),
+        };
+
+        let _ = ext_ft_core::ext(AccountIdRef::new_or_panic("token.near").into())
+            .ft_transfer_call_typed(
+                AccountIdRef::new_or_panic("dex.near").into(),
+                U128(100),
+                None,
+                &action,
+            );
+
+        let receipts = test_utils::get_created_receipts();
+        let args = match &receipts[0].actions[0] {
+            MockAction::FunctionCallWeight { args, .. } => args,
+            action => panic!("unexpected action: {:?}", action),
+        };
+        let args: serde_json::Value = serde_json::from_slice(args).unwrap();
+        assert_eq!(parse_msg::<Action>(args["msg"].as_str().unwrap()), action);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid transfer message")]
+    fn parse_invalid_msg() {
+        parse_msg::<Action>("\"Withdraw\"");
+    }
+}
@@ -18,6 +18,9 @@ pub mod storage_management;
 )]
 pub mod upgrade;
 
+/// Owner-managed allow lists and deny lists of accounts.
+pub mod access;
+
 pub(crate) mod event;
 
 pub mod contract_metadata;
@@ -20,6 +20,24 @@ fn expect_approval<T>(option: Option<T>) -> T {
     option.unwrap_or_else(|| env::panic_str("next_approval_by_id must be set for approval ext"))
 }
 
+impl NonFungibleToken {
+    /// Returns all accounts approved for `token_id` along with their approval IDs, ordered by
+    /// approval ID. Returns an empty list if the token has no approvals or if the contract does
+    /// not support approval management.
+    pub fn nft_approvals(&self, token_id: TokenId) -> Vec<(AccountId, u64)> {
+        expect_token_found(self.owner_by_id.get(&token_id));
+
+        let mut approvals: Vec<(AccountId, u64)> = self
+            .approvals_by_id
+            .as_ref()
+            .and_then(|by_id| by_id.get(&token_id))
+            .map(|approved_account_ids| approved_account_ids.into_iter().collect())
+            .unwrap_or_default();
+        approvals.sort_unstable_by_key(|(_, approval_id)| *approval_id);
+        approvals
+    }
+}
+
 impl NonFungibleTokenApproval for NonFungibleToken {
     fn nft_approve(
         &mut self,
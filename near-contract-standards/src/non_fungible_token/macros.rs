@@ -101,6 +101,13 @@ macro_rules! impl_non_fungible_token_approval {
                 self.$token.nft_is_approved(token_id, approved_account_id, approval_id)
             }
         }
+
+        #[near]
+        impl $contract {
+            pub fn nft_approvals(&self, token_id: TokenId) -> Vec<(AccountId, u64)> {
+                self.$token.nft_approvals(token_id)
+            }
+        }
     };
 }
 
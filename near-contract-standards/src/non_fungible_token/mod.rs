@@ -12,6 +12,8 @@ mod macros;
 /// Metadata traits and implementation according to the [NFT enumeration standard](https://nomicon.io/Standards/NonFungibleToken/Metadata.html).
 /// This covers both the contract metadata and the individual token metadata.
 pub mod metadata;
+/// Royalty payouts according to the [NFT payout standard](https://nomicon.io/Standards/Tokens/NonFungibleToken/Payout).
+pub mod payout;
 /// The Token struct for the non-fungible token.
 mod token;
 pub use self::token::{Token, TokenId};
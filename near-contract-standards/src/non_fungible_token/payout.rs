@@ -0,0 +1,156 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{require, AccountId, NearSchema};
+use std::collections::HashMap;
+
+/// Total of basis points of a sale price, i.e. 100%.
+pub const MAX_BASIS_POINTS: u32 = 10_000;
+
+/// Amounts to pay to each account out of the sale of a token, as returned by `nft_payout` and
+/// `nft_transfer_payout`.
+#[derive(NearSchema, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Payout {
+    pub payout: HashMap<AccountId, U128>,
+}
+
+/// Splits `balance` between the royalty recipients and the owner of the token.
+///
+/// Each recipient gets its share of `balance` in basis points (1/100 of a percent), rounded down.
+/// The owner gets the rest, including the rounding remainder, and a royalty of the owner is
+/// merged into that share.
+///
+/// # Panics
+///
+/// Panics if the royalties add up to more than [`MAX_BASIS_POINTS`], or if the payout, which
+/// includes the owner, would have more than `max_len_payout` accounts.
+///
+/// # Examples
+///
+/// ```
+/// use near_contract_standards::non_fungible_token::payout::compute_payout;
+/// use near_sdk::json_types::U128;
+/// use near_sdk::AccountId;
+/// use std::collections::HashMap;
+///
+/// let owner: AccountId = "owner.near".parse().unwrap();
+/// let artist: AccountId = "artist.near".parse().unwrap();
+/// let royalties = HashMap::from([(artist.clone(), 250)]);
+///
+/// let payout = compute_payout(U128(1_000), &royalties, &owner, 10);
+/// assert_eq!(payout.payout[&artist], U128(25));
+/// assert_eq!(payout.payout[&owner], U128(975));
+/// ```
+pub fn compute_payout(
+    balance: U128,
+    royalties: &HashMap<AccountId, u32>,
+    owner_id: &AccountId,
+    max_len_payout: u32,
+) -> Payout {
+    let total_basis_points: u64 = royalties.values().map(|&bp| u64::from(bp)).sum();
+    require!(
+        total_basis_points <= u64::from(MAX_BASIS_POINTS),
+        "Royalties must not exceed 10000 basis points"
+    );
+    let recipients = royalties.keys().filter(|&account_id| account_id != owner_id).count();
+    require!(
+        recipients < max_len_payout as usize,
+        format!("Payout can't have more than {} accounts", max_len_payout)
+    );
+
+    let mut payout = HashMap::with_capacity(recipients + 1);
+    let mut owner_amount = balance.0;
+    for (account_id, &basis_points) in royalties {
+        if account_id == owner_id {
+            continue;
+        }
+        let amount = share_of(balance.0, basis_points);
+        owner_amount -= amount;
+        payout.insert(account_id.clone(), U128(amount));
+    }
+    payout.insert(owner_id.clone(), U128(owner_amount));
+    Payout { payout }
+}
+
+/// Returns `basis_points` of `balance`, rounded down, without overflowing.
+fn share_of(balance: u128, basis_points: u32) -> u128 {
+    let (basis_points, max) = (u128::from(basis_points), u128::from(MAX_BASIS_POINTS));
+    balance / max * basis_points + balance % max * basis_points / max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::accounts;
+
+    fn total(payout: &Payout) -> u128 {
+        payout.payout.values().map(|amount| amount.0).sum()
+    }
+
+    #[test]
+    fn uneven_split() {
+        let royalties = HashMap::from([(accounts(1), 1_000), (accounts(2), 333)]);
+        let payout = compute_payout(U128(1_000_000), &royalties, &accounts(0), 10);
+
+        assert_eq!(payout.payout.len(), 3);
+        assert_eq!(payout.payout[&accounts(1)], U128(100_000));
+        assert_eq!(payout.payout[&accounts(2)], U128(33_300));
+        assert_eq!(payout.payout[&accounts(0)], U128(866_700));
+        assert_eq!(total(&payout), 1_000_000);
+    }
+
+    #[test]
+    fn rounding_remainder_goes_to_owner() {
+        let royalties = HashMap::from([(accounts(1), 3_333), (accounts(2), 3_333)]);
+        let payout = compute_payout(U128(10), &royalties, &accounts(0), 3);
+
+        // 33.33% of 10 is rounded down to 3 for each recipient.
+        assert_eq!(payout.payout[&accounts(1)], U128(3));
+        assert_eq!(payout.payout[&accounts(2)], U128(3));
+        assert_eq!(payout.payout[&accounts(0)], U128(4));
+        assert_eq!(total(&payout), 10);
+    }
+
+    #[test]
+    fn owner_royalty_is_merged() {
+        let royalties = HashMap::from([(accounts(0), 500), (accounts(1), 500)]);
+        let payout = compute_payout(U128(100), &royalties, &accounts(0), 2);
+
+        assert_eq!(payout.payout.len(), 2);
+        assert_eq!(payout.payout[&accounts(0)], U128(95));
+        assert_eq!(payout.payout[&accounts(1)], U128(5));
+    }
+
+    #[test]
+    fn full_royalties_and_large_balance() {
+        let royalties = HashMap::from([(accounts(1), 7_500), (accounts(2), 2_500)]);
+        let payout = compute_payout(U128(u128::MAX), &royalties, &accounts(0), 3);
+
+        assert_eq!(
+            payout.payout[&accounts(0)].0,
+            u128::MAX - payout.payout[&accounts(1)].0 - payout.payout[&accounts(2)].0
+        );
+        assert_eq!(payout.payout[&accounts(2)].0, u128::MAX / 4);
+        assert_eq!(total(&payout), u128::MAX);
+    }
+
+    #[test]
+    fn no_royalties() {
+        let payout = compute_payout(U128(42), &HashMap::new(), &accounts(0), 1);
+        assert_eq!(payout.payout, HashMap::from([(accounts(0), U128(42))]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Royalties must not exceed 10000 basis points")]
+    fn royalties_above_total() {
+        let royalties = HashMap::from([(accounts(1), 6_000), (accounts(2), 4_001)]);
+        compute_payout(U128(100), &royalties, &accounts(0), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Payout can't have more than 2 accounts")]
+    fn too_many_recipients() {
+        let royalties = HashMap::from([(accounts(1), 100), (accounts(2), 100)]);
+        compute_payout(U128(100), &royalties, &accounts(0), 2);
+    }
+}
@@ -1,4 +1,4 @@
-use near_sdk::{ext_contract, near, AccountId, NearToken};
+use near_sdk::{env, ext_contract, near, AccountId, NearToken, StorageUsage};
 
 #[near(serializers=[borsh, json])]
 pub struct StorageBalance {
@@ -118,3 +118,115 @@ pub trait StorageManagement {
 
     fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance>;
 }
+
+/// Returns the balance needed to cover `bytes` of storage at the current storage byte cost,
+/// e.g. to compute [`StorageBalanceBounds::min`] from the storage used by one account.
+pub fn min_balance_for_bytes(bytes: StorageUsage) -> NearToken {
+    env::storage_byte_cost().saturating_mul(bytes.into())
+}
+
+/// Returns the number of bytes of storage used by registering a single account in `state` with
+/// `register`.
+///
+/// `register` is called with the longest valid account ID, so the result is an upper bound for
+/// any account. The account is then removed again with `unregister`, which must free the storage
+/// used by `register`.
+///
+/// # Panics
+///
+/// Panics if `register` frees storage instead of using it.
+///
+/// # Examples
+///
+/// ```
+/// use near_contract_standards::storage_management::{
+///     measure_account_storage_usage, min_balance_for_bytes,
+/// };
+/// use near_sdk::store::LookupMap;
+///
+/// let mut accounts = LookupMap::new(b"a");
+/// let bytes = measure_account_storage_usage(
+///     &mut accounts,
+///     |accounts, account_id| {
+///         accounts.insert(account_id.clone(), 0u128);
+///         accounts.flush();
+///     },
+///     |accounts, account_id| {
+///         accounts.remove(account_id);
+///         accounts.flush();
+///     },
+/// );
+/// let min_balance = min_balance_for_bytes(bytes);
+/// ```
+pub fn measure_account_storage_usage<T, R, U>(
+    state: &mut T,
+    register: R,
+    unregister: U,
+) -> StorageUsage
+where
+    R: FnOnce(&mut T, &AccountId),
+    U: FnOnce(&mut T, &AccountId),
+{
+    let initial_storage_usage = env::storage_usage();
+    let tmp_account_id: AccountId = "a".repeat(64).parse().unwrap();
+    register(state, &tmp_account_id);
+    let bytes = env::storage_usage()
+        .checked_sub(initial_storage_usage)
+        .unwrap_or_else(|| env::panic_str("Registering an account decreased the storage usage"));
+    unregister(state, &tmp_account_id);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure_account_storage_usage, min_balance_for_bytes};
+    use near_sdk::collections::LookupMap;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{env, testing_env, AccountId, NearToken};
+
+    #[test]
+    fn test_measure_account_storage_usage() {
+        testing_env!(VMContextBuilder::new().build());
+        let mut accounts = LookupMap::new(b"a");
+        let initial_storage_usage = env::storage_usage();
+        let measure = |accounts: &mut LookupMap<AccountId, u128>| {
+            measure_account_storage_usage(
+                accounts,
+                |accounts, account_id| {
+                    accounts.insert(account_id, &0u128);
+                },
+                |accounts, account_id| {
+                    accounts.remove(account_id);
+                },
+            )
+        };
+        // Data record overhead, key (prefix and length-prefixed account ID) and value.
+        assert_eq!(measure(&mut accounts), 40 + (1 + 4 + 64) + 16);
+        // The temporary account is removed afterwards.
+        assert_eq!(env::storage_usage(), initial_storage_usage);
+        assert_eq!(measure(&mut accounts), 40 + (1 + 4 + 64) + 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Registering an account decreased the storage usage")]
+    fn test_measure_account_storage_usage_freeing_storage() {
+        testing_env!(VMContextBuilder::new().build());
+        let mut accounts: LookupMap<AccountId, u128> = LookupMap::new(b"a");
+        accounts.insert(&"alice.near".parse().unwrap(), &0u128);
+        measure_account_storage_usage(
+            &mut accounts,
+            |accounts, _| {
+                accounts.remove(&"alice.near".parse().unwrap());
+            },
+            |_, _| {},
+        );
+    }
+
+    #[test]
+    fn test_min_balance_for_bytes() {
+        testing_env!(VMContextBuilder::new().build());
+        assert_eq!(min_balance_for_bytes(0), NearToken::from_yoctonear(0));
+        assert_eq!(min_balance_for_bytes(125), env::storage_byte_cost().saturating_mul(125));
+        assert_eq!(min_balance_for_bytes(100_000), NearToken::from_near(1));
+    }
+}
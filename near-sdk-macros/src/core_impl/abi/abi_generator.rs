@@ -12,7 +12,20 @@ pub fn generate(i: &ItemImplInfo) -> TokenStream2 {
         return TokenStream2::new();
     }
 
-    let functions: Vec<TokenStream2> = i.methods.iter().map(|m| m.abi_struct()).collect();
+    // Each function is only listed under the same `#[cfg(...)]` as its method.
+    let functions: Vec<TokenStream2> = i
+        .methods
+        .iter()
+        .map(|m| {
+            let cfg_attrs =
+                m.attr_signature_info.non_bindgen_attrs.iter().filter(|a| a.path().is_ident("cfg"));
+            let function = m.abi_struct();
+            quote! {
+                #(#cfg_attrs)*
+                functions.push(#function);
+            }
+        })
+        .collect();
     let first_function_name = &i.methods[0].attr_signature_info.ident;
     let near_abi_symbol = format_ident!("__near_abi_{}", first_function_name);
     quote! {
@@ -23,7 +36,8 @@ pub fn generate(i: &ItemImplInfo) -> TokenStream2 {
                 use ::std::string::String;
 
                 let mut gen = ::near_sdk::schemars::gen::SchemaGenerator::default();
-                let functions = vec![#(#functions),*];
+                let mut functions = ::std::vec::Vec::new();
+                #(#functions)*
                 let mut data = ::std::mem::ManuallyDrop::new(
                     ::near_sdk::serde_json::to_vec(&::near_sdk::__private::ChunkedAbiEntry::new(
                         functions,
@@ -80,7 +94,17 @@ impl ImplItemMethodInfo {
         let attr_signature_info = &self.attr_signature_info;
 
         let function_name_str = attr_signature_info.ident.to_string();
-        let function_doc = match parse_rustdoc(&attr_signature_info.non_bindgen_attrs) {
+        let doc = parse_rustdoc(&attr_signature_info.non_bindgen_attrs);
+        let deprecation = match &attr_signature_info.deprecated_method {
+            Some(replacement) => Some(format!("call `{}` instead", replacement)),
+            None => attr_signature_info.deprecated_log.clone(),
+        };
+        let doc = match (doc, deprecation) {
+            (Some(doc), Some(message)) => Some(format!("{}\n\nDeprecated: {}", doc, message)),
+            (None, Some(message)) => Some(format!("Deprecated: {}", message)),
+            (doc, None) => doc,
+        };
+        let function_doc = match doc {
             Some(doc) => quote! { ::std::option::Option::Some(::std::string::String::from(#doc)) },
             None => quote! { ::std::option::Option::None },
         };
@@ -145,6 +169,8 @@ impl ImplItemMethodInfo {
                     };
                     callbacks.push(generate_abi_type(typ, &arg.serializer_ty));
                 }
+                // The old state is read from storage, so it's not part of the interface.
+                BindgenArgType::MigrateState => {}
                 BindgenArgType::CallbackArgVec => {
                     if callback_vec.is_none() {
                         let typ = if let Some(vec_type) = utils::extract_vec_type(typ) {
@@ -30,7 +30,8 @@ impl AttrSigInfo {
 
         match &self.method_kind {
             Call(call_method) => call_method.is_private,
-            Init(_) => false,
+            // Migrations may only be triggered by the contract itself.
+            Init(init_method) => init_method.migrate_from.is_some(),
             View(view_method) => view_method.is_private,
         }
     }
@@ -89,6 +90,10 @@ impl AttrSigInfo {
             "Can only generate input struct for when input args are specified"
         );
         let attribute = match &self.input_serializer {
+            SerializerType::JSON if self.deny_unknown_arguments == Some(true) => quote! {
+                #[derive(::near_sdk::serde::Deserialize)]
+                #[serde(crate = "::near_sdk::serde", deny_unknown_fields)]
+            },
             SerializerType::JSON => quote! {
                 #[derive(::near_sdk::serde::Deserialize)]
                 #[serde(crate = "::near_sdk::serde")]
@@ -17,15 +17,16 @@ pub(crate) fn generate_ext_structs(
             #name {
                 account_id,
                 deposit: ::near_sdk::NearToken::from_near(0),
-                static_gas: ::near_sdk::Gas::from_gas(0),
+                static_gas: ::std::option::Option::None,
                 gas_weight: ::near_sdk::GasWeight::default(),
             }
         }
     };
     if let Some(generics) = generic_details {
         // If ext generation is on struct, make ext function associated with struct not module
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         ext_code = quote! {
-            impl #generics #ident #generics {
+            impl #impl_generics #ident #ty_generics #where_clause {
                 #ext_code
             }
         };
@@ -36,7 +37,7 @@ pub(crate) fn generate_ext_structs(
       pub struct #name {
           pub(crate) account_id: ::near_sdk::AccountId,
           pub(crate) deposit: ::near_sdk::NearToken,
-          pub(crate) static_gas: ::near_sdk::Gas,
+          pub(crate) static_gas: ::std::option::Option<::near_sdk::Gas>,
           pub(crate) gas_weight: ::near_sdk::GasWeight,
       }
 
@@ -46,7 +47,7 @@ pub(crate) fn generate_ext_structs(
               self
           }
           pub fn with_static_gas(mut self, static_gas: ::near_sdk::Gas) -> Self {
-              self.static_gas = static_gas;
+              self.static_gas = ::std::option::Option::Some(static_gas);
               self
           }
           pub fn with_unused_gas_weight(mut self, gas_weight: u64) -> Self {
@@ -125,6 +126,11 @@ fn generate_ext_function(attr_signature_info: &AttrSigInfo) -> TokenStream2 {
         }
     }
     let Signature { generics, .. } = original_sig;
+    // Static gas set with `with_static_gas` takes precedence over the method's `#[gas(...)]`.
+    let default_static_gas = match &attr_signature_info.static_gas {
+        Some(gas) => quote! { #gas },
+        None => quote! { ::near_sdk::Gas::from_gas(0) },
+    };
     quote! {
         #new_non_bindgen_attrs
         pub fn #ident #generics(self, #pat_type_list) -> ::near_sdk::Promise {
@@ -134,7 +140,7 @@ fn generate_ext_function(attr_signature_info: &AttrSigInfo) -> TokenStream2 {
                 ::std::string::String::from(#ident_str),
                 __args,
                 self.deposit,
-                self.static_gas,
+                self.static_gas.unwrap_or(#default_static_gas),
                 self.gas_weight,
             )
         }
@@ -1,7 +1,10 @@
-use crate::core_impl::info_extractor::{ImplItemMethodInfo, SerializerType};
-use crate::core_impl::{MethodKind, ReturnKind};
+use crate::core_impl::info_extractor::{
+    ArgInfo, AttrSigInfo, BindgenArgType, ImplItemMethodInfo, InitMethod, SerializerType,
+};
+use crate::core_impl::{utils, MethodKind, ReturnKind};
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 use syn::Receiver;
 
 impl ImplItemMethodInfo {
@@ -12,6 +15,7 @@ impl ImplItemMethodInfo {
         let ident = &self.attr_signature_info.ident;
 
         let panic_hook = self.panic_hook_tokens();
+        let deprecated_log = self.deprecated_log_tokens();
 
         let arg_struct = self.arg_struct_tokens();
         let arg_parsing = self.arg_parsing_tokens();
@@ -22,6 +26,7 @@ impl ImplItemMethodInfo {
         let deposit_check = self.deposit_check_tokens();
         let is_private_check = self.private_check_tokens();
         let state_check = self.state_check_tokens();
+        let input_size_check = self.input_size_check_tokens();
 
         let body = match self.attr_signature_info.returns.kind {
             // Extractor errors if Init method doesn't return anything, so we don't need extra check
@@ -31,14 +36,19 @@ impl ImplItemMethodInfo {
             ReturnKind::HandlesResult { .. } => self.result_return_body_tokens(),
         };
 
+        let borsh_result_check = self.borsh_result_check_tokens();
+
         quote! {
+            #borsh_result_check
             #non_bindgen_attrs
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
             pub extern "C" fn #ident() {
                 #panic_hook
+                #deprecated_log
                 #is_private_check
                 #deposit_check
+                #input_size_check
                 #arg_struct
                 #arg_parsing
                 #callback_deser
@@ -85,26 +95,112 @@ impl ImplItemMethodInfo {
         let value_return = self.value_return_tokens();
         let result_identifier = self.result_identifier();
 
+        // A returned promise is not serialized, its result becomes the result of the call.
+        let ok_return = match &self.attr_signature_info.returns.kind {
+            ReturnKind::HandlesResult(ty)
+                if utils::extract_ok_type(ty).is_some_and(utils::type_is_promise) =>
+            {
+                quote! {
+                    #contract_ser
+                    ::std::mem::drop(::near_sdk::Promise::as_return(#result_identifier));
+                }
+            }
+            _ => quote! {
+                #value_ser
+                #value_return
+                #contract_ser
+            },
+        };
+
         quote! {
             #contract_init
             #method_invocation_with_return
             match #result_identifier {
                 ::std::result::Result::Ok(#result_identifier) => {
-                    #value_ser
-                    #value_return
-                    #contract_ser
+                    #ok_return
                 }
                 ::std::result::Result::Err(err) => ::near_sdk::FunctionError::panic(&err)
             }
         }
     }
 
+    /// Checks that the value returned by a `#[result_serializer(borsh)]` method is Borsh
+    /// serializable, reporting a missing implementation at the return type instead of in the
+    /// generated wrapper. Unlike the wrapper, the check is also compiled for non-wasm targets, and
+    /// only under the same `#[cfg(...)]` as the method.
+    fn borsh_result_check_tokens(&self) -> TokenStream2 {
+        let result_serializer = match &self.attr_signature_info.method_kind {
+            MethodKind::Call(call_method) => &call_method.result_serializer,
+            MethodKind::View(view_method) => &view_method.result_serializer,
+            MethodKind::Init(_) => return quote! {},
+        };
+        if *result_serializer != SerializerType::Borsh {
+            return quote! {};
+        }
+        let ty = match &self.attr_signature_info.returns.kind {
+            ReturnKind::Default => return quote! {},
+            ReturnKind::General(ty) => ty,
+            ReturnKind::HandlesResult(ty) => utils::extract_ok_type(ty).unwrap_or(ty),
+        };
+        let (generics, _, where_clause) =
+            self.attr_signature_info.original_sig.generics.split_for_impl();
+        let check = quote_spanned! {ty.span()=>
+            ::near_sdk::__private::assert_borsh_result(value)
+        };
+        let cfg_attrs = self
+            .attr_signature_info
+            .non_bindgen_attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"));
+        quote! {
+            #(#cfg_attrs)*
+            const _: () = {
+                #[allow(dead_code)]
+                fn assert_borsh_result #generics (value: &#ty) #where_clause {
+                    #check
+                }
+            };
+        }
+    }
+
     fn panic_hook_tokens(&self) -> TokenStream2 {
+        if !self.panic_hook {
+            return quote! {};
+        }
         quote! {
             ::near_sdk::env::setup_panic_hook();
         }
     }
 
+    fn input_size_check_tokens(&self) -> TokenStream2 {
+        match self.attr_signature_info.max_input_bytes {
+            Some(limit) => {
+                let message = format!("Input exceeds the maximum size of {} bytes.", limit);
+                quote! {
+                    if ::near_sdk::env::input_len().unwrap_or(0) > #limit {
+                        ::near_sdk::env::panic_str(#message);
+                    }
+                }
+            }
+            None => quote! {},
+        }
+    }
+
+    /// Logs `DEPRECATED: <notice>` for `#[near(deprecated_log = "<notice>")]` and
+    /// ``DEPRECATED: call `<name>` instead`` for `#[deprecated_method(use = "<name>")]`.
+    fn deprecated_log_tokens(&self) -> TokenStream2 {
+        let AttrSigInfo { deprecated_log, deprecated_method, .. } = &self.attr_signature_info;
+        let notice = match (deprecated_log, deprecated_method) {
+            (Some(message), _) => message.clone(),
+            (None, Some(replacement)) => format!("call `{}` instead", replacement),
+            (None, None) => return quote! {},
+        };
+        let log = format!("DEPRECATED: {}", notice);
+        quote! {
+            ::near_sdk::env::log_str(#log);
+        }
+    }
+
     fn arg_struct_tokens(&self) -> TokenStream2 {
         if self.attr_signature_info.has_input_args() {
             self.attr_signature_info.input_struct_deser()
@@ -157,12 +253,28 @@ impl ImplItemMethodInfo {
             }
         };
 
+        let min_deposit_code = || match &self.attr_signature_info.min_deposit {
+            Some(min_deposit) => {
+                let error = format!(
+                    "Method {} requires an attached deposit of at least {{}} yoctoNEAR",
+                    self.attr_signature_info.ident
+                );
+                quote! {
+                    let min_deposit: ::near_sdk::NearToken = #min_deposit;
+                    if ::near_sdk::env::attached_deposit() < min_deposit {
+                        ::near_sdk::env::panic_str(&::std::format!(#error, min_deposit.as_yoctonear()));
+                    }
+                }
+            }
+            None => quote! {},
+        };
+
         match &self.attr_signature_info.method_kind {
             Call(call_method) => {
                 if !call_method.is_payable {
                     reject_deposit_code()
                 } else {
-                    quote! {}
+                    min_deposit_code()
                 }
             }
 
@@ -170,7 +282,7 @@ impl ImplItemMethodInfo {
                 if !init_method.is_payable {
                     reject_deposit_code()
                 } else {
-                    quote! {}
+                    min_deposit_code()
                 }
             }
 
@@ -195,10 +307,26 @@ impl ImplItemMethodInfo {
         use MethodKind::*;
 
         // The purpose of the state check is to prevent the contract from being initialized twice,
-        // so it's not applicable to Call and View methods.
+        // so it's not applicable to Call and View methods. Migrate methods instead require the
+        // old state to exist, and read it.
         match &self.attr_signature_info.method_kind {
             Call(_) => quote! {},
 
+            Init(InitMethod { migrate_from: Some(old_state_ty), .. }) => {
+                let ArgInfo { pat_mutability, ident, .. } = self
+                    .attr_signature_info
+                    .args
+                    .iter()
+                    .find(|arg| matches!(arg.bindgen_ty, BindgenArgType::MigrateState))
+                    .expect("migrate methods always take the old state as an argument");
+                quote! {
+                    let #pat_mutability #ident: #old_state_ty = match ::near_sdk::env::state_read() {
+                        ::std::option::Option::Some(state) => state,
+                        ::std::option::Option::None => ::near_sdk::env::panic_str("Cannot migrate: the contract has no state to migrate from."),
+                    };
+                }
+            }
+
             Init(init_method) => {
                 if !init_method.ignores_state {
                     quote! {
@@ -360,7 +488,13 @@ impl ImplItemMethodInfo {
     fn value_ser_tokens(&self) -> TokenStream2 {
         use MethodKind::*;
 
-        let value_ser = |result_serializer: &SerializerType| match result_serializer {
+        let value_ser = |result_serializer: &SerializerType, pretty: bool| match result_serializer {
+            SerializerType::JSON if pretty => quote! {
+                let result = match near_sdk::serde_json::to_vec_pretty(&result) {
+                    Ok(v) => v,
+                    Err(_) => ::near_sdk::env::panic_str("Failed to serialize the return value using JSON."),
+                };
+            },
             SerializerType::JSON => quote! {
                 let result = match near_sdk::serde_json::to_vec(&result) {
                     Ok(v) => v,
@@ -376,13 +510,17 @@ impl ImplItemMethodInfo {
         };
 
         match &self.attr_signature_info.method_kind {
-            Call(call_method) => value_ser(&call_method.result_serializer),
+            Call(call_method) => {
+                value_ser(&call_method.result_serializer, call_method.pretty_result)
+            }
 
             // There is no value returned on init, only the newly created contract is written to the
             // state.
             Init(_) => quote! {},
 
-            View(view_method) => value_ser(&view_method.result_serializer),
+            View(view_method) => {
+                value_ser(&view_method.result_serializer, view_method.pretty_result)
+            }
         }
     }
 
@@ -1,8 +1,9 @@
 use crate::core_impl::ext::generate_ext_function_wrappers;
 use crate::ItemImplInfo;
+use inflector::Inflector;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::ToTokens;
-use syn::{spanned::Spanned, Ident};
+use quote::{format_ident, quote, ToTokens};
+use syn::{spanned::Spanned, Ident, LitStr, Type};
 
 impl ItemImplInfo {
     /// Generate the code that wraps
@@ -15,7 +16,7 @@ impl ItemImplInfo {
     }
 
     pub fn generate_ext_wrapper_code(&self) -> TokenStream2 {
-        match syn::parse::<Ident>(self.ty.to_token_stream().into()) {
+        match self.type_ident() {
             Ok(n) => generate_ext_function_wrappers(
                 &n,
                 self.methods.iter().map(|m| &m.attr_signature_info),
@@ -23,13 +24,72 @@ impl ItemImplInfo {
             Err(e) => syn::Error::new(self.ty.span(), e).to_compile_error(),
         }
     }
+
+    /// The name of the contract type, without the type arguments of a monomorphized impl block.
+    fn type_ident(&self) -> syn::Result<Ident> {
+        match &self.ty {
+            Type::Path(ty) if ty.qself.is_none() && ty.path.segments.len() == 1 => {
+                Ok(ty.path.segments[0].ident.clone())
+            }
+            ty => syn::parse2::<Ident>(ty.to_token_stream()),
+        }
+    }
+
+    /// Generate a const listing the names of the exported functions of this impl block. The const
+    /// is called `name` if given, and `<TYPE>_METHODS` (or `<TYPE>_<TRAIT>_METHODS` for trait
+    /// impls) otherwise.
+    pub fn methods_const_code(&self, name: Option<&LitStr>) -> TokenStream2 {
+        let ty = match self.type_ident() {
+            Ok(ty) => ty,
+            Err(e) => return syn::Error::new(self.ty.span(), e).to_compile_error(),
+        };
+        let const_ident = match name {
+            Some(name) => match name.parse::<Ident>() {
+                Ok(ident) => ident,
+                Err(_) => {
+                    return syn::Error::new(
+                        name.span(),
+                        "`methods_const` must name the const with a valid identifier.",
+                    )
+                    .to_compile_error()
+                }
+            },
+            None => {
+                let mut const_name = ty.to_string().to_screaming_snake_case();
+                if let Some(trait_ident) =
+                    self.impl_trait.as_ref().and_then(|path| path.segments.last()).map(|s| &s.ident)
+                {
+                    const_name.push('_');
+                    const_name.push_str(&trait_ident.to_string().to_screaming_snake_case());
+                }
+                format_ident!("{}_METHODS", const_name, span = ty.span())
+            }
+        };
+
+        let names = self.methods.iter().map(|method| {
+            let cfg_attrs = method
+                .attr_signature_info
+                .non_bindgen_attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"));
+            let name = method.attr_signature_info.ident.to_string();
+            quote! { #(#cfg_attrs)* #name }
+        });
+        let doc = format!(" Names of the contract methods exported by this `{}` impl block.", ty);
+
+        quote! {
+            #[doc = #doc]
+            pub const #const_ident: &[&str] = &[#(#names),*];
+        }
+    }
 }
 // Rustfmt removes comas.
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use syn::{parse_quote, parse_str, ImplItemFn, Type};
-    use crate::core_impl::info_extractor::ImplItemMethodInfo;
+    use quote::ToTokens;
+    use syn::{parse_quote, parse_str, ImplItem, ImplItemFn, ItemImpl, Type};
+    use crate::core_impl::info_extractor::{ImplItemMethodInfo, ItemImplInfo};
     use crate::core_impl::utils::test_helpers::{local_insta_assert_snapshot, pretty_print_syn_str};
 
 
@@ -242,6 +302,30 @@ mod tests {
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
 
+    #[test]
+    fn payable_min_deposit() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[payable(min = "NearToken::from_millinear(1)")]
+            pub fn method(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn result_json_pretty() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[result_serializer(json_pretty)]
+            pub fn method(&self) -> Bar { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
     #[test]
     fn callback_args_mixed_serialization() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -271,6 +355,30 @@ mod tests {
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
 
+    #[test]
+    fn deprecated_log() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[near(deprecated_log = "use `new_method` instead")]
+            pub fn method(&self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn deprecated_method() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[deprecated_method(use = "new_method")]
+            pub fn method(&self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
     #[test]
     fn handle_result_json() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -308,6 +416,56 @@ mod tests {
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
 
+    #[test]
+    fn result_serializer_borsh_cfg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[cfg(feature = "snapshots")]
+            #[result_serializer(borsh)]
+            pub fn method(&self) -> Snapshot { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn handle_result_promise_or_value() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[handle_result]
+            pub fn method(&mut self) -> Result<PromiseOrValue<U128>, MyError> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn handle_result_promise_or_value_borsh() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[handle_result]
+            #[result_serializer(borsh)]
+            pub fn method(&mut self) -> Result<PromiseOrValue<U128>, MyError> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn handle_result_promise() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[handle_result]
+            pub fn method(&mut self) -> Result<near_sdk::Promise, &'static str> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
     #[test]
     fn handle_result_init() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -342,4 +500,148 @@ mod tests {
         let actual = method_info.method_wrapper();
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
+
+    #[test]
+    fn methods_const() {
+        let mut item: ItemImpl = parse_quote! {
+            impl StatusMessage {
+                #[init]
+                pub fn new() -> Self { }
+                #[private]
+                pub fn reset(&mut self) { }
+                #[cfg(feature = "extra")]
+                pub fn extra(&self) { }
+                pub fn get(&self) -> u8 { }
+                fn helper(&self) { }
+            }
+        };
+        let item_info = ItemImplInfo::new(&mut item).unwrap();
+        let actual = item_info.methods_const_code(None);
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn deny_unknown_arguments() {
+        let mut item: ItemImpl = parse_quote! {
+            impl Hello {
+                pub fn strict(&mut self, k: u64) { }
+                #[near(deny_unknown_arguments = false)]
+                pub fn lenient(&self, k: u64) { }
+            }
+        };
+        let mut item_info = ItemImplInfo::new(&mut item).unwrap();
+        item_info.deny_unknown_arguments();
+        let actual = item_info.wrapper_code();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn no_panic_hook() {
+        let mut item: ItemImpl = parse_quote! {
+            impl Hello {
+                pub fn method(&self) { }
+            }
+        };
+        let mut item_info = ItemImplInfo::new(&mut item).unwrap();
+        assert!(item_info.wrapper_code().to_string().contains("setup_panic_hook"));
+
+        item_info.no_panic_hook();
+        assert!(!item_info.wrapper_code().to_string().contains("setup_panic_hook"));
+    }
+
+    #[test]
+    fn max_input_bytes() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[max_input_bytes(1024)]
+            pub fn method(&self, k: String) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn migrate() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemFn = parse_quote! {
+            #[near(migrate(from = OldHello))]
+            pub fn migrate(old: OldHello, k: u64) -> Self { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        let actual = method_info.method_wrapper();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn monomorphized() {
+        let mut item: ItemImpl = parse_quote! {
+            impl<T: Clone> Hello<T> {
+                #[init]
+                pub fn new(value: T) -> Self { }
+                pub fn set(&mut self, values: Vec<T>) { }
+                pub fn get(&self) -> Option<T> { }
+            }
+        };
+        let item_info = ItemImplInfo::new_monomorphized(&mut item, &parse_str("Hello<u64>").unwrap()).unwrap();
+        let actual = item_info.wrapper_code();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+
+        // The impl block itself stays generic.
+        let ImplItem::Fn(new) = &item.items[0] else { unreachable!() };
+        assert_eq!(new.sig.output.to_token_stream().to_string(), "-> Hello < T >");
+    }
+
+    #[test]
+    fn monomorphize_errors() {
+        let mut item: ItemImpl = parse_quote! { impl Hello { } };
+        let err = ItemImplInfo::new_monomorphized(&mut item, &parse_str("Hello<u64>").unwrap()).map(|_| ()).unwrap_err();
+        assert_eq!(err.to_string(), "`monomorphize` can only be used on impl blocks with type parameters.");
+
+        let mut item: ItemImpl = parse_quote! { impl<T> Hello<T> { } };
+        for concrete in ["World<u64>", "Hello", "Hello<u64, u8>"] {
+            let err = ItemImplInfo::new_monomorphized(&mut item, &parse_str(concrete).unwrap()).map(|_| ()).unwrap_err();
+            assert!(err.to_string().starts_with("`monomorphize` must name the type of the impl block"));
+        }
+
+        let mut item: ItemImpl = parse_quote! { impl<'a, T> Hello<T> { } };
+        let err = ItemImplInfo::new_monomorphized(&mut item, &parse_str("Hello<u64>").unwrap()).map(|_| ()).unwrap_err();
+        assert_eq!(err.to_string(), "Only type parameters can be monomorphized.");
+    }
+
+    #[test]
+    fn methods_const_named() {
+        let mut item: ItemImpl = parse_quote! {
+            impl StatusMessage {
+                pub fn set_admin(&mut self) { }
+            }
+        };
+        let item_info = ItemImplInfo::new(&mut item).unwrap();
+        let actual = item_info.methods_const_code(Some(&parse_quote! { "ADMIN_METHODS" }));
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn methods_const_invalid_name() {
+        let mut item: ItemImpl = parse_quote! {
+            impl StatusMessage {
+                pub fn set_admin(&mut self) { }
+            }
+        };
+        let item_info = ItemImplInfo::new(&mut item).unwrap();
+        let actual = item_info.methods_const_code(Some(&parse_quote! { "admin methods" }));
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn methods_const_trait_impl() {
+        let mut item: ItemImpl = parse_quote! {
+            impl FungibleTokenCore for Contract {
+                fn ft_transfer(&mut self) { }
+            }
+        };
+        let item_info = ItemImplInfo::new(&mut item).unwrap();
+        let actual = item_info.methods_const_code(None);
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
@@ -1,7 +1,9 @@
 use crate::core_impl::ext::{generate_ext_function_wrappers, generate_ext_structs};
-use crate::core_impl::info_extractor::ItemTraitInfo;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use crate::core_impl::info_extractor::{ItemTraitInfo, ReturnKind, TraitItemMethodInfo};
+use crate::core_impl::utils;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, PathArguments, ReturnType, Type};
 
 impl ItemTraitInfo {
     /// Generate code that wraps external calls.
@@ -14,14 +16,80 @@ impl ItemTraitInfo {
             self.methods.iter().map(|m| &m.attr_sig_info),
         );
 
+        let return_aliases = self.methods.iter().filter_map(return_type_alias);
+
         quote! {
             pub mod #mod_name {
                 use super::*;
                 #ext_structs
                 #ext_methods
+
+                /// Types of the values returned by the methods of the contract, named after the
+                /// methods, e.g. to declare the `#[callback_result]` argument of a callback.
+                pub mod returns {
+                    use super::*;
+                    #(#return_aliases)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates a type alias for the value the method returns to the callback, i.e. `T` for both
+/// `PromiseOrValue<T>` and a `#[handle_result]` method returning `Result<T, E>`.
+///
+/// Methods returning a `Promise` or a borrowed value, with generics or mentioning `Self` are
+/// skipped, as their result type can't be named in the alias.
+fn return_type_alias(method: &TraitItemMethodInfo) -> Option<TokenStream2> {
+    let info = &method.attr_sig_info;
+    if !info.original_sig.generics.params.is_empty() {
+        return None;
+    }
+    if let ReturnType::Type(_, ty) = &info.original_sig.output {
+        if !can_alias(ty.to_token_stream()) {
+            return None;
+        }
+    }
+    let ty = match &info.returns.kind {
+        ReturnKind::Default => quote! { () },
+        ReturnKind::General(ty) => returned_value_type(ty)?,
+        ReturnKind::HandlesResult(ty) => returned_value_type(utils::extract_ok_type(ty)?)?,
+    };
+    let ident = &info.ident;
+    Some(quote! {
+        #[allow(non_camel_case_types)]
+        pub type #ident = #ty;
+    })
+}
+
+fn returned_value_type(ty: &Type) -> Option<TokenStream2> {
+    if utils::type_is_promise(ty) {
+        return None;
+    }
+    let Type::Path(type_path) = ty else {
+        return Some(ty.to_token_stream());
+    };
+    let Some(last) = type_path.path.segments.last() else {
+        return Some(ty.to_token_stream());
+    };
+    if last.ident == "PromiseOrValue" {
+        if let PathArguments::AngleBracketed(args) = &last.arguments {
+            if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                return Some(inner.to_token_stream());
             }
         }
     }
+    Some(ty.to_token_stream())
+}
+
+/// Returns `false` if the type mentions `Self` or is borrowed, as its lifetimes would be elided.
+fn can_alias(tokens: TokenStream2) -> bool {
+    tokens.into_iter().all(|token| match token {
+        TokenTree::Ident(ident) => ident != "Self",
+        TokenTree::Punct(punct) => punct.as_char() != '&' && punct.as_char() != '\'',
+        TokenTree::Group(group) => can_alias(group.stream()),
+        TokenTree::Literal(_) => true,
+    })
 }
 
 // Rustfmt removes comas.
@@ -56,6 +124,49 @@ mod tests {
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
 
+    #[test]
+    fn ext_static_gas() {
+        let mut t: ItemTrait = syn::parse2(
+            quote!{
+                pub trait ExternalCrossContract {
+                    #[gas(Gas::from_tgas(10))]
+                    fn with_gas(&self, arr: Vec<u8>);
+                    fn without_gas(&self);
+                }
+            }
+        ).unwrap();
+        let info = ItemTraitInfo::new(&mut t, None).unwrap();
+        let actual = info.wrap_trait_ext();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn ext_impl_only_attrs_fail() {
+        let mut t: ItemTrait = syn::parse2(
+            quote!{
+                pub trait ExternalCrossContract {
+                    #[max_input_bytes(64)]
+                    fn echo(&self, value: String) -> String;
+                }
+            }
+        ).unwrap();
+        let actual = ItemTraitInfo::new(&mut t, None).map(|_| ()).unwrap_err();
+        let expected = "`#[max_input_bytes]` can only be used on methods of `#[near]` impl blocks.";
+        assert_eq!(expected, actual.to_string());
+
+        let mut t: ItemTrait = syn::parse2(
+            quote!{
+                pub trait ExternalCrossContract {
+                    #[deprecated_method(use = "echo")]
+                    fn shout(&self, value: String) -> String;
+                }
+            }
+        ).unwrap();
+        let actual = ItemTraitInfo::new(&mut t, None).map(|_| ()).unwrap_err();
+        let expected = "`#[deprecated_method]` can only be used on methods of `#[near]` impl blocks.";
+        assert_eq!(expected, actual.to_string());
+    }
+
     #[test]
     fn serialize_with_borsh() {
         let mut t: ItemTrait = syn::parse2(
@@ -3,12 +3,31 @@ use quote::quote;
 use syn::Generics;
 
 /// Generates a view method to retrieve the source metadata.
+///
+/// With `hook` set, the metadata is deserialized and passed through the contract's
+/// `contract_source_metadata_hook` before being returned, which requires the contract state.
 pub(crate) fn generate_contract_metadata_method(
     ident: &Ident,
     generics: &Generics,
+    hook: bool,
 ) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    if hook {
+        return quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                pub fn contract_source_metadata(&self) {
+                    let metadata = near_sdk::serde_json::from_str(CONTRACT_SOURCE_METADATA)
+                        .unwrap_or_else(|_| near_sdk::env::abort());
+                    let metadata = self.contract_source_metadata_hook(metadata);
+                    let metadata = near_sdk::serde_json::to_vec(&metadata)
+                        .unwrap_or_else(|_| near_sdk::env::abort());
+                    near_sdk::env::value_return(&metadata)
+                }
+            }
+        };
+    }
     quote! {
-        impl #generics #ident #generics {
+        impl #impl_generics #ident #ty_generics #where_clause {
             pub fn contract_source_metadata() {
                 near_sdk::env::value_return(CONTRACT_SOURCE_METADATA.as_bytes())
             }
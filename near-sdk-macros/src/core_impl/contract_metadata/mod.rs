@@ -21,12 +21,18 @@ pub(crate) struct ContractMetadata {
 
     #[darling(skip)]
     build_info: Option<build_info::BuildInfo>,
+
+    /// Whether the metadata is passed through the contract's `contract_source_metadata_hook`
+    /// before being returned.
+    #[darling(default)]
+    #[serde(skip)]
+    hook: bool,
 }
 
 impl quote::ToTokens for ContractMetadata {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let version = &self.version;
-        let link = &self.link;
+        let version = self.version.as_ref().map(|version| quote! { version = #version, });
+        let link = self.link.as_ref().map(|link| quote! { link = #link, });
         let mut standards = quote! {};
         let standards_vec = &self.standards;
         for standard in standards_vec {
@@ -37,11 +43,17 @@ impl quote::ToTokens for ContractMetadata {
                 standard(standard = #standard_name, version = #standard_version),
             };
         }
+        let hook = if self.hook {
+            quote! { hook, }
+        } else {
+            quote! {}
+        };
         tokens.extend(quote! {
             contract_metadata(
-                version = #version,
-                link = #link,
+                #version
+                #link
                 #standards
+                #hook
             )
         })
     }
@@ -91,6 +103,17 @@ impl ContractMetadata {
     }
 }
 
+/// Returns whether the contract source metadata should be passed through the contract's
+/// `contract_source_metadata_hook`. Malformed attributes are reported by
+/// [`contract_source_metadata_const`].
+pub(crate) fn contract_source_metadata_hook(attr: proc_macro::TokenStream) -> bool {
+    NestedMeta::parse_meta_list(attr.into())
+        .ok()
+        .and_then(|attr_args| MacroConfig::from_list(&attr_args).ok())
+        .and_then(|args| args.contract_metadata)
+        .is_some_and(|metadata| metadata.hook)
+}
+
 /// Allows for the injection of the contract source metadata information into the contract code as
 /// a constant.
 pub(crate) fn contract_source_metadata_const(attr: proc_macro::TokenStream) -> TokenStream {
@@ -2,6 +2,8 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 
 use quote::quote;
+#[cfg(feature = "__abi-generate")]
+use quote::ToTokens;
 
 use darling::ast::NestedMeta;
 use darling::Error;
@@ -36,7 +38,8 @@ pub(crate) fn near_events(attr: TokenStream, item: TokenStream) -> TokenStream {
             return TokenStream::from(e.write_errors());
         }
     };
-    if let Some(standard) = args.event_json.and_then(|event_json| event_json.standard) {
+    let event_json = args.event_json.unwrap_or_default();
+    if let Some(standard) = event_json.standard {
         if let Ok(mut input) = syn::parse::<ItemEnum>(item) {
             let name = &input.ident;
             let standard_name = format!("{}_event_standard", name);
@@ -49,9 +52,15 @@ pub(crate) fn near_events(attr: TokenStream, item: TokenStream) -> TokenStream {
             input.attrs.push(parse_quote! (#[serde(tag = "event", content = "data")]));
             input.attrs.push(parse_quote! (#[serde(rename_all = "snake_case")]));
 
+            #[cfg(not(feature = "__abi-generate"))]
+            let abi_generated = quote! {};
+            #[cfg(feature = "__abi-generate")]
+            let abi_generated = generate_event_abi(&mut input, &standard);
+
             TokenStream::from(quote! {
                 const #standard_ident: &'static str = #standard;
                 #input
+                #abi_generated
             })
         } else {
             TokenStream::from(
@@ -73,6 +82,63 @@ pub(crate) fn near_events(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Exports the events as an ABI chunk without functions, see `near_sdk::__private::event_abi_chunk`.
+///
+/// The exported symbol is disambiguated with a hash of the standard and the enum, as enums with the
+/// same name may be declared in different modules or crates. It is also reachable as the hidden
+/// `__near_abi_event` associated function of the enum.
+///
+/// Events generic over types are skipped as there is no single schema to export for them.
+#[cfg(feature = "__abi-generate")]
+fn generate_event_abi(input: &mut ItemEnum, standard: &str) -> proc_macro2::TokenStream {
+    if input.generics.type_params().next().is_some()
+        || input.generics.const_params().next().is_some()
+    {
+        return quote! {};
+    }
+    let Some(versions) = input.variants.iter().map(get_event_version).collect::<Option<Vec<_>>>()
+    else {
+        // Reported by the `EventMetadata` derive.
+        return quote! {};
+    };
+    input.attrs.push(parse_quote! (#[derive(::near_sdk::schemars::JsonSchema)]));
+    input.attrs.push(parse_quote! (#[schemars(crate = "::near_sdk::schemars")]));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let lifetimes = input.generics.lifetimes().map(|_| quote! { 'static });
+    let near_abi_symbol = format!("__near_abi_event_{}_{:016x}", name, event_hash(input, standard));
+    quote! {
+        #[cfg(not(target_arch = "wasm32"))]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[export_name = #near_abi_symbol]
+            pub extern "C" fn __near_abi_event() -> (*const u8, usize) {
+                let mut data = ::std::mem::ManuallyDrop::new(
+                    ::near_sdk::__private::event_abi_chunk::<#name<#(#lifetimes),*>>(
+                        #standard,
+                        &[#(#versions),*],
+                    ),
+                );
+                data.shrink_to_fit();
+                assert!(data.len() == data.capacity());
+                (data.as_ptr(), data.len())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "__abi-generate")]
+fn event_hash(input: &ItemEnum, standard: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    standard.hash(&mut hasher);
+    input.to_token_stream().to_string().hash(&mut hasher);
+    format!("{:?}", input.ident.span()).hash(&mut hasher);
+    hasher.finish()
+}
+
 /// This function returns the `version` value from `#[event_version("x.x.x")]`.
 /// used by `near_sdk::EventMetadata`
 pub(crate) fn get_event_version(var: &syn::Variant) -> Option<LitStr> {
@@ -13,6 +13,8 @@ pub enum BindgenArgType {
     CallbackResultArg,
     /// An argument that we read from all `env::promise_result()`.
     CallbackArgVec,
+    /// The old contract state that a `#[near(migrate)]` method reads from storage.
+    MigrateState,
 }
 
 /// A single argument of a function after it was processed by the bindgen.
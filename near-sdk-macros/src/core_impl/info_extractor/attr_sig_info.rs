@@ -24,19 +24,50 @@ pub struct AttrSigInfo {
     pub input_serializer: SerializerType,
     /// The original method signature.
     pub original_sig: Signature,
+    /// Deprecation notice logged whenever the method is invoked.
+    pub deprecated_log: Option<String>,
+    /// Name of the method replacing this one, logged whenever the method is invoked.
+    pub deprecated_method: Option<String>,
+    /// Whether JSON arguments not known to the method are rejected, `None` to inherit the
+    /// setting of the impl block.
+    pub deny_unknown_arguments: Option<bool>,
+    /// Maximum length of the input in bytes, checked before the input is read.
+    pub max_input_bytes: Option<u64>,
+    /// Static gas attached by default when the method is called through the `ext` builder.
+    pub static_gas: Option<syn::Expr>,
+    /// Minimum attached deposit of a payable method, set by `#[payable(min = "...")]`.
+    pub min_deposit: Option<syn::Expr>,
 }
 
 use darling::FromAttributes;
 #[derive(darling::FromAttributes, Clone, Debug)]
 #[darling(
-    attributes(init, payable, private, result_serializer, serializer, handle_result),
+    attributes(init, payable, private, result_serializer, serializer, handle_result, near),
     forward_attrs(serializer)
 )]
 struct AttributeConfig {
     borsh: Option<bool>,
     json: Option<bool>,
+    json_pretty: Option<bool>,
     ignore_state: Option<bool>,
     aliased: Option<bool>,
+    view: Option<bool>,
+    deprecated_log: Option<String>,
+    deny_unknown_arguments: Option<bool>,
+    migrate: Option<MigrateConfig>,
+    min: Option<syn::Expr>,
+}
+
+#[derive(darling::FromMeta, Clone, Debug)]
+struct MigrateConfig {
+    #[darling(with = parse_type)]
+    from: Type,
+}
+
+/// Parses a type given as `name = Type`, which `darling` only accepts as a string literal.
+fn parse_type(meta: &syn::Meta) -> darling::Result<Type> {
+    let value = &meta.require_name_value()?.value;
+    syn::parse2(value.to_token_stream()).map_err(|err| darling::Error::from(err).with_span(value))
 }
 
 impl AttrSigInfo {
@@ -96,6 +127,12 @@ impl AttrSigInfo {
 
         let ident = original_sig.ident.clone();
         let mut non_bindgen_attrs = vec![];
+        let mut deprecated_log = None;
+        let mut deprecated_method = None;
+        let mut deny_unknown_arguments = None;
+        let mut max_input_bytes = None;
+        let mut static_gas = None;
+        let mut min_deposit = None;
 
         let args = AttributeConfig::from_attributes(original_attrs)?;
         // Visit attributes
@@ -103,6 +140,12 @@ impl AttrSigInfo {
             let attr_str = attr.path().to_token_stream().to_string();
             match attr_str.as_str() {
                 "init" => {
+                    if args.migrate.is_some() {
+                        return Err(Error::new(
+                            attr.span(),
+                            "Migrate function can't be an init function at the same time.",
+                        ));
+                    }
                     let mut init_attr = InitAttr { ignore_state: false };
                     if let Some(state) = args.ignore_state {
                         init_attr.ignore_state = state;
@@ -111,18 +154,22 @@ impl AttrSigInfo {
                 }
                 "payable" => {
                     visitor.visit_payable_attr(attr)?;
+                    min_deposit.clone_from(&args.min);
                 }
                 "private" => {
                     visitor.visit_private_attr(attr)?;
                 }
                 "result_serializer" => {
-                    if args.borsh.is_some() && args.json.is_some() {
+                    if [args.borsh, args.json, args.json_pretty].iter().flatten().count() > 1 {
                         return Err(Error::new(
                             attr.span(),
-                            "Only one of `borsh` or `json` can be specified.",
+                            "Only one of `borsh`, `json` or `json_pretty` can be specified.",
                         ));
                     };
-                    let mut serializer = SerializerAttr { serializer_type: SerializerType::JSON };
+                    let mut serializer = SerializerAttr {
+                        serializer_type: SerializerType::JSON,
+                        pretty: args.json_pretty.unwrap_or(false),
+                    };
                     if let Some(borsh) = args.borsh {
                         if borsh {
                             serializer.serializer_type = SerializerType::Borsh;
@@ -135,6 +182,31 @@ impl AttrSigInfo {
                     }
                     visitor.visit_result_serializer_attr(attr, &serializer)?;
                 }
+                "near" => {
+                    if args.view.unwrap_or(false) {
+                        visitor.visit_view_attr(attr)?;
+                    }
+                    if let Some(migrate) = &args.migrate {
+                        visitor.visit_migrate_attr(&migrate.from);
+                    }
+                    deprecated_log.clone_from(&args.deprecated_log);
+                    deny_unknown_arguments = args.deny_unknown_arguments;
+                }
+                "max_input_bytes" => {
+                    let limit = attr.parse_args::<syn::LitInt>()?.base10_parse::<u64>()?;
+                    max_input_bytes = Some(limit);
+                }
+                "deprecated_method" => {
+                    let replacement = attr.parse_args_with(|input: syn::parse::ParseStream| {
+                        input.parse::<syn::Token![use]>()?;
+                        input.parse::<syn::Token![=]>()?;
+                        input.parse::<syn::LitStr>()
+                    })?;
+                    deprecated_method = Some(replacement.value());
+                }
+                "gas" => {
+                    static_gas = Some(attr.parse_args::<syn::Expr>()?);
+                }
                 "handle_result" => {
                     if let Some(value) = args.aliased {
                         let handle_result = HandleResultAttr { check: value };
@@ -150,6 +222,15 @@ impl AttrSigInfo {
             }
         }
 
+        if args.min.is_some() && min_deposit.is_none() {
+            return Err(Error::new(
+                original_sig.span(),
+                "`min` can only be specified on `#[payable]`.",
+            ));
+        }
+
+        let is_migrate = args.migrate.is_some();
+
         // Visit arguments
         let mut args = vec![];
         for fn_arg in &mut original_sig.inputs {
@@ -161,6 +242,20 @@ impl AttrSigInfo {
             }
         }
 
+        if is_migrate {
+            match args.first_mut() {
+                Some(arg) if matches!(arg.bindgen_ty, BindgenArgType::Regular) => {
+                    arg.bindgen_ty = BindgenArgType::MigrateState;
+                }
+                _ => {
+                    return Err(Error::new(
+                        original_sig.span(),
+                        "Migrate function must take the old state as its first argument.",
+                    ));
+                }
+            }
+        }
+
         let (method_kind, returns) = visitor.build()?;
 
         self_occurrences.extend(args.iter().flat_map(|arg| arg.self_occurrences.clone()));
@@ -174,6 +269,13 @@ impl AttrSigInfo {
             )?;
         }
 
+        if deprecated_log.is_some() && deprecated_method.is_some() {
+            return Err(Error::new(
+                original_sig.span(),
+                "Only one of `deprecated_log` or `deprecated_method` can be specified.",
+            ));
+        }
+
         let mut result = AttrSigInfo {
             ident,
             non_bindgen_attrs,
@@ -182,6 +284,12 @@ impl AttrSigInfo {
             returns,
             input_serializer: SerializerType::JSON,
             original_sig: original_sig.clone(),
+            deprecated_log,
+            deprecated_method,
+            deny_unknown_arguments,
+            max_input_bytes,
+            static_gas,
+            min_deposit,
         };
 
         let input_serializer =
@@ -1,7 +1,8 @@
 use crate::core_impl::info_extractor::AttrSigInfo;
 use crate::core_impl::utils;
 use quote::ToTokens;
-use syn::{ImplItemFn as ImplItemMethod, Path, Type, Visibility};
+use syn::spanned::Spanned;
+use syn::{Error, ImplItemFn as ImplItemMethod, Path, Type, Visibility};
 
 /// Information extracted from `ImplItemMethod`.
 pub struct ImplItemMethodInfo {
@@ -11,6 +12,8 @@ pub struct ImplItemMethodInfo {
     pub struct_type: Type,
     /// The trait that this method is implemented for.
     pub impl_trait: Option<Path>,
+    /// Whether the wrapper sets up the panic hook, see `ItemImplInfo::no_panic_hook`.
+    pub panic_hook: bool,
 }
 
 impl ImplItemMethodInfo {
@@ -23,9 +26,15 @@ impl ImplItemMethodInfo {
         let ImplItemMethod { attrs, sig, .. } = original;
         utils::sig_is_supported(sig)?;
         if impl_trait.is_some() || matches!(original.vis, Visibility::Public(_)) {
+            if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("gas")) {
+                return Err(Error::new(
+                    attr.span(),
+                    "`#[gas]` can only be used on methods of `#[ext_contract]` traits.",
+                ));
+            }
             let source_type = &struct_type.to_token_stream();
             let attr_signature_info = AttrSigInfo::new(attrs, sig, source_type)?;
-            Ok(Some(Self { attr_signature_info, struct_type, impl_trait }))
+            Ok(Some(Self { attr_signature_info, struct_type, impl_trait, panic_hook: true }))
         } else {
             Ok(None)
         }
@@ -37,7 +46,7 @@ impl ImplItemMethodInfo {
 #[cfg(test)]
 mod tests {
     use syn::{parse_quote, Type, ImplItemFn as ImplItemMethod , ReturnType};
-    use crate::core_impl::ImplItemMethodInfo;
+    use crate::core_impl::{ImplItemMethodInfo, MethodKind};
 
     #[test]
     fn init_no_return() {
@@ -112,4 +121,53 @@ mod tests {
         let expected = "View function can't be payable.";
         assert_eq!(expected.to_string(), actual.to_string());
     }
+
+    #[test]
+    fn explicit_view() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[near(view)]
+            pub fn method(&self) -> u64 { }
+        };
+        let method = ImplItemMethodInfo::new(&mut method, None, impl_type).unwrap().unwrap();
+        assert!(matches!(method.attr_signature_info.method_kind, MethodKind::View(_)));
+        assert!(method.attr_signature_info.non_bindgen_attrs.is_empty());
+    }
+
+    #[test]
+    fn explicit_view_mut_self_fails() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[near(view)]
+            pub fn method(&mut self) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, None, impl_type).map(|_| ()).unwrap_err();
+        let expected = "View function can't take `&mut self` since its state is never written back.";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn explicit_view_init_fails() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[init]
+            #[near(view)]
+            pub fn new() -> Self { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, None, impl_type).map(|_| ()).unwrap_err();
+        let expected = "Init function can't be a view function.";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn static_gas_fails() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[gas(Gas::from_tgas(10))]
+            pub fn method(&self) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, None, impl_type).map(|_| ()).unwrap_err();
+        let expected = "`#[gas]` can only be used on methods of `#[ext_contract]` traits.";
+        assert_eq!(expected, actual.to_string());
+    }
 }
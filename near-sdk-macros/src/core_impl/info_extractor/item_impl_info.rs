@@ -1,11 +1,19 @@
+use crate::core_impl::utils;
 use crate::ImplItemMethodInfo;
+use proc_macro2::Ident;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Error, ImplItem, ItemImpl, Type};
+use syn::{
+    Error, FnArg, GenericArgument, GenericParam, ImplItem, ImplItemFn, ItemImpl, Path,
+    PathArguments, ReturnType, Token, Type,
+};
 
 /// Information extracted from `impl` section.
 pub struct ItemImplInfo {
     /// The type for which this `impl` is written.
     pub ty: Type,
+    /// The trait being implemented, if this is a trait `impl`.
+    pub impl_trait: Option<Path>,
     /// Info extracted for each public method.
     pub methods: Vec<ImplItemMethodInfo>,
 }
@@ -15,17 +23,46 @@ impl ItemImplInfo {
         if !original.generics.params.is_empty() {
             return Err(Error::new(
                 original.generics.params.span(),
-                "Impl type parameters are not supported for smart contracts.",
+                "Impl type parameters are not supported for smart contracts. Use \
+                 `#[near(monomorphize = \"...\")]` to export the methods for concrete type arguments.",
             ));
         }
-        let ty = (*original.self_ty.as_ref()).clone();
+        let ty = with_turbofish((*original.self_ty.as_ref()).clone());
+        Self::from_methods(original, ty, &[])
+    }
+
+    /// Same as [`Self::new`], but for an impl block with type parameters. The methods are exported
+    /// for `concrete`, the implemented type with concrete type arguments, e.g. `Contract<u64>`.
+    pub fn new_monomorphized(original: &mut ItemImpl, concrete: &Type) -> syn::Result<Self> {
+        if original.generics.params.is_empty() {
+            return Err(Error::new(
+                concrete.span(),
+                "`monomorphize` can only be used on impl blocks with type parameters.",
+            ));
+        }
+        let substitutions =
+            type_param_substitutions(&original.self_ty, &original.generics.params, concrete)?;
+        Self::from_methods(original, with_turbofish(concrete.clone()), &substitutions)
+    }
+
+    fn from_methods(
+        original: &mut ItemImpl,
+        ty: Type,
+        substitutions: &[(Ident, Type)],
+    ) -> syn::Result<Self> {
+        let generic_ty = (*original.self_ty.as_ref()).clone();
         let trait_ = original.trait_.as_ref().map(|(_not, path, _for)| path);
 
         let mut methods = vec![];
         let mut errors = vec![];
         for subitem in &mut original.items {
             if let ImplItem::Fn(m) = subitem {
-                match ImplItemMethodInfo::new(m, trait_.cloned(), ty.clone()) {
+                let method_info = if substitutions.is_empty() {
+                    ImplItemMethodInfo::new(m, trait_.cloned(), ty.clone())
+                } else {
+                    monomorphized_method_info(m, trait_, &generic_ty, &ty, substitutions)
+                };
+                match method_info {
                     Ok(Some(method_info)) => methods.push(method_info),
                     Ok(None) => {} // do nothing
                     Err(e) => errors.push(e),
@@ -42,6 +79,115 @@ impl ItemImplInfo {
             return Err(combined_error.unwrap());
         }
 
-        Ok(Self { ty, methods })
+        Ok(Self { ty, impl_trait: trait_.cloned(), methods })
+    }
+
+    /// Rejects unknown JSON arguments in all methods that don't set it themselves.
+    pub fn deny_unknown_arguments(&mut self) {
+        for method in &mut self.methods {
+            method.attr_signature_info.deny_unknown_arguments.get_or_insert(true);
+        }
+    }
+
+    /// Omits the panic hook setup from the wrappers of all methods.
+    pub fn no_panic_hook(&mut self) {
+        for method in &mut self.methods {
+            method.panic_hook = false;
+        }
+    }
+}
+
+/// Writes the type arguments of `ty` in turbofish form, e.g. `Contract::<u64>`, so that the type
+/// can also be used to call associated functions in the generated code.
+fn with_turbofish(mut ty: Type) -> Type {
+    if let Type::Path(ty) = &mut ty {
+        for segment in &mut ty.path.segments {
+            if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token.get_or_insert_with(Default::default);
+            }
+        }
+    }
+    ty
+}
+
+/// Maps the type parameters of an impl block for `self_ty` to the matching type arguments of
+/// `concrete`.
+fn type_param_substitutions(
+    self_ty: &Type,
+    params: &Punctuated<GenericParam, Token![,]>,
+    concrete: &Type,
+) -> syn::Result<Vec<(Ident, Type)>> {
+    let mismatch = || {
+        Error::new(
+            concrete.span(),
+            "`monomorphize` must name the type of the impl block with concrete type arguments, \
+             e.g. `Contract<u64>`.",
+        )
+    };
+    let last_segment = |ty: &Type| match ty {
+        Type::Path(ty) if ty.qself.is_none() => ty.path.segments.last().cloned(),
+        _ => None,
+    };
+    let (generic, concrete) =
+        last_segment(self_ty).zip(last_segment(concrete)).ok_or_else(mismatch)?;
+    let (PathArguments::AngleBracketed(generic_args), PathArguments::AngleBracketed(concrete_args)) =
+        (&generic.arguments, &concrete.arguments)
+    else {
+        return Err(mismatch());
+    };
+    if generic.ident != concrete.ident || generic_args.args.len() != concrete_args.args.len() {
+        return Err(mismatch());
+    }
+
+    params
+        .iter()
+        .map(|param| {
+            let GenericParam::Type(param) = param else {
+                return Err(Error::new(param.span(), "Only type parameters can be monomorphized."));
+            };
+            let concrete_arg = generic_args.args.iter().zip(&concrete_args.args).find_map(
+                |(generic_arg, concrete_arg)| match (generic_arg, concrete_arg) {
+                    (
+                        GenericArgument::Type(Type::Path(generic_arg)),
+                        GenericArgument::Type(concrete_arg),
+                    ) if generic_arg.path.is_ident(&param.ident) => Some(concrete_arg.clone()),
+                    _ => None,
+                },
+            );
+            match concrete_arg {
+                Some(concrete_arg) => Ok((param.ident.clone(), concrete_arg)),
+                None => Err(Error::new(
+                    param.ident.span(),
+                    "Type parameter must be a type argument of the implemented type to be \
+                     monomorphized.",
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Extracts the info of a method of a monomorphized impl block. The info comes from a copy of the
+/// method with the type parameters substituted, while `original` itself is processed against the
+/// generic type, since it stays in the generic impl block.
+fn monomorphized_method_info(
+    original: &mut ImplItemFn,
+    impl_trait: Option<&Path>,
+    generic_ty: &Type,
+    concrete_ty: &Type,
+    substitutions: &[(Ident, Type)],
+) -> syn::Result<Option<ImplItemMethodInfo>> {
+    let mut method = original.clone();
+    for arg in &mut method.sig.inputs {
+        if let FnArg::Typed(arg) = arg {
+            *arg.ty = utils::substitute_type_params(&arg.ty, substitutions)?;
+        }
+    }
+    if let ReturnType::Type(_, ty) = &mut method.sig.output {
+        **ty = utils::substitute_type_params(ty, substitutions)?;
+    }
+
+    if ImplItemMethodInfo::new(original, impl_trait.cloned(), generic_ty.clone())?.is_none() {
+        return Ok(None);
     }
+    ImplItemMethodInfo::new(&mut method, impl_trait.cloned(), concrete_ty.clone())
 }
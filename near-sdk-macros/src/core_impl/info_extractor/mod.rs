@@ -53,6 +53,8 @@ pub struct CallMethod {
     pub is_private: bool,
     /// The serializer that we use for the return type.
     pub result_serializer: SerializerType,
+    /// Whether the JSON return value is pretty-printed, set by `#[result_serializer(json_pretty)]`.
+    pub pretty_result: bool,
     /// The receiver, like `mut self`, `self`, `&mut self`, `&self`, or `None`.
     pub receiver: Option<Receiver>,
 }
@@ -63,6 +65,8 @@ pub struct ViewMethod {
     pub is_private: bool,
     /// The serializer that we use for the return type.
     pub result_serializer: SerializerType,
+    /// Whether the JSON return value is pretty-printed, set by `#[result_serializer(json_pretty)]`.
+    pub pretty_result: bool,
     /// The receiver, like `mut self`, `self`, `&mut self`, `&self`, or `None`.
     pub receiver: Option<Receiver>,
 }
@@ -73,6 +77,8 @@ pub struct InitMethod {
     pub is_payable: bool,
     /// Whether init method ignores state
     pub ignores_state: bool,
+    /// The type of the old state, if this is a `#[near(migrate(from = ...))]` method.
+    pub migrate_from: Option<Type>,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -2,4 +2,6 @@ use super::SerializerType;
 
 pub struct SerializerAttr {
     pub serializer_type: SerializerType,
+    /// Whether JSON output is pretty-printed.
+    pub pretty: bool,
 }
@@ -29,6 +29,15 @@ impl TraitItemMethodInfo {
 
         let TraitItemFn { attrs, sig, .. } = original;
 
+        for name in ["max_input_bytes", "deprecated_method"] {
+            if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident(name)) {
+                return Err(Error::new(
+                    attr.span(),
+                    format!("`#[{}]` can only be used on methods of `#[near]` impl blocks.", name),
+                ));
+            }
+        }
+
         utils::sig_is_supported(sig)?;
         let attr_sig_info = AttrSigInfo::new(attrs, sig, trait_name)?;
 
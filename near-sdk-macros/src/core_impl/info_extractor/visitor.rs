@@ -1,7 +1,10 @@
 use super::{HandleResultAttr, InitAttr, MethodKind, ReturnKind, SerializerAttr};
 use crate::core_impl::{utils, CallMethod, InitMethod, Returns, SerializerType, ViewMethod};
 use quote::ToTokens;
-use syn::{spanned::Spanned, Attribute, Error, FnArg, Receiver, ReturnType, Signature, Type};
+use syn::punctuated::Punctuated;
+use syn::{
+    spanned::Spanned, Attribute, Error, FnArg, Meta, Receiver, ReturnType, Signature, Token, Type,
+};
 
 /// Traversal abstraction to walk a method declaration and build it's respective [MethodKind].
 pub struct Visitor {
@@ -15,7 +18,9 @@ struct ParsedData {
     is_payable: bool,
     is_private: bool,
     ignores_state: bool,
+    migrate_from: Option<Type>,
     result_serializer: SerializerType,
+    pretty_result: bool,
     receiver: Option<Receiver>,
 }
 
@@ -42,7 +47,9 @@ impl Default for ParsedData {
             is_payable: Default::default(),
             is_private: Default::default(),
             ignores_state: Default::default(),
+            migrate_from: Default::default(),
             result_serializer: SerializerType::JSON,
+            pretty_result: Default::default(),
             receiver: Default::default(),
         }
     }
@@ -60,7 +67,7 @@ impl Visitor {
         use VisitorKind::*;
 
         // Run early checks to determine the method type
-        let kind = if is_init(original_attrs) {
+        let kind = if is_init(original_attrs) || is_migrate(original_attrs) {
             Init
         } else if is_view(original_sig) {
             View
@@ -87,6 +94,10 @@ impl Visitor {
         }
     }
 
+    pub fn visit_migrate_attr(&mut self, from: &Type) {
+        self.parsed_data.migrate_from = Some(from.clone());
+    }
+
     pub fn visit_payable_attr(&mut self, attr: &Attribute) -> syn::Result<()> {
         use VisitorKind::*;
 
@@ -102,6 +113,22 @@ impl Visitor {
         }
     }
 
+    pub fn visit_view_attr(&mut self, attr: &Attribute) -> syn::Result<()> {
+        use VisitorKind::*;
+
+        match self.kind {
+            View => Ok(()),
+            Call => Err(Error::new(
+                attr.span(),
+                "View function can't take `&mut self` since its state is never written back.",
+            )),
+            Init => {
+                let message = format!("{} function can't be a view function.", self.kind);
+                Err(Error::new(attr.span(), message))
+            }
+        }
+    }
+
     pub fn visit_private_attr(&mut self, _attr: &Attribute) -> syn::Result<()> {
         self.parsed_data.is_private = true;
         Ok(())
@@ -117,6 +144,7 @@ impl Visitor {
         match self.kind {
             Call | View => {
                 self.parsed_data.result_serializer = result_serializer_attr.serializer_type.clone();
+                self.parsed_data.pretty_result = result_serializer_attr.pretty;
                 Ok(())
             }
             Init => {
@@ -178,15 +206,31 @@ impl Visitor {
         let Visitor { kind, parsed_data, .. } = self;
 
         let ParsedData {
-            is_payable, is_private, ignores_state, result_serializer, receiver, ..
+            is_payable,
+            is_private,
+            ignores_state,
+            migrate_from,
+            result_serializer,
+            pretty_result,
+            receiver,
+            ..
         } = parsed_data;
 
         let method_kind = match kind {
-            Call => {
-                MethodKind::Call(CallMethod { is_payable, is_private, result_serializer, receiver })
-            }
-            Init => MethodKind::Init(InitMethod { is_payable, ignores_state }),
-            View => MethodKind::View(ViewMethod { is_private, result_serializer, receiver }),
+            Call => MethodKind::Call(CallMethod {
+                is_payable,
+                is_private,
+                result_serializer,
+                pretty_result,
+                receiver,
+            }),
+            Init => MethodKind::Init(InitMethod { is_payable, ignores_state, migrate_from }),
+            View => MethodKind::View(ViewMethod {
+                is_private,
+                result_serializer,
+                pretty_result,
+                receiver,
+            }),
         };
 
         Ok((method_kind, returns))
@@ -197,6 +241,14 @@ fn is_init(attributes: &[Attribute]) -> bool {
     attributes.iter().any(|a| a.path().to_token_stream().to_string() == "init")
 }
 
+fn is_migrate(attributes: &[Attribute]) -> bool {
+    attributes.iter().filter(|a| a.path().is_ident("near")).any(|a| {
+        a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .map(|metas| metas.iter().any(|meta| meta.path().is_ident("migrate")))
+            .unwrap_or(false)
+    })
+}
+
 fn is_view(sig: &Signature) -> bool {
     let receiver_opt = sig.inputs.iter().find_map(|arg| match arg {
         FnArg::Receiver(r) => Some(r),
@@ -6,7 +6,7 @@ mod event;
 mod info_extractor;
 mod utils;
 pub(crate) use code_generator::*;
-pub(crate) use contract_metadata::contract_source_metadata_const;
 pub(crate) use contract_metadata::ContractMetadata;
+pub(crate) use contract_metadata::{contract_source_metadata_const, contract_source_metadata_hook};
 pub(crate) use event::{get_event_version, near_events};
 pub(crate) use info_extractor::*;
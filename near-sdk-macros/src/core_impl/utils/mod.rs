@@ -1,4 +1,4 @@
-use proc_macro2::{Group, Span, TokenStream as TokenStream2, TokenTree};
+use proc_macro2::{Group, Ident, Span, TokenStream as TokenStream2, TokenTree};
 use quote::quote;
 use syn::spanned::Spanned;
 use syn::token::{And, Mut};
@@ -47,6 +47,20 @@ pub(crate) fn extract_ok_type(ty: &Type) -> Option<&Type> {
     }
 }
 
+/// Checks whether the given type is a `Promise`, either imported or qualified like
+/// `near_sdk::Promise`.
+/// Note that it won't match a type alias like `type MyPromise = Promise`.
+pub(crate) fn type_is_promise(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|last| last.ident == "Promise" && last.arguments.is_none()),
+        _ => false,
+    }
+}
+
 /// Checks whether the given path is literally "Vec".
 /// Note that it won't match a fully qualified name `std::vec::Vec` or a type alias like
 /// `type MyVec = Vec<String>`.
@@ -147,6 +161,39 @@ pub fn sanitize_self(typ: &Type, replace_with: &TokenStream2) -> syn::Result<San
     Ok(SanitizeSelfResult { ty, self_occurrences })
 }
 
+/// Replaces the type parameters in `typ` with the concrete types they are mapped to.
+pub(crate) fn substitute_type_params(
+    typ: &Type,
+    substitutions: &[(Ident, Type)],
+) -> syn::Result<Type> {
+    let ty_tokens = _substitute_type_params(quote! { #typ }, substitutions);
+
+    syn::parse2(ty_tokens).map_err(|original| {
+        syn::Error::new(
+            original.span(),
+            "Type parameter substitution failed. Please report this as a bug.",
+        )
+    })
+}
+
+fn _substitute_type_params(typ: TokenStream2, substitutions: &[(Ident, Type)]) -> TokenStream2 {
+    typ.into_iter()
+        .map(|t| match t {
+            TokenTree::Ident(ident) => {
+                match substitutions.iter().find(|(param, _)| *param == ident) {
+                    Some((_, concrete)) => quote! { #concrete },
+                    None => TokenTree::Ident(ident).into(),
+                }
+            }
+            TokenTree::Group(group) => {
+                let stream = _substitute_type_params(group.stream(), substitutions);
+                TokenTree::Group(Group::new(group.delimiter(), stream)).into()
+            }
+            rest => rest.into(),
+        })
+        .collect()
+}
+
 pub struct SanitizeSelfResult {
     pub ty: Type,
     pub self_occurrences: Vec<Span>,
@@ -182,6 +229,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn substitute_type_params_works() {
+        let substitutions = vec![
+            (syn::parse_str("T").unwrap(), syn::parse_str("u64").unwrap()),
+            (syn::parse_str("K").unwrap(), syn::parse_str("Vec<String>").unwrap()),
+        ];
+
+        let typ: Type = syn::parse_str("T").unwrap();
+        let substituted = substitute_type_params(&typ, &substitutions).unwrap();
+        assert_eq!(quote! { #substituted }.to_string(), "u64");
+
+        let typ: Type = syn::parse_str("Option<[(T, Result<K, Tx>); 2]>").unwrap();
+        let substituted = substitute_type_params(&typ, &substitutions).unwrap();
+        assert_eq!(
+            quote! { #substituted }.to_string(),
+            "Option < [(u64 , Result < Vec < String > , Tx >) ; 2] >"
+        );
+    }
+
     #[test]
     fn sanitize_self_keeps_track_of_replacements() {
         let typ: Type = syn::parse_str("Self").unwrap();
@@ -9,6 +9,7 @@ use proc_macro::TokenStream;
 
 use self::core_impl::*;
 use darling::ast::NestedMeta;
+use darling::util::Override;
 use darling::{Error, FromMeta};
 use proc_macro2::{Ident, Span};
 use quote::{quote, ToTokens};
@@ -41,6 +42,22 @@ struct NearMacroArgs {
     contract_state: Option<bool>,
     contract_metadata: Option<core_impl::ContractMetadata>,
     inside_nearsdk: Option<bool>,
+    methods_const: Option<Override<syn::LitStr>>,
+    deny_unknown_arguments: Option<bool>,
+    no_panic_hook: Option<bool>,
+    monomorphize: Option<syn::LitStr>,
+    derive: Option<darling::util::PathList>,
+}
+
+/// Arguments of `#[near_bindgen(...)]` on impl blocks.
+#[derive(FromMeta, Default)]
+struct ImplBlockArgs {
+    methods_const: Option<Override<syn::LitStr>>,
+    #[darling(default)]
+    deny_unknown_arguments: bool,
+    #[darling(default)]
+    no_panic_hook: bool,
+    monomorphize: Option<syn::Type>,
 }
 
 /// This attribute macro is used on a struct and its implementations
@@ -76,7 +93,7 @@ struct NearMacroArgs {
 /// `#[near]` will also handle serializing and setting the return value of the
 /// function execution based on what type is returned by the function. By default, this will be
 /// done through `serde` serialized as JSON, but this can be overwritten using
-/// `#[result_serializer(borsh)]`:
+/// `#[result_serializer(borsh)]`, or `#[result_serializer(json_pretty)]` for indented JSON:
 /// ```ignore
 /// #[near]
 /// impl Adder {
@@ -94,6 +111,28 @@ struct NearMacroArgs {
 /// Or use `#[near(serializers=[json])]` to make it serializable with Json. By default, borsh is used.
 /// You can also specify both and none. BorshSchema or JsonSchema are always generated.
 ///
+/// Bounds for the generated borsh implementations can be overridden with
+/// `#[near(serializers=[borsh(bound(...))])]`, which takes the same arguments as borsh's
+/// `#[borsh(bound(...))]` field attribute and applies it to every field that does not have a
+/// `#[borsh(...)]` attribute of its own:
+///
+/// ```ignore
+/// #[near(serializers=[borsh(bound(deserialize = "K: BorshDeserialize + Ord, V: BorshDeserialize"))])]
+/// pub struct Index<K, V> {
+///     entries: BTreeMap<K, V>,
+/// }
+/// ```
+///
+/// Additional derives can be passed with `derive(...)`, which places them after the derives
+/// generated by the macro:
+///
+/// ```ignore
+/// #[near(contract_state, derive(Clone, Debug))]
+/// pub struct Contract {
+///    data: i8,
+/// }
+/// ```
+///
 /// If you want the struct to be a contract state, you can pass in the contract_state argument.
 ///
 /// ## Example
@@ -112,6 +151,93 @@ struct NearMacroArgs {
 /// ```
 /// As well, the macro supports arguments like `event_json` and `contract_metadata`.
 ///
+/// # Exported method names
+///
+/// Passing `methods_const` to `#[near]` on an impl block additionally emits a
+/// `pub const <TYPE>_METHODS: &[&str]` (`<TYPE>_<TRAIT>_METHODS` for trait impls) with the names
+/// of the functions exported by that block, `#[init]` and `#[private]` ones included. This is
+/// handy for building function call access key method lists.
+///
+/// ## Example
+/// ```ignore
+/// #[near(methods_const)]
+/// impl StatusMessage {
+///     pub fn set_status(&mut self, message: String) {}
+///     pub fn get_status(&self) -> String {}
+/// }
+///
+/// assert_eq!(STATUS_MESSAGE_METHODS, &["set_status", "get_status"]);
+/// ```
+///
+/// The const is emitted per impl block. Name it with `methods_const = "NAME"` when more than one
+/// inherent impl block of a type opts in, as the default names would clash.
+///
+/// ```ignore
+/// #[near(methods_const = "STATUS_MESSAGE_ADMIN_METHODS")]
+/// impl StatusMessage {
+///     pub fn set_admin(&mut self, admin: AccountId) {}
+/// }
+/// ```
+///
+/// # Rejecting unknown arguments
+///
+/// Passing `deny_unknown_arguments` to `#[near]` on an impl block makes all methods of that block
+/// fail to deserialize JSON arguments that contain fields they don't declare, instead of ignoring
+/// them. A method can opt out with `#[near(deny_unknown_arguments = false)]`, or opt in by itself
+/// with `#[near(deny_unknown_arguments)]`.
+///
+/// ## Example
+/// ```ignore
+/// #[near(deny_unknown_arguments)]
+/// impl Contract {
+///     // Fails on `{"amount": "1", "memo": "..."}`.
+///     pub fn withdraw(&mut self, amount: U128) {}
+///
+///     #[near(deny_unknown_arguments = false)]
+///     pub fn ping(&self, nonce: u64) {}
+/// }
+/// ```
+///
+/// # Skipping the panic hook
+///
+/// Every exported method calls `env::setup_panic_hook()` first, so that a panic fails the call
+/// with its message. Passing `no_panic_hook` to `#[near]` on an impl block omits that call from the
+/// methods of the block, which saves the panic formatting machinery in very small contracts. A
+/// panic then aborts with a generic `WebAssembly trap: An unreachable opcode was executed` error
+/// and its message is lost. `env::panic_str` and `require!` are not affected.
+///
+/// ## Example
+/// ```ignore
+/// #[near(no_panic_hook)]
+/// impl Contract {
+///     pub fn get(&self) -> u64 {
+///         self.value
+///     }
+/// }
+/// ```
+///
+/// # Generic contract state
+///
+/// Exported functions can't be generic, so `#[near]` rejects impl blocks with type parameters.
+/// Passing `monomorphize = "Type<Args>"` to the contract state and to its impl blocks exports
+/// their methods for those concrete type arguments instead.
+///
+/// ## Example
+/// ```ignore
+/// #[near(contract_state, monomorphize = "Registry<u64>")]
+/// #[derive(Default)]
+/// pub struct Registry<T: BorshSerialize + BorshDeserialize> {
+///     items: Vec<T>,
+/// }
+///
+/// #[near(monomorphize = "Registry<u64>")]
+/// impl<T: BorshSerialize + BorshDeserialize + Serialize + Clone> Registry<T> {
+///     pub fn get(&self, index: u32) -> Option<T> {
+///         self.items.get(index as usize).cloned()
+///     }
+/// }
+/// ```
+///
 /// # Events Standard:
 ///
 /// By passing `event_json` as an argument `near_bindgen` will generate the relevant code to format events
@@ -122,6 +248,12 @@ struct NearMacroArgs {
 /// The `standard` and `version` values must be included in the enum and variant declaration (see example below).
 /// By default this will be JSON deserialized with `serde`
 ///
+/// The events are exported in the ABI under an `events` section, with the `standard`, `version`,
+/// `event` name and `data` schema of every variant. The full log of each variant is also described
+/// in the definitions of the ABI root schema under the name of the enum. This requires the data of
+/// every variant to implement `JsonSchema` when the ABI is generated. Events generic over types
+/// are not exported.
+///
 ///
 /// ## Examples
 ///
@@ -162,6 +294,12 @@ struct NearMacroArgs {
 /// The `contract_source_metadata()` view function will be added and can be used to retrieve the source metadata.
 /// Also, the source metadata will be stored as a constant, `CONTRACT_SOURCE_METADATA`, in the contract code.
 ///
+/// Passing `hook` to `contract_metadata` makes `contract_source_metadata()` deserialize the constant and
+/// pass it through a `contract_source_metadata_hook(&self, metadata)` method of the contract before
+/// returning it, e.g. to add values only known at build or run time. The hook is usually provided by
+/// implementing `near_contract_standards::contract_metadata::ContractSourceMetadataHook`, and since it
+/// takes `&self`, the view then requires the contract state to be initialized.
+///
 /// ## Examples
 /// ```ignore
 /// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
@@ -207,18 +345,31 @@ pub fn near(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     let mut expanded: proc_macro2::TokenStream = quote! {};
 
+    let monomorphize = near_macro_args.monomorphize.as_ref().map(|ty| quote! {monomorphize = #ty});
     if near_macro_args.contract_state.unwrap_or(false) {
-        if let Some(metadata) = near_macro_args.contract_metadata {
-            expanded = quote! {#[#near_sdk_crate::near_bindgen(#metadata)]}
-        } else {
+        let state_args =
+            near_macro_args.contract_metadata.iter().map(|metadata| quote! {#metadata});
+        let state_args = state_args.chain(monomorphize.clone()).collect::<Vec<_>>();
+        if state_args.is_empty() {
             expanded = quote! {#[#near_sdk_crate::near_bindgen]}
+        } else {
+            expanded = quote! {#[#near_sdk_crate::near_bindgen(#(#state_args),*)]}
         }
+    } else if monomorphize.is_some() && syn::parse::<ItemImpl>(item.clone()).is_err() {
+        return TokenStream::from(
+            syn::Error::new(
+                Span::call_site(),
+                "`monomorphize` can only be used on contract state definitions and impl sections.",
+            )
+            .to_compile_error(),
+        );
     };
 
     let mut has_borsh = false;
     let mut has_json = false;
 
     let mut borsh_attr = quote! {};
+    let mut borsh_field_bounds: Vec<Expr> = vec![];
 
     match near_macro_args.serializers {
         Some(serializers) => {
@@ -237,6 +388,11 @@ pub fn near(attr: TokenStream, item: TokenStream) -> TokenStream {
                                     call_expr.args.push(parse_quote! {crate=#string_serde_crate});
                                 } else if *ident == "borsh" {
                                     has_borsh = true;
+                                    // The borsh derives only accept `bound` on fields.
+                                    let (bounds, args) =
+                                        call_expr.args.iter().cloned().partition(is_borsh_bound);
+                                    borsh_field_bounds = bounds;
+                                    call_expr.args = args.into_iter().collect();
                                     call_expr.args.push(parse_quote! {crate=#string_borsh_crate});
                                 }
                             }
@@ -290,19 +446,68 @@ pub fn near(attr: TokenStream, item: TokenStream) -> TokenStream {
         };
     }
 
-    if let Ok(input) = syn::parse::<ItemStruct>(item.clone()) {
+    let is_impl = syn::parse::<ItemImpl>(item.clone()).is_ok();
+    if let Some(derives) = &near_macro_args.derive {
+        if is_impl {
+            return TokenStream::from(
+                syn::Error::new(
+                    Span::call_site(),
+                    "`derive` can only be used on struct or enum definitions.",
+                )
+                .to_compile_error(),
+            );
+        }
+        let derives = derives.iter();
+        expanded = quote! {
+            #expanded
+            #[derive(#(#derives),*)]
+        };
+    }
+    let impl_only_args = [
+        ("methods_const", near_macro_args.methods_const.is_some()),
+        ("deny_unknown_arguments", near_macro_args.deny_unknown_arguments.is_some()),
+        ("no_panic_hook", near_macro_args.no_panic_hook.is_some()),
+    ];
+    if let Some((arg, _)) = impl_only_args.iter().find(|(_, used)| !is_impl && *used) {
+        return TokenStream::from(
+            syn::Error::new(
+                Span::call_site(),
+                format!("`{}` can only be used on impl sections.", arg),
+            )
+            .to_compile_error(),
+        );
+    }
+
+    if let Ok(mut input) = syn::parse::<ItemStruct>(item.clone()) {
+        add_borsh_field_bounds(&mut input.fields, &borsh_field_bounds);
         expanded = quote! {
             #expanded
             #input
         };
-    } else if let Ok(input) = syn::parse::<ItemEnum>(item.clone()) {
+    } else if let Ok(mut input) = syn::parse::<ItemEnum>(item.clone()) {
+        for variant in input.variants.iter_mut() {
+            add_borsh_field_bounds(&mut variant.fields, &borsh_field_bounds);
+        }
         expanded = quote! {
             #expanded
             #input
         };
     } else if let Ok(input) = syn::parse::<ItemImpl>(item) {
+        let mut impl_args = vec![];
+        match &near_macro_args.methods_const {
+            Some(Override::Inherit) => impl_args.push(quote! {methods_const}),
+            Some(Override::Explicit(name)) => impl_args.push(quote! {methods_const = #name}),
+            None => {}
+        }
+        if near_macro_args.deny_unknown_arguments.unwrap_or(false) {
+            impl_args.push(quote! {deny_unknown_arguments});
+        }
+        if near_macro_args.no_panic_hook.unwrap_or(false) {
+            impl_args.push(quote! {no_panic_hook});
+        }
+        impl_args.extend(monomorphize);
         expanded = quote! {
-            #[#near_sdk_crate::near_bindgen]
+            #[#near_sdk_crate::near_bindgen(#(#impl_args),*)]
             #input
         };
     } else {
@@ -318,6 +523,23 @@ pub fn near(attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+fn is_borsh_bound(expr: &Expr) -> bool {
+    matches!(expr, Expr::Call(call) if matches!(&*call.func, Expr::Path(path) if path.path.is_ident("bound")))
+}
+
+/// Adds `#[borsh(bound(...))]` to every field without its own `#[borsh(...)]` attribute, which
+/// replaces the bounds the borsh derives would otherwise infer from the field types.
+fn add_borsh_field_bounds(fields: &mut syn::Fields, bounds: &[Expr]) {
+    if bounds.is_empty() {
+        return;
+    }
+    for field in fields.iter_mut() {
+        if !field.attrs.iter().any(|attr| attr.path().is_ident("borsh")) {
+            field.attrs.push(parse_quote! {#[borsh(#(#bounds),*)]});
+        }
+    }
+}
+
 /// This macro is deprecated. Use [#\[near\]](./attr.near.html) instead. The difference between #\[near\] and #\[near_bindgen\] is that
 /// with #\[near_bindgen\] you have to manually add boilerplate code for structs and enums so that they become Json- and Borsh-serializable:
 /// ```ignore
@@ -341,14 +563,26 @@ pub fn near_bindgen(attr: TokenStream, item: TokenStream) -> TokenStream {
         return core_impl::near_events(attr, item);
     }
 
+    let is_impl = syn::parse::<ItemImpl>(item.clone()).is_ok();
+    let (monomorphize, attr) = if is_impl {
+        (None, attr)
+    } else {
+        match split_monomorphize(attr) {
+            Ok(v) => v,
+            Err(err) => return err,
+        }
+    };
+
+    let hook = core_impl::contract_source_metadata_hook(attr.clone());
     let generate_metadata = |ident: &Ident,
                              generics: &syn::Generics|
      -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
-        let metadata_impl_gen = generate_contract_metadata_method(ident, generics).into();
+        let metadata_impl_gen = generate_contract_metadata_method(ident, generics, hook).into();
 
         let metadata_impl_gen = syn::parse::<ItemImpl>(metadata_impl_gen)
             .expect("failed to generate contract metadata");
-        process_impl_block(metadata_impl_gen)
+        let impl_args = ImplBlockArgs { monomorphize: monomorphize.clone(), ..Default::default() };
+        process_impl_block(metadata_impl_gen, &impl_args)
     };
 
     if let Ok(input) = syn::parse::<ItemStruct>(item.clone()) {
@@ -409,7 +643,14 @@ pub fn near_bindgen(attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
             }
         }
-        match process_impl_block(input) {
+        let impl_args = match NestedMeta::parse_meta_list(attr.into())
+            .map_err(Error::from)
+            .and_then(|meta_list| ImplBlockArgs::from_list(&meta_list))
+        {
+            Ok(v) => v,
+            Err(e) => return TokenStream::from(e.write_errors()),
+        };
+        match process_impl_block(input, &impl_args) {
             Ok(output) => output,
             Err(output) => output,
         }
@@ -425,19 +666,55 @@ pub fn near_bindgen(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Splits the `monomorphize` argument of `#[near_bindgen(...)]` on a struct or enum off the
+/// contract metadata arguments.
+fn split_monomorphize(attr: TokenStream) -> Result<(Option<syn::Type>, TokenStream), TokenStream> {
+    if attr.is_empty() {
+        return Ok((None, attr));
+    }
+    let meta_list = NestedMeta::parse_meta_list(attr.into())
+        .map_err(|e| TokenStream::from(Error::from(e).write_errors()))?;
+    let (monomorphize, rest): (Vec<_>, Vec<_>) = meta_list.into_iter().partition(
+        |meta| matches!(meta, NestedMeta::Meta(meta) if meta.path().is_ident("monomorphize")),
+    );
+    let monomorphize = match monomorphize.first() {
+        Some(NestedMeta::Meta(meta)) => {
+            Some(syn::Type::from_meta(meta).map_err(|e| TokenStream::from(e.write_errors()))?)
+        }
+        _ => None,
+    };
+    Ok((monomorphize, quote! {#(#rest),*}.into()))
+}
+
 // This function deals with impl block processing, generating wrappers and ABI.
 //
 // # Arguments
 // * input - impl block to process.
+// * impl_args - `methods_const` to also emit a const listing the exported method names, named by
+//   its value if given,
+//   `deny_unknown_arguments` to reject unknown JSON arguments in methods that don't opt out,
+//   `no_panic_hook` to not set up the panic hook in the generated wrappers,
+//   `monomorphize` to export the methods of a generic impl block for concrete type arguments.
 //
 // The Result has a TokenStream error type, because those need to be propagated to the compiler.
 fn process_impl_block(
     mut input: ItemImpl,
+    impl_args: &ImplBlockArgs,
 ) -> Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
-    let item_impl_info = match ItemImplInfo::new(&mut input) {
+    let item_impl_info = match &impl_args.monomorphize {
+        Some(concrete) => ItemImplInfo::new_monomorphized(&mut input, concrete),
+        None => ItemImplInfo::new(&mut input),
+    };
+    let mut item_impl_info = match item_impl_info {
         Ok(x) => x,
         Err(err) => return Err(err.to_compile_error()),
     };
+    if impl_args.deny_unknown_arguments {
+        item_impl_info.deny_unknown_arguments();
+    }
+    if impl_args.no_panic_hook {
+        item_impl_info.no_panic_hook();
+    }
 
     #[cfg(not(feature = "__abi-generate"))]
     let abi_generated = quote! {};
@@ -449,10 +726,16 @@ fn process_impl_block(
     // Add wrapper methods for ext call API
     let ext_generated_code = item_impl_info.generate_ext_wrapper_code();
 
+    let methods_const_code = match &impl_args.methods_const {
+        Some(name) => item_impl_info.methods_const_code(name.as_ref().explicit()),
+        None => quote! {},
+    };
+
     Ok(TokenStream::from(quote! {
         #ext_generated_code
         #input
         #generated_code
+        #methods_const_code
         #abi_generated
     })
     .into())
@@ -484,6 +767,25 @@ fn process_impl_block(
 ///
 /// ```
 ///
+/// A method can declare the static gas it is called with by default through the `#[gas(...)]`
+/// attribute, which takes an expression evaluating to [`Gas`](near_sdk::Gas). Calling
+/// `with_static_gas` on the builder still overrides it. The attribute is only accepted in
+/// `#[ext_contract]` traits, while `#[max_input_bytes]` and `#[deprecated_method]` are rejected
+/// there, as they only apply to methods exported by `#[near]`.
+///
+/// ```ignore
+/// #[ext_contract(ext_calculator)]
+/// trait Calculator {
+///     #[gas(Gas::from_tgas(5))]
+///     fn mult(&self, a: u64, b: u64) -> u128;
+/// }
+///
+/// // Attaches 5 TGas.
+/// ext_calculator::ext(calculator_account.clone()).mult(number, 5);
+/// // Attaches 10 TGas.
+/// ext_calculator::ext(calculator_account).with_static_gas(Gas::from_tgas(10)).mult(number, 5);
+/// ```
+///
 /// See more information about role of ext_contract in [NEAR documentation](https://docs.near.org/build/smart-contracts/anatomy/crosscontract)
 #[proc_macro_attribute]
 pub fn ext_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -561,7 +863,7 @@ pub fn init(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
 #[cfg(feature = "abi")]
 #[derive(darling::FromDeriveInput, Debug)]
-#[darling(attributes(abi), forward_attrs(serde, borsh_skip, schemars, validate))]
+#[darling(attributes(abi), forward_attrs(serde, borsh, borsh_skip, repr, schemars, validate))]
 struct DeriveNearSchema {
     attrs: Vec<syn::Attribute>,
     json: Option<bool>,
@@ -617,6 +919,7 @@ pub fn derive_near_schema(#[allow(unused)] input: TokenStream) -> TokenStream {
         let (json_schema, borsh_schema) = (args.json.unwrap_or(false), args.borsh.unwrap_or(false));
         let mut input = derive_input.clone();
         input.attrs = args.attrs;
+        retain_borsh_use_discriminant(&mut input.attrs);
 
         let strip_unknown_attr = |attrs: &mut Vec<syn::Attribute>| {
             attrs.retain(|attr| {
@@ -734,6 +1037,32 @@ pub fn derive_near_schema(#[allow(unused)] input: TokenStream) -> TokenStream {
     }
 }
 
+/// Keeps only `use_discriminant` out of the container level `#[borsh(...)]` attributes, so the
+/// schema records the same enum tags as the serialization. Other arguments such as `crate` or
+/// `init` either clash with the ones added for the schema derive or don't apply to it.
+#[cfg(feature = "abi")]
+fn retain_borsh_use_discriminant(attrs: &mut Vec<syn::Attribute>) {
+    use syn::punctuated::Punctuated;
+
+    attrs.retain_mut(|attr| {
+        if !attr.path().is_ident("borsh") {
+            return true;
+        }
+        let args =
+            match attr.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                Ok(args) => args,
+                Err(_) => return false,
+            };
+        let kept: Vec<_> =
+            args.into_iter().filter(|meta| meta.path().is_ident("use_discriminant")).collect();
+        if kept.is_empty() {
+            return false;
+        }
+        *attr = parse_quote! {#[borsh(#(#kept),*)]};
+        true
+    });
+}
+
 #[allow(dead_code)]
 fn get_schema_derive(
     json_schema: bool,
@@ -816,6 +1145,26 @@ pub fn derive_no_default(item: TokenStream) -> TokenStream {
 /// The type should also implement or derive `BorshSerialize` trait.
 ///
 /// More information about storage keys in [NEAR documentation](https://docs.near.org/build/smart-contracts/anatomy/storage)
+///
+/// The key of an enum variant starts with its Borsh discriminant, i.e. its position in the enum.
+/// A variant can instead be given an explicit byte prefix with `#[storage_key(prefix = b"...")]`,
+/// which stays the same when variants are reordered or when the enum is replaced during a
+/// migration. The key of such a variant is the prefix followed by the Borsh serialization of its
+/// fields. No key may be a prefix of another one: explicit prefixes must not start with one
+/// another, nor with the discriminant byte of a variant that has no prefix. Overlapping keys are
+/// rejected at compile time, since a collection under one of them could alias the entries of a
+/// collection under the other.
+///
+/// ```ignore
+/// #[derive(BorshSerialize, BorshStorageKey)]
+/// #[borsh(crate = "near_sdk::borsh")]
+/// pub enum StorageKey {
+///     #[storage_key(prefix = b"v2/msg")]
+///     Messages,
+///     #[storage_key(prefix = b"v2/acc")]
+///     Account { account_id: AccountId },
+/// }
+/// ```
 /// ## Example
 /// ```ignore
 /// #[derive(BorshSerialize, BorshDeserialize, BorshStorageKey)]
@@ -839,12 +1188,13 @@ pub fn derive_no_default(item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(BorshStorageKey)]
+#[proc_macro_derive(BorshStorageKey, attributes(storage_key))]
 pub fn borsh_storage_key(item: TokenStream) -> TokenStream {
-    let (name, generics) = if let Ok(input) = syn::parse::<ItemEnum>(item.clone()) {
-        (input.ident, input.generics)
+    let (name, generics, prefix_arms) = if let Ok(input) = syn::parse::<ItemEnum>(item.clone()) {
+        let arms = storage_key_prefix_arms(&input);
+        (input.ident, input.generics, arms)
     } else if let Ok(input) = syn::parse::<ItemStruct>(item) {
-        (input.ident, input.generics)
+        (input.ident, input.generics, Ok((vec![], true)))
     } else {
         return TokenStream::from(
             syn::Error::new(
@@ -862,20 +1212,198 @@ pub fn borsh_storage_key(item: TokenStream) -> TokenStream {
     } else {
         parse_quote!(where #predicate)
     };
+    let (prefix_arms, has_fallback) = match prefix_arms {
+        Ok(arms) => arms,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let body = if prefix_arms.is_empty() {
+        quote! {}
+    } else {
+        let fallback =
+            has_fallback.then(|| quote! { _ => ::near_sdk::borsh::to_vec(self).unwrap(), });
+        quote! {
+            fn borsh_storage_key(&self) -> ::std::vec::Vec<u8> {
+                match self {
+                    #(#prefix_arms)*
+                    #fallback
+                }
+            }
+        }
+    };
     TokenStream::from(quote! {
-        impl #impl_generics ::near_sdk::__private::BorshIntoStorageKey for #name #ty_generics #where_clause {}
+        impl #impl_generics ::near_sdk::__private::BorshIntoStorageKey for #name #ty_generics #where_clause {
+            #body
+        }
     })
 }
 
+/// Generates a match arm building the key of every variant with a `#[storage_key(prefix = ...)]`,
+/// along with whether any variant is left to the Borsh serialization fallback.
+///
+/// Keys of different variants must not be prefixes of one another, otherwise a collection under
+/// one variant could alias the entries of a collection under another. Variants without an
+/// explicit prefix are keyed by their 1-byte Borsh discriminant, so those are checked too.
+fn storage_key_prefix_arms(input: &ItemEnum) -> syn::Result<(Vec<proc_macro2::TokenStream>, bool)> {
+    let use_discriminant = borsh_use_discriminant(&input.attrs)?;
+    let mut keys: Vec<(Vec<u8>, &syn::Ident)> = vec![];
+    let mut arms = vec![];
+    let mut has_fallback = false;
+    let mut discriminant: u8 = 0;
+    for var in &input.variants {
+        if use_discriminant {
+            if let Some((_, expr)) = &var.discriminant {
+                discriminant = match expr {
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => {
+                        lit.base10_parse()?
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            expr,
+                            "Expected an integer literal discriminant.",
+                        ))
+                    }
+                };
+            }
+        }
+        let prefix = get_storage_key_prefix(&var.attrs)?;
+        let key = prefix.clone().unwrap_or_else(|| vec![discriminant]);
+        discriminant = discriminant.wrapping_add(1);
+        if let Some((_, other)) =
+            keys.iter().find(|(other, _)| other.starts_with(&key) || key.starts_with(other))
+        {
+            return Err(syn::Error::new_spanned(
+                &var.ident,
+                format!(
+                    "Storage key of `{}` overlaps with the storage key of `{}`, neither may be a \
+                     prefix of the other.",
+                    var.ident, other
+                ),
+            ));
+        }
+        keys.push((key, &var.ident));
+        let Some(prefix) = prefix else {
+            has_fallback = true;
+            continue;
+        };
+        let bindings: Vec<_> = var
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                field.ident.clone().unwrap_or_else(|| quote::format_ident!("__field{}", i))
+            })
+            .collect();
+        let var_ident = &var.ident;
+        let pattern = match &var.fields {
+            syn::Fields::Named(_) => quote! { Self::#var_ident { #(#bindings),* } },
+            syn::Fields::Unnamed(_) => quote! { Self::#var_ident ( #(#bindings),* ) },
+            syn::Fields::Unit => quote! { Self::#var_ident },
+        };
+        let prefix_lit = syn::LitByteStr::new(&prefix, var.ident.span());
+        arms.push(quote! {
+            #pattern => {
+                let mut key = #prefix_lit.to_vec();
+                #(::near_sdk::borsh::BorshSerialize::serialize(#bindings, &mut key).unwrap();)*
+                key
+            }
+        });
+    }
+    Ok((arms, has_fallback))
+}
+
+/// Whether the enum is serialized with `#[borsh(use_discriminant = true)]`.
+fn borsh_use_discriminant(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let mut use_discriminant = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("borsh")) {
+        attr.parse_nested_meta(|meta| {
+            let value = meta.value()?.parse::<syn::Expr>()?;
+            if meta.path.is_ident("use_discriminant") {
+                use_discriminant = matches!(
+                    value,
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Bool(syn::LitBool { value: true, .. }),
+                        ..
+                    })
+                );
+            }
+            Ok(())
+        })?;
+    }
+    Ok(use_discriminant)
+}
+
+fn get_storage_key_prefix(attrs: &[syn::Attribute]) -> syn::Result<Option<Vec<u8>>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("storage_key")) else {
+        return Ok(None);
+    };
+    let mut prefix = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("prefix") {
+            prefix = Some(match meta.value()?.parse::<syn::Lit>()? {
+                syn::Lit::ByteStr(lit) => lit.value(),
+                syn::Lit::Str(lit) => lit.value().into_bytes(),
+                lit => return Err(syn::Error::new_spanned(lit, "Expected a byte string.")),
+            });
+            Ok(())
+        } else {
+            Err(meta.error("Unsupported storage_key attribute, expected `prefix`."))
+        }
+    })?;
+    match prefix {
+        Some(prefix) if !prefix.is_empty() => Ok(Some(prefix)),
+        _ => Err(syn::Error::new_spanned(attr, "Expected a non-empty `prefix = b\"...\"`.")),
+    }
+}
+
 /// `FunctionError` generates implementation for `near_sdk::FunctionError` trait.
 /// It allows contract runtime to panic with the type using its `ToString` implementation
 /// as the message.
-#[proc_macro_derive(FunctionError)]
+///
+/// Each variant of an enum (or the struct itself) can be given a stable numeric code with
+/// `#[error_code(N)]`, so that off-chain indexers can match on it. The panic message is then
+/// prefixed with the code, e.g. `E42: <display>`, and a `pub fn code(&self) -> u32` method is
+/// generated. If any variant has a code, all of them must have one.
+///
+/// ```ignore
+/// use near_sdk::FunctionError;
+///
+/// #[derive(FunctionError)]
+/// enum Error {
+///     #[error_code(1)]
+///     NotFound,
+///     #[error_code(42)]
+///     Banned { account_id: String },
+/// }
+///
+/// impl std::fmt::Display for Error {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Error::NotFound => write!(f, "not found"),
+///             Error::Banned { account_id } => write!(f, "account {} is banned", account_id),
+///         }
+///     }
+/// }
+///
+/// // Panics with "E42: account bob.near is banned".
+/// assert_eq!(Error::Banned { account_id: "bob.near".to_string() }.code(), 42);
+/// ```
+#[proc_macro_derive(FunctionError, attributes(error_code))]
 pub fn function_error(item: TokenStream) -> TokenStream {
-    let name = if let Ok(input) = syn::parse::<ItemEnum>(item.clone()) {
-        input.ident
+    let (name, generics, codes) = if let Ok(input) = syn::parse::<ItemEnum>(item.clone()) {
+        let codes = input
+            .variants
+            .iter()
+            .map(|var| {
+                let var_ident = &var.ident;
+                get_error_code(&var.attrs)
+                    .map(|code| (quote! { Self::#var_ident { .. } }, code, var.ident.span()))
+            })
+            .collect::<syn::Result<Vec<_>>>();
+        (input.ident, input.generics, codes)
     } else if let Ok(input) = syn::parse::<ItemStruct>(item) {
-        input.ident
+        let codes = get_error_code(&input.attrs)
+            .map(|code| vec![(quote! { Self { .. } }, code, input.ident.span())]);
+        (input.ident, input.generics, codes)
     } else {
         return TokenStream::from(
             syn::Error::new(
@@ -885,15 +1413,62 @@ pub fn function_error(item: TokenStream) -> TokenStream {
             .to_compile_error(),
         );
     };
+    let codes = match codes {
+        Ok(codes) => codes,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    if codes.iter().all(|(_, code, _)| code.is_none()) {
+        return TokenStream::from(quote! {
+            impl #impl_generics ::near_sdk::FunctionError for #name #ty_generics #where_clause {
+                fn panic(&self) -> ! {
+                    ::near_sdk::env::panic_str(&::std::string::ToString::to_string(&self))
+                }
+            }
+        });
+    }
+
+    let mut arms = Vec::with_capacity(codes.len());
+    for (pattern, code, span) in codes {
+        if let Some(code) = code {
+            arms.push(quote! { #pattern => #code });
+        } else {
+            return TokenStream::from(
+                syn::Error::new(
+                    span,
+                    "All variants must have an `error_code` if any of them has one.",
+                )
+                .to_compile_error(),
+            );
+        }
+    }
     TokenStream::from(quote! {
-        impl ::near_sdk::FunctionError for #name {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the stable numeric code of this error.
+            pub fn code(&self) -> u32 {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+
+        impl #impl_generics ::near_sdk::FunctionError for #name #ty_generics #where_clause {
             fn panic(&self) -> ! {
-                ::near_sdk::env::panic_str(&::std::string::ToString::to_string(&self))
+                ::near_sdk::env::panic_str(&::std::format!("E{}: {}", self.code(), self))
             }
         }
     })
 }
 
+fn get_error_code(attrs: &[syn::Attribute]) -> syn::Result<Option<u32>> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("error_code"))
+        .map(|attr| attr.parse_args::<syn::LitInt>()?.base10_parse::<u32>())
+        .transpose()
+}
+
 /// NOTE: This is an internal implementation for `#[near_bindgen(events(standard = ...))]` attribute.
 ///
 /// This derive macro is used to inject the necessary wrapper and logic to auto format
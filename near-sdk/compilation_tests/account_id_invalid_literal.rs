@@ -0,0 +1,7 @@
+//! Invalid account id literals fail to compile.
+
+use near_sdk::{account_id, AccountIdRef};
+
+const _TREASURY: &AccountIdRef = account_id!("Treasury.near");
+
+fn main() {}
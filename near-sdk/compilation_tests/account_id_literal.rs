@@ -0,0 +1,10 @@
+//! Account id literals are validated at compile time.
+
+use near_sdk::{account_id, AccountId, AccountIdRef};
+
+const TREASURY: &AccountIdRef = account_id!("treasury.near");
+
+fn main() {
+    let owner: AccountId = account_id!("alice.near").to_owned();
+    assert_ne!(owner.as_str(), TREASURY.as_str());
+}
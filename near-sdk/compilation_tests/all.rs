@@ -6,20 +6,27 @@ fn compilation_tests() {
     t.pass("compilation_tests/regular.rs");
     t.pass("compilation_tests/private.rs");
     t.pass("compilation_tests/trait_impl.rs");
+    t.pass("compilation_tests/methods_const.rs");
+    t.compile_fail("compilation_tests/impl_args_on_struct.rs");
     t.compile_fail("compilation_tests/bad_argument.rs");
     t.pass("compilation_tests/complex.rs");
     t.compile_fail("compilation_tests/impl_generic.rs");
     t.pass("compilation_tests/references.rs");
     t.pass("compilation_tests/init_function.rs");
     t.pass("compilation_tests/init_ignore_state.rs");
+    t.pass("compilation_tests/migrate.rs");
     t.pass("compilation_tests/no_default.rs");
     // TODO: unignore upon resolution of https://github.com/near/near-sdk-rs/issues/1211
     // t.pass("compilation_tests/lifetime_method_result.rs");
     t.pass("compilation_tests/lifetime_method.rs");
     t.pass("compilation_tests/cond_compilation.rs");
     t.compile_fail("compilation_tests/payable_view.rs");
+    t.compile_fail("compilation_tests/view_mut_self.rs");
     t.pass("compilation_tests/borsh_storage_key.rs");
     t.pass("compilation_tests/borsh_storage_key_generics.rs");
+    t.compile_fail("compilation_tests/borsh_storage_key_duplicate_prefix.rs");
+    t.compile_fail("compilation_tests/borsh_storage_key_overlapping_prefix.rs");
+    t.pass("compilation_tests/borsh_bound.rs");
     t.pass("compilation_tests/function_error.rs");
     t.pass("compilation_tests/enum_near_bindgen.rs");
     t.pass("compilation_tests/schema_derive.rs");
@@ -29,6 +36,16 @@ fn compilation_tests() {
         // so we snapshoted this single version
         t.compile_fail("compilation_tests/schema_derive_invalids.rs");
     }
+    // Trait bound errors are reported differently before 1.95, so each output has its own snapshot.
+    if rustversion::cfg!(since(1.95)) {
+        t.compile_fail("compilation_tests/result_serializer_borsh_missing.rs");
+    } else {
+        t.compile_fail("compilation_tests/result_serializer_borsh_missing_before_1_95.rs");
+    }
+    if rustversion::cfg!(since(1.95)) && std::env::consts::OS == "linux" {
+        // Const evaluation errors are reported differently before 1.95.
+        t.compile_fail("compilation_tests/account_id_invalid_literal.rs");
+    }
     t.compile_fail("compilation_tests/generic_function.rs");
     t.compile_fail("compilation_tests/generic_const_function.rs");
     t.pass("compilation_tests/self_support.rs");
@@ -40,4 +57,10 @@ fn compilation_tests() {
     t.compile_fail("compilation_tests/contract_metadata_fn_name.rs");
     t.pass("compilation_tests/contract_metadata_bindgen.rs");
     t.pass("compilation_tests/types.rs");
+    t.pass("compilation_tests/require_deposit.rs");
+    t.pass("compilation_tests/near_derive.rs");
+    t.pass("compilation_tests/no_panic_hook.rs");
+    t.pass("compilation_tests/account_id_literal.rs");
+    t.pass("compilation_tests/generic_state.rs");
+    t.compile_fail("compilation_tests/monomorphize_mismatch.rs");
 }
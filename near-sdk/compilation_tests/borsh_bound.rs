@@ -0,0 +1,37 @@
+//! Generic state whose borsh implementations need bounds that can't be inferred from the fields.
+
+use near_sdk::borsh::BorshDeserialize;
+use near_sdk::near;
+use std::collections::BTreeMap;
+
+#[near(serializers=[borsh(bound(deserialize = "K: BorshDeserialize + Ord, V: BorshDeserialize"))])]
+pub struct Index<K, V> {
+    entries: BTreeMap<K, V>,
+}
+
+#[near(serializers=[borsh(bound(deserialize = "K: BorshDeserialize + Ord"))])]
+pub enum Keys<K> {
+    Empty,
+    Sorted(BTreeMap<K, ()>),
+}
+
+#[near(contract_state)]
+pub struct Contract {
+    index: Index<String, u64>,
+    keys: Keys<u32>,
+}
+
+impl Default for Contract {
+    fn default() -> Self {
+        Self { index: Index { entries: BTreeMap::new() }, keys: Keys::Empty }
+    }
+}
+
+#[near]
+impl Contract {
+    pub fn get(&self, key: String) -> Option<u64> {
+        self.index.entries.get(&key).copied()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+//! Explicit storage key prefixes must be unique within the enum.
+
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::BorshStorageKey;
+
+#[derive(BorshStorageKey, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+enum StorageKey {
+    #[storage_key(prefix = b"a")]
+    Accounts,
+    #[storage_key(prefix = "a")]
+    Balances,
+}
+
+fn main() {}
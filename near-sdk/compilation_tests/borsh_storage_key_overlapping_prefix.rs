@@ -0,0 +1,23 @@
+//! Storage keys of different variants must not be prefixes of one another.
+
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::BorshStorageKey;
+
+#[derive(BorshStorageKey, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+enum StorageKey {
+    #[storage_key(prefix = b"v2")]
+    Accounts,
+    #[storage_key(prefix = b"v2/named")]
+    Named { id: u32 },
+}
+
+#[derive(BorshStorageKey, BorshSerialize)]
+#[borsh(crate = "near_sdk::borsh")]
+enum DiscriminantKey {
+    Accounts,
+    #[storage_key(prefix = b"\x00balances")]
+    Balances,
+}
+
+fn main() {}
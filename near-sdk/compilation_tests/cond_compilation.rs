@@ -8,6 +8,12 @@ struct Incrementer {
     value: u32,
 }
 
+#[cfg(feature = "myfeature")]
+#[near(serializers = [borsh])]
+pub struct Snapshot {
+    value: u32,
+}
+
 #[near]
 impl Incrementer {
     #[cfg(feature = "myfeature")]
@@ -31,6 +37,12 @@ impl Incrementer {
     pub fn inc(&mut self, by: u32) {
         self.value += by;
     }
+
+    #[cfg(feature = "myfeature")]
+    #[result_serializer(borsh)]
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot { value: self.value }
+    }
 }
 
 fn main() {}
@@ -2,7 +2,9 @@
 
 use borsh::BorshSerialize;
 use near_sdk::FunctionError;
+use near_sdk::json_types::U128;
 use near_sdk::near;
+use near_sdk::{Promise, PromiseOrValue};
 use std::fmt;
 
 #[derive(FunctionError, BorshSerialize)]
@@ -31,6 +33,23 @@ impl fmt::Display for ErrorEnum {
     }
 }
 
+#[derive(FunctionError)]
+enum CodedError {
+    #[error_code(404)]
+    NotFound,
+    #[error_code(403)]
+    Banned(String),
+}
+
+impl fmt::Display for CodedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodedError::NotFound => write!(f, "not found"),
+            CodedError::Banned(account_id) => write!(f, "account {} is banned", account_id),
+        }
+    }
+}
+
 #[derive(Default)]
 #[near(contract_state)]
 struct Contract {}
@@ -46,6 +65,20 @@ impl Contract {
     pub fn get(&self) -> Result<String, ErrorEnum> {
         Err(ErrorEnum::NotFound)
     }
+
+    #[handle_result]
+    pub fn get_coded(&self) -> Result<String, CodedError> {
+        Err(CodedError::NotFound)
+    }
+
+    #[handle_result]
+    pub fn get_or_forward(&mut self, key: u8) -> Result<PromiseOrValue<U128>, ErrorEnum> {
+        match key {
+            0 => Ok(PromiseOrValue::Value(U128(0))),
+            1 => Ok(Promise::new("bob.near".parse().unwrap()).create_account().into()),
+            _ => Err(ErrorEnum::NotFound),
+        }
+    }
 }
 
 fn main() {}
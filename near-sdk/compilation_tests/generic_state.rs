@@ -0,0 +1,44 @@
+//! Generic contract state with the methods exported for concrete type arguments.
+
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near;
+use near_sdk::serde::de::DeserializeOwned;
+use near_sdk::serde::Serialize;
+
+#[near(contract_state, monomorphize = "Registry<u64>")]
+#[derive(Default)]
+pub struct Registry<T: BorshSerialize + BorshDeserialize> {
+    items: Vec<T>,
+}
+
+#[near(monomorphize = "Registry<u64>")]
+impl<T> Registry<T>
+where
+    T: BorshSerialize + BorshDeserialize + Serialize + DeserializeOwned + Clone,
+{
+    #[init]
+    pub fn new(items: Vec<T>) -> Self {
+        Self { items }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn get(&self, index: u32) -> Option<T> {
+        self.items.get(index as usize).cloned()
+    }
+
+    #[result_serializer(borsh)]
+    pub fn get_borsh(&self, index: u32) -> Option<T> {
+        self.items.get(index as usize).cloned()
+    }
+
+    pub fn items(&self) -> &Vec<T> {
+        &self.items
+    }
+}
+
+fn main() {
+    let _ = Registry::<u64>::ext("registry.near".parse().unwrap()).push(1);
+}
@@ -0,0 +1,22 @@
+//! Arguments that only apply to impl sections are rejected on other items.
+
+use near_sdk::near;
+
+#[near(contract_state, methods_const)]
+#[derive(Default)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near(no_panic_hook)]
+struct Counter {
+    value: u32,
+}
+
+#[near(serializers = [json], deny_unknown_arguments)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+//! Smart contract that lists its exported methods in a const.
+
+use near_sdk::near;
+
+#[near(contract_state)]
+#[derive(Default)]
+struct Incrementer {
+    value: u32,
+}
+
+pub trait Zeroable {
+    fn set_to_zero(&mut self);
+}
+
+#[near(methods_const)]
+impl Incrementer {
+    #[init]
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+
+    #[private]
+    pub fn reset(&mut self) {
+        self.value = 0;
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value
+    }
+
+    fn helper(&self) {}
+}
+
+#[near(methods_const = "INCREMENTER_LIMIT_METHODS")]
+impl Incrementer {
+    pub fn inc_to(&mut self, limit: u32) {
+        self.value = self.value.max(limit);
+    }
+}
+
+#[near(methods_const)]
+impl Zeroable for Incrementer {
+    fn set_to_zero(&mut self) {
+        self.value = 0;
+    }
+}
+
+fn main() {
+    assert_eq!(INCREMENTER_METHODS, &["new", "inc", "reset", "get"]);
+    assert_eq!(INCREMENTER_LIMIT_METHODS, &["inc_to"]);
+    assert_eq!(INCREMENTER_ZEROABLE_METHODS, &["set_to_zero"]);
+}
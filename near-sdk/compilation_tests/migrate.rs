@@ -0,0 +1,28 @@
+//! Smart contract with a state migration.
+
+use near_sdk::near;
+
+#[near]
+struct OldIncrementer {
+    value: u32,
+}
+
+#[near(contract_state)]
+struct Incrementer {
+    value: u64,
+    step: u64,
+}
+
+#[near]
+impl Incrementer {
+    pub fn inc(&mut self) {
+        self.value += self.step;
+    }
+
+    #[near(migrate(from = OldIncrementer))]
+    pub fn migrate(old: OldIncrementer, step: u64) -> Self {
+        Self { value: old.value.into(), step }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! `monomorphize` has to name the type of the impl block.
+
+use near_sdk::near;
+
+#[near(contract_state, monomorphize = "Counter<u32>")]
+#[derive(Default)]
+struct Counter<T: near_sdk::borsh::BorshSerialize + near_sdk::borsh::BorshDeserialize> {
+    value: T,
+}
+
+#[near(monomorphize = "Incrementer<u32>")]
+impl<T: near_sdk::borsh::BorshSerialize + near_sdk::borsh::BorshDeserialize> Counter<T> {
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+fn main() {}
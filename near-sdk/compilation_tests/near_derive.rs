@@ -0,0 +1,27 @@
+//! Extra derives passed to `#[near(derive(...))]` are applied to the type.
+
+use near_sdk::near;
+
+#[near(contract_state, derive(Clone, Debug))]
+#[derive(Default)]
+struct Contract {
+    value: u32,
+}
+
+#[near]
+impl Contract {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[near(serializers = [json], derive(Debug, PartialEq))]
+enum Status {
+    Active,
+}
+
+fn main() {
+    let contract = Contract::default();
+    assert_eq!(format!("{:?}", contract.clone()), "Contract { value: 0 }");
+    assert_eq!(format!("{:?}", Status::Active), "Active");
+}
@@ -0,0 +1,22 @@
+//! Smart contract whose methods don't set up the panic hook.
+
+use near_sdk::near;
+
+#[near(contract_state)]
+#[derive(Default)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near(no_panic_hook)]
+impl Incrementer {
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! `require_one_yocto!` and `require_min_deposit!` can be used without importing helpers.
+
+use near_sdk::{near, NearToken};
+
+#[derive(Default)]
+#[near(contract_state)]
+struct Contract {}
+
+#[near]
+impl Contract {
+    #[payable]
+    pub fn transfer(&mut self) {
+        near_sdk::require_one_yocto!();
+    }
+
+    #[payable]
+    pub fn deposit(&mut self) {
+        near_sdk::require_min_deposit!(NearToken::from_millinear(1));
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+//! Returning a type without `BorshSerialize` from a `#[result_serializer(borsh)]` method points
+//! at the return type.
+
+use near_sdk::borsh::BorshSchema;
+use near_sdk::near;
+
+// `BorshSchema` keeps the ABI generation from reporting its own error.
+#[near(serializers = [json])]
+#[derive(BorshSchema)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct NotBorsh {
+    value: u32,
+}
+
+#[derive(Default)]
+#[near(contract_state)]
+struct Contract {}
+
+#[near]
+impl Contract {
+    #[result_serializer(borsh)]
+    pub fn get(&self) -> NotBorsh {
+        NotBorsh { value: 0 }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+//! Same as `result_serializer_borsh_missing.rs`, for rustc before 1.95, which reports the
+//! unsatisfied trait bound differently.
+
+use near_sdk::borsh::BorshSchema;
+use near_sdk::near;
+
+// `BorshSchema` keeps the ABI generation from reporting its own error.
+#[near(serializers = [json])]
+#[derive(BorshSchema)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct NotBorsh {
+    value: u32,
+}
+
+#[derive(Default)]
+#[near(contract_state)]
+struct Contract {}
+
+#[near]
+impl Contract {
+    #[result_serializer(borsh)]
+    pub fn get(&self) -> NotBorsh {
+        NotBorsh { value: 0 }
+    }
+}
+
+fn main() {}
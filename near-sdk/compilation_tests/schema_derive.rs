@@ -247,6 +247,51 @@ pub fn json_borsh_schema_spec() {
     const_assert_impls!(StructNoSchemaSpec: near_sdk::borsh::BorshSchema);
 }
 
+pub fn borsh_schema_discriminants() {
+    use near_sdk::borsh::schema::{Definition, Fields};
+    use near_sdk::borsh::BorshSchema;
+
+    #[derive(NearSchema)]
+    #[abi(borsh)]
+    #[borsh(use_discriminant = true)]
+    #[repr(u8)]
+    pub enum ExplicitDiscriminants {
+        Unit = 3,
+        Tuple(u8, u16) = 7,
+        Struct { a: u32 } = 10,
+    }
+
+    #[derive(NearSchema)]
+    #[abi(borsh)]
+    pub enum ImplicitDiscriminants {
+        Unit,
+        Tuple(u8, u16),
+        Struct { a: u32 },
+    }
+
+    let mut definitions = Default::default();
+    ExplicitDiscriminants::add_definitions_recursively(&mut definitions);
+    ImplicitDiscriminants::add_definitions_recursively(&mut definitions);
+
+    let variants = |name: &str| match &definitions[name] {
+        Definition::Enum { variants, .. } => variants
+            .iter()
+            .map(|(discriminant, variant, _)| (*discriminant, variant.as_str()))
+            .collect::<Vec<_>>(),
+        definition => panic!("{name} is not an enum: {definition:?}"),
+    };
+    assert_eq!(variants("ExplicitDiscriminants"), [(3, "Unit"), (7, "Tuple"), (10, "Struct")]);
+    assert_eq!(variants("ImplicitDiscriminants"), [(0, "Unit"), (1, "Tuple"), (2, "Struct")]);
+    assert!(matches!(
+        &definitions["ExplicitDiscriminants__Tuple"],
+        Definition::Struct { fields: Fields::UnnamedFields(fields) } if fields.len() == 2
+    ));
+    assert!(matches!(
+        &definitions["ExplicitDiscriminants__Struct"],
+        Definition::Struct { fields: Fields::NamedFields(fields) } if fields.len() == 1
+    ));
+}
+
 // original comment by @miraclx
 // fixme! this should fail, since A__NEAR_SCHEMA_PROXY does not derive NearSchema
 // fixme! hygeinic macro expansion is required to make this work
@@ -278,4 +323,6 @@ struct A__NEAR_SCHEMA_PROXY {}
 #[derive(NearSchema)]
 struct A(A__NEAR_SCHEMA_PROXY);
 
-fn main() {}
+fn main() {
+    borsh_schema_discriminants();
+}
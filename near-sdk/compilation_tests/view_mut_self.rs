@@ -0,0 +1,20 @@
+//! Explicit view methods can't take `&mut self`
+
+use near_sdk::near;
+
+#[derive(Default)]
+#[near(contract_state)]
+struct Test {}
+
+#[near]
+impl Test {
+    #[near(view)]
+    pub fn get(&self) -> u8 {
+        0
+    }
+
+    #[near(view)]
+    pub fn set(&mut self) {}
+}
+
+fn main() {}
@@ -3,6 +3,7 @@
 //! whenever possible. In case of cross-contract calls prefer using even higher-level API available
 //! through `callback_args`, `callback_args_vec`, `ext_contract`, `Promise`, and `PromiseOrValue`.
 
+use std::cell::Cell;
 use std::convert::TryInto;
 use std::mem::{size_of, size_of_val};
 use std::panic as std_panic;
@@ -12,9 +13,10 @@ use std::{convert::TryFrom, mem::MaybeUninit};
 use crate::mock::MockedBlockchain;
 use crate::promise::Allowance;
 use crate::types::{
-    AccountId, BlockHeight, Gas, NearToken, PromiseIndex, PromiseResult, PublicKey, StorageUsage,
+    AccountId, AccountIdRef, BlockHeight, Gas, NearToken, PromiseIndex, PromiseResult, PublicKey,
+    StorageUsage,
 };
-use crate::{CryptoHash, GasWeight, PromiseError};
+use crate::{CallbackError, CryptoHash, GasWeight, PromiseError, PromiseResultError};
 use near_sys as sys;
 
 const REGISTER_EXPECTED_ERR: &str =
@@ -27,6 +29,12 @@ const ATOMIC_OP_REGISTER: u64 = u64::MAX - 2;
 /// Register used to record evicted values from the storage.
 const EVICTED_REGISTER: u64 = u64::MAX - 1;
 
+thread_local! {
+    /// Number of ids generated by [`generate_request_id`] during the current execution. Reset by
+    /// [`set_blockchain_interface`] in unit tests, where one thread runs several executions.
+    static REQUEST_ID_COUNTER: Cell<u64> = const { Cell::new(0) };
+}
+
 /// Key used to store the state of the contract.
 const STATE_KEY: &[u8] = b"STATE";
 
@@ -100,7 +108,8 @@ pub(crate) unsafe fn read_register_fixed_64(register_id: u64) -> [u8; 64] {
 pub fn set_blockchain_interface(blockchain_interface: MockedBlockchain) {
     crate::mock::with_mocked_blockchain(|b| {
         *b = blockchain_interface;
-    })
+    });
+    REQUEST_ID_COUNTER.with(|counter| counter.set(0));
 }
 
 /// Implements panic hook that converts `PanicInfo` into a string and provides it through the
@@ -209,6 +218,52 @@ pub fn predecessor_account_id() -> AccountId {
     assert_valid_account_id(method_into_register!(predecessor_account_id))
 }
 
+/// Panics unless the predecessor account, see [`predecessor_account_id`], is `expected`.
+///
+/// Use it for methods restricted to a specific account, e.g. a minter or an owner. Methods only
+/// callable by the contract itself should be marked `#[private]` instead.
+///
+/// # Examples
+/// ```
+/// use near_sdk::{env, AccountIdRef};
+///
+/// // The predecessor of the default test context is `bob.near`.
+/// env::assert_predecessor(AccountIdRef::new_or_panic("bob.near"));
+/// ```
+///
+/// ```should_panic
+/// use near_sdk::{env, AccountIdRef};
+///
+/// env::assert_predecessor(AccountIdRef::new_or_panic("minter.near"));
+/// ```
+pub fn assert_predecessor(expected: &AccountIdRef) {
+    let predecessor = predecessor_account_id();
+    if &*predecessor != expected {
+        panic_str(&format!("Method can only be called by {}, not {}", expected, predecessor));
+    }
+}
+
+/// Panics unless the predecessor account, see [`predecessor_account_id`], is one of `expected`.
+///
+/// # Examples
+/// ```
+/// use near_sdk::{env, AccountIdRef};
+///
+/// let admins = [AccountIdRef::new_or_panic("alice.near"), AccountIdRef::new_or_panic("bob.near")];
+/// env::assert_one_of(&admins);
+/// ```
+pub fn assert_one_of(expected: &[&AccountIdRef]) {
+    let predecessor = predecessor_account_id();
+    if !expected.iter().any(|expected| &*predecessor == *expected) {
+        let expected: Vec<&str> = expected.iter().map(|expected| expected.as_str()).collect();
+        panic_str(&format!(
+            "Method can only be called by one of [{}], not {}",
+            expected.join(", "),
+            predecessor
+        ));
+    }
+}
+
 /// Helper function to convert and check the account ID from bytes from the runtime.
 fn assert_valid_account_id(bytes: Vec<u8>) -> AccountId {
     String::from_utf8(bytes)
@@ -230,6 +285,79 @@ pub fn input() -> Option<Vec<u8>> {
     try_method_into_register!(input)
 }
 
+/// The length of the input to the contract call in bytes, without reading the input itself.
+/// If input is not provided returns `None`.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env::input_len;
+///
+/// assert_eq!(input_len(), Some(0));
+/// ```
+pub fn input_len() -> Option<u64> {
+    unsafe { sys::input(ATOMIC_OP_REGISTER) };
+    register_len(ATOMIC_OP_REGISTER)
+}
+
+/// The input to the contract call deserialized from JSON.
+///
+/// This is what `#[near]` methods do with their arguments, and is useful for hand-written
+/// `#[no_mangle]` entrypoints.
+///
+/// # Panics
+/// Panics if there is no input or if it cannot be deserialized as `T`.
+///
+/// # Examples
+/// ```no_run
+/// use near_sdk::env;
+///
+/// #[derive(near_sdk::serde::Deserialize)]
+/// #[serde(crate = "near_sdk::serde")]
+/// struct Args {
+///     key: String,
+///     value: String,
+/// }
+///
+/// #[no_mangle]
+/// pub extern "C" fn set() {
+///     let Args { key, value } = env::input_json();
+///     env::storage_write(key.as_bytes(), value.as_bytes());
+/// }
+/// ```
+pub fn input_json<T: serde::de::DeserializeOwned>() -> T {
+    match input() {
+        Some(input) => serde_json::from_slice(&input)
+            .unwrap_or_else(|_| panic_str("Failed to deserialize input from JSON.")),
+        None => panic_str("Expected input since method has arguments."),
+    }
+}
+
+/// The input to the contract call deserialized from Borsh.
+///
+/// This is what `#[near]` methods with `#[serializer(borsh)]` arguments do, and is useful for
+/// hand-written `#[no_mangle]` entrypoints.
+///
+/// # Panics
+/// Panics if there is no input or if it cannot be deserialized as `T`.
+///
+/// # Examples
+/// ```no_run
+/// use near_sdk::env;
+///
+/// #[no_mangle]
+/// pub extern "C" fn set() {
+///     let (key, value): (Vec<u8>, Vec<u8>) = env::input_borsh();
+///     env::storage_write(&key, &value);
+/// }
+/// ```
+pub fn input_borsh<T: borsh::BorshDeserialize>() -> T {
+    match input() {
+        Some(input) => T::try_from_slice(&input)
+            .unwrap_or_else(|_| panic_str("Failed to deserialize input from Borsh.")),
+        None => panic_str("Expected input since method has arguments."),
+    }
+}
+
 /// Current block index.
 ///
 /// # Examples
@@ -269,6 +397,10 @@ pub fn block_timestamp() -> u64 {
 
 /// Current block timestamp, i.e, number of non-leap-milliseconds since January 1, 1970 0:00:00 UTC.
 ///
+/// The nanosecond [`block_timestamp`] is rounded down. Wrap the values in
+/// [`TimestampNs`](crate::json_types::TimestampNs) or [`TimestampMs`](crate::json_types::TimestampMs)
+/// to keep the unit explicit when returning them from a contract.
+///
 /// # Examples
 /// ```
 /// use near_sdk::env::block_timestamp_ms;
@@ -379,6 +511,44 @@ pub fn used_gas() -> Gas {
     Gas::from_gas(unsafe { sys::used_gas() })
 }
 
+/// Aborts the execution if the current method is called as a view, i.e. without a transaction.
+///
+/// Useful to guard helpers which rely on their state changes being persisted. Methods can also be
+/// checked to be views at compile time with `#[near(view)]`.
+///
+/// # Panics
+///
+/// Panics with a message saying the method cannot be called in a view call. The runtime does not
+/// expose whether the call is a view directly, so on chain this requests [`prepaid_gas`], which
+/// the runtime rejects in views with `ProhibitedInView { method_name: "prepaid_gas" }` before the
+/// contract gets to panic. The message is emitted when the call is known to be a view, such as
+/// with the mocked blockchain in unit tests.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env;
+///
+/// // Passes, the default test context is not a view.
+/// env::assert_not_view();
+/// ```
+pub fn assert_not_view() {
+    if is_view() {
+        panic_str("assert_not_view: the method cannot be called in a view call");
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "unit-testing"))]
+fn is_view() -> bool {
+    crate::mock::with_mocked_blockchain(|b| b.is_view())
+}
+
+#[cfg(not(all(not(target_arch = "wasm32"), feature = "unit-testing")))]
+fn is_view() -> bool {
+    // The runtime aborts view calls requesting the prepaid gas, so this only returns otherwise.
+    let _ = prepaid_gas();
+    false
+}
+
 // ############
 // # Math API #
 // ############
@@ -387,6 +557,8 @@ pub fn used_gas() -> Gas {
 /// the block. This value is not modified in any way each time this function is called within the
 /// same method/block.
 ///
+/// Use [`random_seed_array`] to get the seed without allocating a `Vec`.
+///
 /// # Examples
 /// ```
 /// use near_sdk::env::random_seed;
@@ -456,6 +628,45 @@ pub fn random_seed_array() -> [u8; 32] {
     }
 }
 
+/// Generates an id, for example for a request, that is unique within the current execution and
+/// deterministic given the block it runs in.
+///
+/// The id is the sha256 hash of the [`block_height`], the [`block_timestamp`], a counter of ids
+/// generated so far by the current execution, and the [`random_seed_array`]. Consequently:
+///
+/// - Calling this function repeatedly within one execution always yields distinct ids, since the
+///   counter is part of the hashed data.
+/// - Replaying the same execution in the same block reproduces the same sequence of ids, so every
+///   validator computes identical values.
+/// - Ids generated in different blocks differ as long as their height, timestamp or seed do.
+///   Executions in the same block are only distinguished by the random seed, so contracts that
+///   need ids unique across all of their calls should still record or check them in storage.
+///
+/// The counter is not persisted, so it starts from zero in every execution. Ids are not secret:
+/// anyone who knows the block data can recompute them, and validators can influence the seed.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env::generate_request_id;
+///
+/// let first = generate_request_id();
+/// let second = generate_request_id();
+/// assert_ne!(first, second);
+/// ```
+pub fn generate_request_id() -> CryptoHash {
+    let counter = REQUEST_ID_COUNTER.with(|counter| {
+        let value = counter.get();
+        counter.set(value.wrapping_add(1));
+        value
+    });
+    let mut data = [0u8; 56];
+    data[..8].copy_from_slice(&block_height().to_le_bytes());
+    data[8..16].copy_from_slice(&block_timestamp().to_le_bytes());
+    data[16..24].copy_from_slice(&counter.to_le_bytes());
+    data[24..].copy_from_slice(&random_seed_array());
+    sha256_array(&data)
+}
+
 /// Hashes the random sequence of bytes using sha256.
 ///
 /// # Examples
@@ -1435,6 +1646,93 @@ pub fn promise_result(result_idx: u64) -> PromiseResult {
     }
 }
 
+/// Returns an iterator over the execution results of all promises that caused the callback,
+/// in order from `0` to [`promise_results_count`]. Each result is only read when the iterator
+/// is advanced.
+///
+/// # Examples
+/// ```no_run
+/// use near_sdk::env::promise_results;
+/// use near_sdk::PromiseResult;
+///
+/// let successes: Vec<Vec<u8>> = promise_results()
+///     .filter_map(|result| match result {
+///         PromiseResult::Successful(data) => Some(data),
+///         PromiseResult::Failed => None,
+///     })
+///     .collect();
+/// ```
+pub fn promise_results() -> impl Iterator<Item = PromiseResult> {
+    (0..promise_results_count()).map(promise_result)
+}
+
+/// Returns the execution result of the promise under `result_idx`, as long as it is at most
+/// `max_bytes` long.
+///
+/// The callee controls how much data it returns, so a callback that reads the result blindly
+/// can be made to copy, allocate and deserialize an arbitrarily large value and run out of gas.
+/// This function checks the length of the result first and returns
+/// [`PromiseResultError::TooLong`] without reading it into contract memory if it is too long.
+///
+/// # Examples
+/// ```no_run
+/// use near_sdk::env::promise_result_checked;
+/// use near_sdk::PromiseResultError;
+///
+/// match promise_result_checked(0, 1024) {
+///     Ok(data) => { /* at most 1024 bytes */ }
+///     Err(PromiseResultError::TooLong) => { /* the callee returned too much data */ }
+///     Err(_) => { /* the promise failed */ }
+/// }
+/// ```
+pub fn promise_result_checked(
+    result_idx: u64,
+    max_bytes: usize,
+) -> Result<Vec<u8>, PromiseResultError> {
+    promise_result_internal(result_idx)
+        .map_err(|PromiseError::Failed| PromiseResultError::Failed)?;
+    let len = expect_register(register_len(ATOMIC_OP_REGISTER));
+    if len > max_bytes as u64 {
+        return Err(PromiseResultError::TooLong);
+    }
+    Ok(expect_register(read_register(ATOMIC_OP_REGISTER)))
+}
+
+/// Reads the execution result of the promise under `result_idx` like [`promise_result_checked`]
+/// and deserializes it from JSON.
+///
+/// # Examples
+/// ```no_run
+/// use near_sdk::env::promise_result_as;
+/// use near_sdk::json_types::U128;
+///
+/// let used_amount: U128 = promise_result_as(0, 64).unwrap_or(U128(0));
+/// ```
+pub fn promise_result_as<T: serde::de::DeserializeOwned>(
+    result_idx: u64,
+    max_bytes: usize,
+) -> Result<T, CallbackError> {
+    let data = promise_result_checked(result_idx, max_bytes)?;
+    serde_json::from_slice(&data).map_err(|_| CallbackError::Deserialize)
+}
+
+/// Reads the execution result of the promise under `result_idx` like [`promise_result_checked`]
+/// and deserializes it with Borsh.
+///
+/// # Examples
+/// ```no_run
+/// use near_sdk::env::promise_result_as_borsh;
+///
+/// let used_amount: u128 = promise_result_as_borsh(0, 16).unwrap_or(0);
+/// ```
+pub fn promise_result_as_borsh<T: borsh::BorshDeserialize>(
+    result_idx: u64,
+    max_bytes: usize,
+) -> Result<T, CallbackError> {
+    let data = promise_result_checked(result_idx, max_bytes)?;
+    T::try_from_slice(&data).map_err(|_| CallbackError::Deserialize)
+}
+
 pub(crate) fn promise_result_internal(result_idx: u64) -> Result<(), PromiseError> {
     match unsafe { sys::promise_result(result_idx, ATOMIC_OP_REGISTER) } {
         1 => Ok(()),
@@ -1479,6 +1777,13 @@ pub fn promise_return(promise_idx: PromiseIndex) {
 /// The callback method will execute with a single promise input. Input will either be a payload
 /// provided by the user when calling promise_yield_resume, or a PromiseError in case of timeout.
 ///
+/// Since resumption always delivers its payload successfully, a timeout is the only case in which
+/// the callback sees [`PromiseResult::Failed`](crate::PromiseResult::Failed), or
+/// `Err(PromiseError::Failed)` with `#[callback_result]`. The runtime doesn't provide any other
+/// information about the failure, so errors that the callback has to tell apart from a timeout
+/// must be part of the payload, e.g. by resuming with a serialized `Result<T, E>` and taking
+/// `#[callback_result] result: Result<Result<T, E>, PromiseError>`.
+///
 /// Resumption tokens are specific to the local account; promise_yield_resume must be called from
 /// a method of the same contract.
 ///
@@ -1742,9 +2047,48 @@ pub fn log_str(message: &str) {
     #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
     eprintln!("{}", message);
 
+    #[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+    tracing::info!(target: "near_sdk::log", "{}", message);
+
     unsafe { sys::log_utf8(message.len() as _, message.as_ptr() as _) }
 }
 
+/// Logs `value` serialized as a single line of JSON.
+///
+/// Unlike [`log_str`] with a hand-formatted message, strings and other values are escaped as
+/// needed. The log is not prefixed with `EVENT_JSON:`, use NEP-297 events for logs meant to be
+/// indexed.
+///
+/// # Panics
+/// Panics if `value` cannot be serialized to JSON.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env::log_json;
+/// use near_sdk::serde_json::json;
+///
+/// log_json(&json!({ "action": "withdraw", "amount": "10" }));
+/// ```
+pub fn log_json<T: serde::Serialize + ?Sized>(value: &T) {
+    log_str(&serde_json::to_string(value).unwrap_or_else(|_| panic_str(ERR_LOG_JSON)));
+}
+
+/// Same as [`log_json`], but the log is prefixed with `prefix`.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env::log_json_with_prefix;
+///
+/// // Logs `DEBUG: [1,2,3]`.
+/// log_json_with_prefix("DEBUG: ", &[1, 2, 3]);
+/// ```
+pub fn log_json_with_prefix<T: serde::Serialize + ?Sized>(prefix: &str, value: &T) {
+    let json = serde_json::to_string(value).unwrap_or_else(|_| panic_str(ERR_LOG_JSON));
+    log_str(&[prefix, &json].concat());
+}
+
+const ERR_LOG_JSON: &str = "Failed to serialize log value to JSON.";
+
 /// Log the UTF-8 encodable message.
 ///
 /// # Examples
@@ -1758,6 +2102,9 @@ pub fn log(message: &[u8]) {
     #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
     eprintln!("{}", String::from_utf8_lossy(message));
 
+    #[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+    tracing::info!(target: "near_sdk::log", "{}", String::from_utf8_lossy(message));
+
     unsafe { sys::log_utf8(message.len() as _, message.as_ptr() as _) }
 }
 
@@ -1816,6 +2163,65 @@ pub fn storage_read(key: &[u8]) -> Option<Vec<u8>> {
         _ => abort(),
     }
 }
+
+/// Reads the value stored under the given key into `buf`, returning whether the key existed.
+///
+/// `buf` is cleared first and its capacity is reused, so it only reallocates when the value is
+/// larger than any value previously read into it. Prefer this over [`storage_read`] when
+/// repeatedly reading large values. If the key does not exist, `buf` is left empty.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env::{storage_write, storage_read_into};
+///
+/// let mut buf = Vec::new();
+/// assert!(!storage_read_into(b"key", &mut buf));
+/// storage_write(b"key", b"value");
+/// assert!(storage_read_into(b"key", &mut buf));
+/// assert_eq!(buf, b"value");
+/// ```
+pub fn storage_read_into(key: &[u8], buf: &mut Vec<u8>) -> bool {
+    buf.clear();
+    match unsafe { sys::storage_read(key.len() as _, key.as_ptr() as _, ATOMIC_OP_REGISTER) } {
+        0 => false,
+        1 => {
+            let len: usize = register_len(ATOMIC_OP_REGISTER)
+                .unwrap_or_else(|| abort())
+                .try_into()
+                .unwrap_or_else(|_| abort());
+            buf.reserve(len);
+            //* SAFETY: The buffer is empty and has at least `len` bytes of capacity, which is the
+            //*         exact size of the register that is being read from.
+            unsafe {
+                sys::read_register(ATOMIC_OP_REGISTER, buf.as_mut_ptr() as u64);
+                buf.set_len(len);
+            }
+            true
+        }
+        _ => abort(),
+    }
+}
+
+/// Reads the values stored under each of the given keys, in order.
+///
+/// Each entry of the result is the same as calling [`storage_read`] with the key at the same
+/// index. The reads are currently issued one by one.
+///
+/// # Examples
+/// ```
+/// use near_sdk::env::{storage_write, storage_read_many};
+///
+/// storage_write(b"a", b"1");
+/// storage_write(b"c", b"3");
+/// assert_eq!(
+///     storage_read_many(&[b"a", b"b", b"c"]),
+///     vec![Some(b"1".to_vec()), None, Some(b"3".to_vec())]
+/// );
+/// ```
+pub fn storage_read_many(keys: &[&[u8]]) -> Vec<Option<Vec<u8>>> {
+    keys.iter().map(|key| storage_read(key)).collect()
+}
+
 /// Removes the value stored under the given key.
 /// If key-value existed returns `true`, otherwise `false`.
 ///
@@ -2090,6 +2496,17 @@ mod tests {
         assert_eq!(super::random_seed(), [8; 32]);
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn random_seed_array_matches_random_seed() {
+        let seed: [u8; 32] = std::array::from_fn(|i| i as u8);
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().random_seed(seed).build());
+
+        let from_vec: [u8; 32] = super::random_seed().try_into().unwrap();
+        assert_eq!(super::random_seed_array(), from_vec);
+        assert_eq!(super::random_seed_array(), seed);
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     #[cfg(feature = "unstable")]
     #[test]
@@ -2286,4 +2703,339 @@ mod tests {
 
         assert!(!super::alt_bn128_pairing_check(&invalid_pair));
     }
+
+    #[test]
+    fn block_and_epoch_height_from_context() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+        assert_eq!(super::block_height(), 0);
+        assert_eq!(super::epoch_height(), 0);
+
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new()
+            .block_height(1_000)
+            .epoch_height(7)
+            .build());
+        assert_eq!(super::block_height(), 1_000);
+        assert_eq!(super::epoch_height(), 7);
+    }
+
+    #[test]
+    fn assert_not_view() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+        super::assert_not_view();
+    }
+
+    #[test]
+    #[should_panic(expected = "the method cannot be called in a view call")]
+    fn assert_not_view_in_view() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().is_view(true).build());
+        super::assert_not_view();
+    }
+
+    #[test]
+    fn assert_predecessor() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new()
+            .predecessor_account_id("minter.near".parse().unwrap())
+            .build());
+
+        super::assert_predecessor(AccountIdRef::new_or_panic("minter.near"));
+        super::assert_one_of(&[AccountIdRef::new_or_panic("minter.near")]);
+        super::assert_one_of(&[
+            AccountIdRef::new_or_panic("admin.near"),
+            AccountIdRef::new_or_panic("minter.near"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Method can only be called by minter.near, not bob.near")]
+    fn assert_predecessor_mismatch() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+
+        super::assert_predecessor(AccountIdRef::new_or_panic("minter.near"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Method can only be called by one of [admin.near, minter.near], not bob.near"
+    )]
+    fn assert_one_of_mismatch() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+
+        super::assert_one_of(&[
+            AccountIdRef::new_or_panic("admin.near"),
+            AccountIdRef::new_or_panic("minter.near"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Method can only be called by one of [], not bob.near")]
+    fn assert_one_of_empty() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+
+        super::assert_one_of(&[]);
+    }
+
+    #[test]
+    fn input_len() {
+        let mut context = crate::test_utils::VMContextBuilder::new().build();
+        context.input = b"0123456789".to_vec();
+        crate::testing_env!(context);
+
+        assert_eq!(super::input_len(), Some(10));
+        assert_eq!(super::input(), Some(b"0123456789".to_vec()));
+    }
+
+    #[test]
+    fn input_json() {
+        let mut context = crate::test_utils::VMContextBuilder::new().build();
+        context.input = br#"{"a":1,"b":"two"}"#.to_vec();
+        crate::testing_env!(context);
+
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        #[serde(crate = "crate::serde")]
+        struct Args {
+            a: u32,
+            b: String,
+        }
+        assert_eq!(super::input_json::<Args>(), Args { a: 1, b: "two".to_string() });
+    }
+
+    #[test]
+    fn input_json_from_builder() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(crate = "crate::serde")]
+        struct Args {
+            a: u32,
+            b: String,
+        }
+
+        // Written the way a contract method is exported, without the `#[near]` macro.
+        extern "C" fn greet() {
+            let Args { a, b } = super::input_json();
+            super::log_str(&format!("{} {}", a, b));
+        }
+
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new()
+            .input_json(&Args { a: 1, b: "two".to_string() })
+            .random_seed([7; 32])
+            .build());
+
+        greet();
+        assert_eq!(crate::test_utils::get_logs(), ["1 two"]);
+        assert_eq!(super::random_seed_array(), [7; 32]);
+
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().input(vec![1, 2]).build());
+        assert_eq!(super::input(), Some(vec![1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to deserialize input from JSON.")]
+    fn input_json_malformed() {
+        let mut context = crate::test_utils::VMContextBuilder::new().build();
+        context.input = br#"{"a":"#.to_vec();
+        crate::testing_env!(context);
+
+        super::input_json::<serde_json::Value>();
+    }
+
+    #[test]
+    fn input_borsh() {
+        let mut context = crate::test_utils::VMContextBuilder::new().build();
+        context.input = borsh::to_vec(&(1u32, "two".to_string())).unwrap();
+        crate::testing_env!(context);
+
+        assert_eq!(super::input_borsh::<(u32, String)>(), (1, "two".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to deserialize input from Borsh.")]
+    fn input_borsh_malformed() {
+        let mut context = crate::test_utils::VMContextBuilder::new().build();
+        context.input = vec![1, 2, 3];
+        crate::testing_env!(context);
+
+        super::input_borsh::<u64>();
+    }
+
+    #[test]
+    fn storage_read_into() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+        super::storage_write(b"large", &[7; 1024]);
+        super::storage_write(b"small", &[1, 2, 3]);
+
+        let mut buf = Vec::new();
+        assert!(super::storage_read_into(b"large", &mut buf));
+        assert_eq!(Some(&buf), super::storage_read(b"large").as_ref());
+        let (capacity, ptr) = (buf.capacity(), buf.as_ptr());
+
+        assert!(super::storage_read_into(b"small", &mut buf));
+        assert_eq!(Some(&buf), super::storage_read(b"small").as_ref());
+        assert!(super::storage_read_into(b"large", &mut buf));
+        assert_eq!(Some(&buf), super::storage_read(b"large").as_ref());
+        assert_eq!((buf.capacity(), buf.as_ptr()), (capacity, ptr));
+
+        assert!(!super::storage_read_into(b"missing", &mut buf));
+        assert!(buf.is_empty());
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn generate_request_id() {
+        use crate::test_utils::VMContextBuilder;
+        use std::collections::HashSet;
+
+        fn ids_in_block(height: u64, seed: u8, count: usize) -> Vec<crate::CryptoHash> {
+            crate::testing_env!(VMContextBuilder::new()
+                .block_height(height)
+                .block_timestamp(height * 1_000_000_000)
+                .random_seed([seed; 32])
+                .build());
+            (0..count).map(|_| super::generate_request_id()).collect()
+        }
+
+        // The first id of an execution is derived from the block data and a zero counter.
+        let first = ids_in_block(10, 1, 1)[0];
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&10u64.to_le_bytes());
+        preimage.extend_from_slice(&10_000_000_000u64.to_le_bytes());
+        preimage.extend_from_slice(&0u64.to_le_bytes());
+        preimage.extend_from_slice(&[1; 32]);
+        assert_eq!(first, super::sha256_array(&preimage));
+
+        // Replaying an execution in the same block yields the same ids, as `testing_env!` starts
+        // a new execution.
+        assert_eq!(ids_in_block(10, 1, 5), ids_in_block(10, 1, 5));
+        assert_eq!(ids_in_block(10, 1, 5)[0], first);
+
+        // Ids are distinct within a block and across blocks.
+        let mut ids = ids_in_block(10, 1, 50);
+        ids.extend(ids_in_block(11, 1, 50));
+        ids.extend(ids_in_block(11, 2, 50));
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+    }
+
+    #[test]
+    fn storage_read_many() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+        super::storage_write(b"one", b"1");
+        super::storage_write(b"three", b"3");
+
+        assert_eq!(super::storage_read_many(&[]), Vec::<Option<Vec<u8>>>::new());
+        assert_eq!(
+            super::storage_read_many(&[b"three", b"two", b"one", b"three"]),
+            vec![Some(b"3".to_vec()), None, Some(b"1".to_vec()), Some(b"3".to_vec())]
+        );
+    }
+
+    #[test]
+    fn promise_results_iterates_all() {
+        crate::testing_env!(
+            crate::test_utils::VMContextBuilder::new().build(),
+            crate::test_vm_config(),
+            crate::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![
+                PromiseResult::Successful(b"first".to_vec()),
+                PromiseResult::Failed,
+                PromiseResult::Successful(vec![]),
+            ],
+        );
+
+        let results: Vec<PromiseResult> = super::promise_results().collect();
+        assert_eq!(
+            results,
+            vec![
+                PromiseResult::Successful(b"first".to_vec()),
+                PromiseResult::Failed,
+                PromiseResult::Successful(vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn promise_results_empty() {
+        crate::testing_env!(crate::test_utils::VMContextBuilder::new().build());
+
+        assert_eq!(super::promise_results().count(), 0);
+    }
+
+    #[test]
+    fn promise_result_checked() {
+        crate::testing_env!(
+            crate::test_utils::VMContextBuilder::new().build(),
+            crate::test_vm_config(),
+            crate::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![PromiseResult::Successful(b"result".to_vec()), PromiseResult::Failed],
+        );
+
+        assert_eq!(super::promise_result_checked(0, 6), Ok(b"result".to_vec()));
+        assert_eq!(super::promise_result_checked(0, 5), Err(PromiseResultError::TooLong));
+        assert_eq!(super::promise_result_checked(1, 1024), Err(PromiseResultError::Failed));
+    }
+
+    #[test]
+    fn promise_result_as() {
+        crate::testing_env!(
+            crate::test_utils::VMContextBuilder::new().build(),
+            crate::test_vm_config(),
+            crate::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![
+                PromiseResult::Successful(b"\"100\"".to_vec()),
+                PromiseResult::Successful(b"not json".to_vec()),
+                PromiseResult::Failed,
+            ],
+        );
+
+        assert_eq!(super::promise_result_as(0, 5), Ok(crate::json_types::U128(100)));
+        assert_eq!(
+            super::promise_result_as::<crate::json_types::U128>(0, 4),
+            Err(CallbackError::TooLong)
+        );
+        assert_eq!(super::promise_result_as::<u64>(1, 1024), Err(CallbackError::Deserialize));
+        assert_eq!(super::promise_result_as::<u64>(2, 1024), Err(CallbackError::Failed));
+    }
+
+    #[test]
+    fn promise_result_as_borsh() {
+        crate::testing_env!(
+            crate::test_utils::VMContextBuilder::new().build(),
+            crate::test_vm_config(),
+            crate::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![
+                PromiseResult::Successful(borsh::to_vec(&7u64).unwrap()),
+                PromiseResult::Successful(vec![1, 2, 3]),
+                PromiseResult::Failed,
+            ],
+        );
+
+        assert_eq!(super::promise_result_as_borsh(0, 8), Ok(7u64));
+        assert_eq!(super::promise_result_as_borsh::<u64>(0, 7), Err(CallbackError::TooLong));
+        assert_eq!(super::promise_result_as_borsh::<u64>(1, 1024), Err(CallbackError::Deserialize));
+        assert_eq!(super::promise_result_as_borsh::<u64>(2, 1024), Err(CallbackError::Failed));
+    }
+
+    #[test]
+    fn log_json() {
+        #[derive(serde::Serialize)]
+        #[serde(crate = "crate::serde")]
+        struct Withdrawal<'a> {
+            account_id: &'a str,
+            amount: crate::json_types::U128,
+        }
+
+        super::log_json(&Withdrawal { account_id: "alice.near", amount: 10.into() });
+        super::log_json("say \"hi\"\n\\");
+        super::log_json_with_prefix("DEBUG: ", &[1, 2, 3]);
+
+        assert_eq!(
+            crate::test_utils::get_logs(),
+            vec![
+                r#"{"account_id":"alice.near","amount":"10"}"#,
+                r#""say \"hi\"\n\\""#,
+                "DEBUG: [1,2,3]",
+            ]
+        );
+    }
 }
@@ -9,7 +9,7 @@ use near_parameters::{RuntimeConfigStore, RuntimeFeesConfig};
 use near_primitives_core::version::PROTOCOL_VERSION;
 use near_vm_runner::logic::mocks::mock_external::MockedExternal;
 use near_vm_runner::logic::types::{PromiseResult as VmPromiseResult, ReceiptIndex};
-use near_vm_runner::logic::{ExecutionResultState, External, MemoryLike, VMLogic};
+use near_vm_runner::logic::{ExecutionResultState, External, MemSlice, MemoryLike, VMLogic};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::marker::PhantomData;
@@ -28,6 +28,8 @@ where
     // We keep ownership over logic fixture so that references in `VMLogic` are valid.
     #[allow(dead_code)]
     logic_fixture: LogicFixture,
+    /// Value passed to the last `value_return` call.
+    return_value: Option<Vec<u8>>,
     _memory: PhantomData<Memory>,
 }
 
@@ -104,7 +106,7 @@ where
         };
 
         let logic = RefCell::new(logic);
-        Self { logic, logic_fixture, _memory: PhantomData }
+        Self { logic, logic_fixture, return_value: None, _memory: PhantomData }
     }
 
     pub fn take_storage(&mut self) -> HashMap<Vec<u8>, Vec<u8>> {
@@ -156,6 +158,26 @@ where
     pub fn logs(&self) -> Vec<String> {
         self.logic.borrow().logs().to_vec()
     }
+
+    /// Returns the value passed to the last `value_return` call, if any.
+    pub fn return_value(&self) -> Option<Vec<u8>> {
+        self.return_value.clone()
+    }
+
+    /// Returns whether the mocked call is a view, i.e. the context has a view config.
+    pub fn is_view(&self) -> bool {
+        self.logic_fixture.context.view_config.is_some()
+    }
+
+    fn value_return(&mut self, value_len: u64, value_ptr: u64) {
+        self.logic.borrow_mut().value_return(value_len, value_ptr).unwrap();
+        let value = self
+            .logic_fixture
+            .memory
+            .view_memory(MemSlice { ptr: value_ptr, len: value_len })
+            .unwrap_or_else(|_| panic!("failed to read the returned value from memory"));
+        self.return_value = Some(value.into_owned());
+    }
 }
 
 fn sdk_context_to_vm_context(
@@ -318,7 +340,7 @@ mod mock_chain {
     }
     #[no_mangle]
     extern "C-unwind" fn value_return(value_len: u64, value_ptr: u64) {
-        with_mock_interface(|b| b.value_return(value_len, value_ptr))
+        crate::mock::with_mocked_blockchain(|b| b.value_return(value_len, value_ptr))
     }
     #[no_mangle]
     extern "C-unwind" fn panic() -> ! {
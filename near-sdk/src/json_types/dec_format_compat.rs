@@ -0,0 +1,197 @@
+//! Serde helpers for amounts that are sent by clients either as decimal strings or as JSON
+//! numbers.
+//!
+//! [`NearToken`] and [`Gas`] serialize to a JSON string holding their decimal value, but some
+//! front ends send small amounts as plain JSON numbers. Fields annotated with
+//! `#[serde(with = "near_sdk::json_types::dec_format_compat")]` accept both and are always
+//! serialized as a string.
+//!
+//! JSON numbers are only accepted if they are integers that fit the amount exactly, that is up to
+//! `u64::MAX`. Larger numbers, fractions and exponents are rejected rather than rounded, since
+//! they can't be parsed without losing precision. Such amounts have to be sent as strings.
+//!
+//! # Example
+//! ```
+//! use near_sdk::serde::{Deserialize, Serialize};
+//! use near_sdk::{Gas, NearToken};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! #[serde(crate = "near_sdk::serde")]
+//! struct Deposit {
+//!     #[serde(with = "near_sdk::json_types::dec_format_compat")]
+//!     amount: NearToken,
+//!     #[serde(with = "near_sdk::json_types::dec_format_compat")]
+//!     gas: Gas,
+//! }
+//!
+//! let deposit: Deposit = serde_json::from_str(r#"{"amount": 1000, "gas": "30"}"#).unwrap();
+//! assert_eq!(deposit.amount, NearToken::from_yoctonear(1000));
+//! assert_eq!(
+//!     serde_json::to_string(&deposit).unwrap(),
+//!     r#"{"amount":"1000","gas":"30"}"#
+//! );
+//! ```
+
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::{Gas, NearToken};
+
+mod private {
+    /// Seal `DecFormat` implementations to limit usage to the builtin amount types
+    pub trait Sealed {}
+
+    impl Sealed for crate::NearToken {}
+    impl Sealed for crate::Gas {}
+}
+
+/// Amount types supported by [`dec_format_compat`](self).
+pub trait DecFormat: Sized + self::private::Sealed {
+    #[doc(hidden)]
+    fn to_dec(&self) -> u128;
+
+    /// Returns `None` if `value` overflows the amount type.
+    #[doc(hidden)]
+    fn from_dec(value: u128) -> Option<Self>;
+}
+
+impl DecFormat for NearToken {
+    fn to_dec(&self) -> u128 {
+        self.as_yoctonear()
+    }
+
+    fn from_dec(value: u128) -> Option<Self> {
+        Some(NearToken::from_yoctonear(value))
+    }
+}
+
+impl DecFormat for Gas {
+    fn to_dec(&self) -> u128 {
+        self.as_gas().into()
+    }
+
+    fn from_dec(value: u128) -> Option<Self> {
+        u64::try_from(value).ok().map(Gas::from_gas)
+    }
+}
+
+/// Serializes the amount as a JSON string holding its decimal value.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: DecFormat,
+    S: Serializer,
+{
+    serializer.collect_str(&value.to_dec())
+}
+
+/// Deserializes the amount from a JSON string holding its decimal value, or from a JSON integer.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DecFormat,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DecVisitor(core::marker::PhantomData))
+}
+
+struct DecVisitor<T>(core::marker::PhantomData<T>);
+
+impl<T: DecFormat> DecVisitor<T> {
+    fn from_u128<E: de::Error>(value: u128) -> Result<T, E> {
+        T::from_dec(value).ok_or_else(|| E::custom(format!("amount {} is out of range", value)))
+    }
+}
+
+impl<'de, T: DecFormat> Visitor<'de> for DecVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a decimal string or a non-negative integer")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        let value = value.parse::<u128>().map_err(|err| E::custom(err.to_string()))?;
+        Self::from_u128(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        Self::from_u128(value.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<T, E> {
+        Self::from_u128(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        let value = u64::try_from(value).map_err(|_| E::custom("amount can't be negative"))?;
+        self.visit_u64(value)
+    }
+
+    fn visit_f64<E: de::Error>(self, _value: f64) -> Result<T, E> {
+        Err(E::custom(
+            "amount is not an integer or is too large for a JSON number, pass it as a string",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{Gas, NearToken};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "crate::serde")]
+    struct Amounts {
+        #[serde(with = "crate::json_types::dec_format_compat")]
+        token: NearToken,
+        #[serde(with = "crate::json_types::dec_format_compat")]
+        gas: Gas,
+    }
+
+    fn parse(json: &str) -> Result<Amounts, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn round_trip() {
+        for (token, gas) in [(0, 0), (1, 1), (u128::MAX, u64::MAX)] {
+            let amounts =
+                Amounts { token: NearToken::from_yoctonear(token), gas: Gas::from_gas(gas) };
+            let json = serde_json::to_string(&amounts).unwrap();
+            assert_eq!(json, format!(r#"{{"token":"{}","gas":"{}"}}"#, token, gas));
+            assert_eq!(parse(&json).unwrap(), amounts);
+        }
+    }
+
+    #[test]
+    fn mixed_input() {
+        let expected = Amounts { token: NearToken::from_yoctonear(5), gas: Gas::from_gas(7) };
+        assert_eq!(parse(r#"{"token": 5, "gas": "7"}"#).unwrap(), expected);
+        assert_eq!(parse(r#"{"token": "5", "gas": 7}"#).unwrap(), expected);
+        assert_eq!(parse(r#"{"token": 5, "gas": 7}"#).unwrap(), expected);
+
+        let max = format!(r#"{{"token": {}, "gas": {}}}"#, u64::MAX, u64::MAX);
+        assert_eq!(
+            parse(&max).unwrap(),
+            Amounts {
+                token: NearToken::from_yoctonear(u64::MAX.into()),
+                gas: Gas::from_gas(u64::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        // Past `u64::MAX` JSON numbers are parsed as floats and lose precision.
+        assert!(parse(r#"{"token": 18446744073709551616, "gas": 0}"#).is_err());
+        assert!(parse(r#"{"token": 0, "gas": 18446744073709551616}"#).is_err());
+        assert!(parse(r#"{"token": 0, "gas": "18446744073709551616"}"#).is_err());
+        assert!(parse(r#"{"token": 1.5, "gas": 0}"#).is_err());
+        assert!(parse(r#"{"token": 1e3, "gas": 0}"#).is_err());
+        assert!(parse(r#"{"token": -1, "gas": 0}"#).is_err());
+        assert!(parse(r#"{"token": "abc", "gas": 0}"#).is_err());
+        assert!(parse(r#"{"token": null, "gas": 0}"#).is_err());
+    }
+}
@@ -66,6 +66,8 @@ macro_rules! impl_str_type {
     };
 }
 
+pub(crate) use impl_str_type;
+
 impl_str_type!(U128, u128);
 impl_str_type!(U64, u64);
 impl_str_type!(I128, i128);
@@ -83,8 +85,14 @@ mod tests {
             assert_eq!(a, b);
 
             let str: String = serde_json::to_string(&str_a).unwrap();
+            assert_eq!(str, format!("\"{}\"", a));
             let deser_a: $str_type = serde_json::from_str(&str).unwrap();
             assert_eq!(a, deser_a.0);
+
+            let bytes = borsh::to_vec(&str_a).unwrap();
+            assert_eq!(bytes, borsh::to_vec(&a).unwrap());
+            let borsh_a: $str_type = borsh::from_slice(&bytes).unwrap();
+            assert_eq!(a, borsh_a.0);
         };
     }
 
@@ -137,4 +145,15 @@ mod tests {
         test_serde!(I64, i64, i64::MIN);
         assert!(I64::from(i64::MIN) < I64::from(i64::MAX));
     }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<U128>("\"-1\"").is_err());
+        assert!(serde_json::from_str::<I128>("\"abc\"").is_err());
+        assert!(serde_json::from_str::<I128>("\"\"").is_err());
+        assert!(serde_json::from_str::<I128>("\"1.5\"").is_err());
+        assert!(serde_json::from_str::<I64>("\"9223372036854775808\"").is_err());
+        assert!(serde_json::from_str::<I64>("-1").is_err());
+        assert_eq!(serde_json::from_str::<I64>("\"-42\"").unwrap(), I64(-42));
+    }
 }
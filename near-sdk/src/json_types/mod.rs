@@ -1,13 +1,18 @@
 //! Helper types for JSON serialization.
 
+pub mod dec_format_compat;
 mod hash;
 mod integers;
+mod stringified;
+mod time;
 mod vector;
 
 use crate::types::{AccountId, PublicKey};
 
 pub use hash::Base58CryptoHash;
 pub use integers::{I128, I64, U128, U64};
+pub use stringified::Stringified;
+pub use time::{DurationNs, TimestampMs, TimestampNs};
 pub use vector::Base64VecU8;
 
 #[deprecated(
@@ -0,0 +1,127 @@
+use near_sdk_macros::near;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Helper class to serialize any `T` as a JSON string containing its JSON representation.
+///
+/// This is useful for front ends that expect nested values, such as arrays of numbers, to be
+/// passed around as strings.
+///
+/// # Example
+/// ```
+/// use near_sdk::json_types::Stringified;
+///
+/// let value = Stringified(vec![1u64, 2, 3]);
+/// assert_eq!(serde_json::to_string(&value).unwrap(), r#""[1,2,3]""#);
+///
+/// let parsed: Stringified<Vec<u64>> = serde_json::from_str(r#""[1,2,3]""#).unwrap();
+/// assert_eq!(parsed.0, vec![1, 2, 3]);
+/// ```
+#[near(inside_nearsdk)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Stringified<T>(pub T);
+
+impl<T> From<T> for Stringified<T> {
+    fn from(v: T) -> Self {
+        Self(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Stringified<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let s = serde_json::to_string(&self.0).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&s)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Stringified<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        serde_json::from_str(&s).map(Self).map_err(|err| {
+            serde::de::Error::custom(format!("invalid stringified JSON value: {}", err))
+        })
+    }
+}
+
+#[cfg(feature = "abi")]
+impl<T> schemars::JsonSchema for Stringified<T> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        String::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Point {
+        x: u64,
+        label: String,
+    }
+
+    #[test]
+    fn test_vec_roundtrip() {
+        let value = Stringified(vec![1u64, 2, 3]);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#""[1,2,3]""#);
+        let parsed: Stringified<Vec<u64>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, value);
+
+        let empty: Stringified<Vec<u64>> = serde_json::from_str(r#""[]""#).unwrap();
+        assert!(empty.0.is_empty());
+    }
+
+    #[test]
+    fn test_struct_roundtrip() {
+        let value = Stringified(Point { x: 7, label: "say \"hi\"".to_string() });
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#""{\"x\":7,\"label\":\"say \\\"hi\\\"\"}""#);
+        let parsed: Stringified<Point> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, value);
+    }
+
+    #[test]
+    fn test_nested() {
+        let value = Stringified(Stringified(vec!["a".to_string()]));
+        let json = serde_json::to_string(&value).unwrap();
+        let parsed: Stringified<Stringified<Vec<String>>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, value);
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        // Not a string at all.
+        assert!(serde_json::from_str::<Stringified<Vec<u64>>>("[1,2,3]").is_err());
+
+        // A string holding JSON of the wrong shape.
+        let err = serde_json::from_str::<Stringified<Vec<u64>>>(r#""[1,\"a\"]""#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid stringified JSON value: invalid type"));
+
+        // A string holding malformed JSON.
+        let err = serde_json::from_str::<Stringified<Vec<u64>>>(r#""[1,2""#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid stringified JSON value: EOF"));
+    }
+
+    #[test]
+    fn test_borsh() {
+        let value = Stringified(vec![1u64, 2, 3]);
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&value.0).unwrap());
+        assert_eq!(borsh::from_slice::<Stringified<Vec<u64>>>(&bytes).unwrap(), value);
+    }
+}
@@ -0,0 +1,94 @@
+//! Timestamp and duration types with an explicit unit, serialized as base-10 strings.
+//!
+//! The runtime reports [`block_timestamp`](crate::env::block_timestamp) in nanoseconds, while
+//! front ends usually work with milliseconds. These wrappers keep the unit in the type.
+//! Conversions to a coarser unit round down, the same as
+//! [`block_timestamp_ms`](crate::env::block_timestamp_ms).
+
+use super::integers::impl_str_type;
+use near_sdk_macros::near;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const NANOS_PER_MILLI: u64 = 1_000_000;
+
+impl_str_type!(TimestampNs, u64);
+impl_str_type!(TimestampMs, u64);
+impl_str_type!(DurationNs, u64);
+
+impl TimestampNs {
+    /// Converts to milliseconds, rounding down.
+    pub const fn to_ms(self) -> TimestampMs {
+        TimestampMs(self.0 / NANOS_PER_MILLI)
+    }
+
+    /// Converts from milliseconds, saturating at [`u64::MAX`] nanoseconds.
+    pub const fn from_ms(ms: TimestampMs) -> Self {
+        Self(ms.0.saturating_mul(NANOS_PER_MILLI))
+    }
+}
+
+impl TimestampMs {
+    /// Converts to nanoseconds, saturating at [`u64::MAX`].
+    pub const fn to_ns(self) -> TimestampNs {
+        TimestampNs::from_ms(self)
+    }
+
+    /// Converts from nanoseconds, rounding down.
+    pub const fn from_ns(ns: TimestampNs) -> Self {
+        ns.to_ms()
+    }
+}
+
+impl DurationNs {
+    /// Returns the number of whole milliseconds, rounding down.
+    pub const fn to_ms(self) -> u64 {
+        self.0 / NANOS_PER_MILLI
+    }
+
+    /// Creates a duration from milliseconds, saturating at [`u64::MAX`] nanoseconds.
+    pub const fn from_ms(ms: u64) -> Self {
+        Self(ms.saturating_mul(NANOS_PER_MILLI))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ns_to_ms_rounds_down() {
+        assert_eq!(TimestampNs(0).to_ms(), TimestampMs(0));
+        assert_eq!(TimestampNs(999_999).to_ms(), TimestampMs(0));
+        assert_eq!(TimestampNs(1_000_000).to_ms(), TimestampMs(1));
+        assert_eq!(TimestampNs(1_999_999).to_ms(), TimestampMs(1));
+        assert_eq!(TimestampMs::from_ns(TimestampNs(1_999_999)), TimestampMs(1));
+        assert_eq!(DurationNs(2_999_999).to_ms(), 2);
+    }
+
+    #[test]
+    fn test_ms_to_ns() {
+        assert_eq!(
+            TimestampNs::from_ms(TimestampMs(1_700_000_000_000)).0,
+            1_700_000_000_000_000_000
+        );
+        assert_eq!(TimestampMs(5).to_ns(), TimestampNs(5_000_000));
+        assert_eq!(DurationNs::from_ms(3), DurationNs(3_000_000));
+        assert_eq!(TimestampMs(u64::MAX).to_ns(), TimestampNs(u64::MAX));
+        assert_eq!(DurationNs::from_ms(u64::MAX), DurationNs(u64::MAX));
+
+        let ms = TimestampMs(1_700_000_000_123);
+        assert_eq!(ms.to_ns().to_ms(), ms);
+    }
+
+    #[test]
+    fn test_serde() {
+        let ts = TimestampNs(1_700_000_000_000_000_001);
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(json, "\"1700000000000000001\"");
+        assert_eq!(serde_json::from_str::<TimestampNs>(&json).unwrap(), ts);
+
+        assert_eq!(serde_json::to_string(&TimestampMs(42)).unwrap(), "\"42\"");
+        assert_eq!(serde_json::from_str::<DurationNs>("\"7\"").unwrap(), DurationNs(7));
+        assert!(serde_json::from_str::<TimestampMs>("7").is_err());
+    }
+}
@@ -1,5 +1,6 @@
+use base64::Engine;
 use near_sdk_macros::near;
-use serde::{Deserialize, Deserializer, Serializer};
+use serde::{Deserializer, Serializer};
 
 /// Helper class to serialize/deserialize `Vec<u8>` to base64 string.
 
@@ -13,6 +14,29 @@ pub struct Base64VecU8(
     pub Vec<u8>,
 );
 
+impl Base64VecU8 {
+    /// Decodes the base64 string `s` into `buf`, replacing its contents.
+    ///
+    /// Unlike building a new [`Base64VecU8`], this reuses the capacity of `buf`, e.g. when
+    /// decoding many values in a loop. On error, `buf` is left empty.
+    ///
+    /// # Example
+    /// ```
+    /// use near_sdk::json_types::Base64VecU8;
+    ///
+    /// let mut buf = Vec::new();
+    /// Base64VecU8::decode_into("ZHkfFAAXIA==", &mut buf).unwrap();
+    /// assert_eq!(buf, [100, 121, 31, 20, 0, 23, 32]);
+    ///
+    /// assert!(Base64VecU8::decode_into("not base64!", &mut buf).is_err());
+    /// assert!(buf.is_empty());
+    /// ```
+    pub fn decode_into(s: &str, buf: &mut Vec<u8>) -> Result<(), base64::DecodeError> {
+        buf.clear();
+        base64::engine::general_purpose::STANDARD.decode_vec(s, buf).inspect_err(|_| buf.clear())
+    }
+}
+
 impl From<Vec<u8>> for Base64VecU8 {
     fn from(v: Vec<u8>) -> Self {
         Self(v)
@@ -40,7 +64,6 @@ impl From<Base64VecU8> for Vec<u8> {
 /// ```
 mod base64_bytes {
     use super::*;
-    use base64::Engine;
     use serde::de;
 
     pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
@@ -54,8 +77,25 @@ mod base64_bytes {
     where
         D: Deserializer<'de>,
     {
-        let s: String = Deserialize::deserialize(deserializer)?;
-        base64::engine::general_purpose::STANDARD.decode(s.as_str()).map_err(de::Error::custom)
+        // Decodes straight from the input string, without copying it into an owned `String`
+        // first when the deserializer can lend it.
+        struct Base64Visitor;
+
+        impl de::Visitor<'_> for Base64Visitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a base64 string")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Vec<u8>, E> {
+                let mut buf = Vec::new();
+                Base64VecU8::decode_into(s, &mut buf).map_err(de::Error::custom)?;
+                Ok(buf)
+            }
+        }
+
+        deserializer.deserialize_str(Base64Visitor)
     }
 }
 
@@ -95,6 +135,44 @@ mod tests {
         test_serde!(vec![123; 16000]);
     }
 
+    #[test]
+    fn test_decode_into() {
+        let mut buf = Vec::with_capacity(64);
+        let capacity = buf.capacity();
+        for a in [vec![], vec![0], vec![3, 255, 255, 13, 0, 23], vec![123; 48]] {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(&a);
+            Base64VecU8::decode_into(&encoded, &mut buf).unwrap();
+            assert_eq!(buf, a);
+            assert_eq!(buf.capacity(), capacity);
+
+            let wrapped: Base64VecU8 =
+                serde_json::from_str(&serde_json::to_string(&Base64VecU8(a.clone())).unwrap())
+                    .unwrap();
+            assert_eq!(wrapped.0, buf);
+        }
+    }
+
+    #[test]
+    fn test_decode_into_invalid() {
+        let mut buf = vec![1, 2, 3];
+        for invalid in ["ZHkfFAAXIA=", "ZHkf FAAXIA==", "ZHk", "%%%%"] {
+            assert!(Base64VecU8::decode_into(invalid, &mut buf).is_err());
+            assert!(buf.is_empty());
+            assert!(serde_json::from_str::<Base64VecU8>(&format!("\"{}\"", invalid)).is_err());
+        }
+        assert!(serde_json::from_str::<Base64VecU8>("[1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_escaped() {
+        // Escaped strings can't be borrowed from the input and take the owned path.
+        let a_deser: Base64VecU8 = serde_json::from_str("\"ZHkf\\/AAXIA==\"").unwrap();
+        assert_eq!(
+            a_deser.0,
+            base64::engine::general_purpose::STANDARD.decode("ZHkf/AAXIA==").unwrap()
+        );
+    }
+
     #[test]
     fn test_manual() {
         let a = vec![100, 121, 31, 20, 0, 23, 32];
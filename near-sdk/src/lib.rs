@@ -65,6 +65,22 @@
 //! cargo near build
 //! ```
 //!
+//! ### Choosing an allocator
+//!
+//! By default contracts use [`wee_alloc`](https://docs.rs/wee_alloc) as the global allocator
+//! when compiled to Wasm. The allocator can be selected through features of `near-sdk`:
+//!
+//! - `wee_alloc` (default): use `wee_alloc`, optimized for code size.
+//! - `bump_alloc`: use `near_sdk::BumpAllocator`, which never frees memory and
+//!   gives deterministic allocations. Takes precedence over `wee_alloc` if both are enabled.
+//! - No allocator feature (`default-features = false`): use the Rust system allocator, or set
+//!   a custom `#[global_allocator]` in the contract.
+//!
+//! ```toml
+//! [dependencies]
+//! near-sdk = { version = "5.7.1", features = ["bump_alloc"] }
+//! ```
+//!
 //! ### Running Unit Tests
 //!
 //! Use the following testing setup:
@@ -87,6 +103,19 @@
 //! ```bash
 //! cargo test
 //! ```
+//!
+//! ### Capturing logs with `tracing`
+//!
+//! With the `tracing` feature, messages logged through [`env::log_str`] and the macros built on
+//! it, like [`log!`] and events, are also emitted as `INFO` events with the `near_sdk::log`
+//! target of the [`tracing`](https://docs.rs/tracing) crate. This lets test harnesses capture
+//! contract logs with their own subscriber. It only applies to non-Wasm targets, contracts
+//! compiled to Wasm are unaffected.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! near-sdk = { version = "5.7.1", features = ["unit-testing", "tracing"] }
+//! ```
 
 //* Clippy is giving false positive warnings for this in 1.57 version. Remove this if fixed.
 //* https://github.com/rust-lang/rust-clippy/issues/8091
@@ -115,7 +144,7 @@ pub use environment::env;
 pub use near_sys as sys;
 
 mod promise;
-pub use promise::{Allowance, Promise, PromiseOrValue};
+pub use promise::{Allowance, Promise, PromiseBatch, PromiseOrValue};
 
 // Private types just used within macro generation, not stable to be used.
 #[doc(hidden)]
@@ -148,10 +177,16 @@ pub mod near;
 pub mod test_utils;
 
 // Set up global allocator by default if custom-allocator feature is not set in wasm32 architecture.
-#[cfg(all(feature = "wee_alloc", target_arch = "wasm32"))]
+// The `bump_alloc` feature takes precedence over the default `wee_alloc` so that only one global
+// allocator is ever set. With both features disabled the system allocator is used.
+#[cfg(all(feature = "wee_alloc", not(feature = "bump_alloc"), target_arch = "wasm32"))]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+#[cfg(all(feature = "bump_alloc", target_arch = "wasm32"))]
+#[global_allocator]
+static ALLOC: crate::utils::BumpAllocator = crate::utils::BumpAllocator::new();
+
 // Exporting common crates
 
 pub use base64;
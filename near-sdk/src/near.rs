@@ -56,6 +56,30 @@ pub fn init() {}
 ///     }
 /// }
 /// ```
+///
+/// ## Minimum deposit
+///
+/// `#[payable(min = "...")]` makes the method panic if the attached deposit is below the given
+/// [`NearToken`](crate::NearToken) expression. A plain `#[payable]` accepts any deposit,
+/// including zero.
+///
+/// ```rust
+/// use near_sdk::{near, NearToken};
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Counter {
+///     val: i8,
+/// }
+///
+/// #[near]
+/// impl Counter {
+///     #[payable(min = "NearToken::from_yoctonear(1)")]
+///     pub fn increment(&mut self) {
+///         self.val += 1;
+///     }
+/// }
+/// ```
 pub fn payable() {}
 
 /// Private Methods inner [`#[near]`](../attr.near.html) annotation. More details can be found [here](https://docs.near.org/sdk/rust/contract-structure/near-bindgen#private-methods)
@@ -86,9 +110,212 @@ pub fn payable() {}
 /// ```
 pub fn private() {}
 
+/// Explicit view methods inner [`#[near]`](../attr.near.html) annotation.
+///
+/// Whether a method is a view or a call is normally inferred from its receiver: methods taking
+/// `&mut self` write the contract state back after execution, all other methods don't.
+/// Annotating a method with `#[near(view)]` makes the read-only intent explicit and fails to
+/// compile if the method takes `&mut self`.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::near;
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Counter {
+///     val: u64,
+/// }
+///
+/// #[near]
+/// impl Counter {
+///     #[near(view)]
+///     pub fn get(&self) -> u64 {
+///         self.val
+///     }
+/// }
+/// ```
+pub fn view() {}
+
+/// Deprecation notice inner [`#[near]`](../attr.near.html) annotation.
+///
+/// `#[near(deprecated_log = "...")]` logs `DEPRECATED: <message>` at the start of every call to
+/// the method, so that integrators calling it can notice the deprecation in the transaction
+/// outcome. Like with [`deprecated_method`], the notice is logged by the exported function, so
+/// calls to the method from the contract's own code don't log it. The message is also appended
+/// to the method's documentation in the ABI.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::near;
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Counter {
+///     val: u64,
+/// }
+///
+/// #[near]
+/// impl Counter {
+///     #[near(deprecated_log = "use `increment_by` instead")]
+///     pub fn increment(&mut self) {
+///         self.increment_by(1);
+///     }
+///
+///     pub fn increment_by(&mut self, amount: u64) {
+///         self.val += amount;
+///     }
+/// }
+/// ```
+pub fn deprecated_log() {}
+
+/// Deprecated method inner [`#[near]`](../attr.near.html) annotation.
+///
+/// `#[deprecated_method(use = "new_name")]` keeps the method exported but logs
+/// ``DEPRECATED: call `new_name` instead`` at the start of every invocation, pointing
+/// integrators to its replacement. The notice is also appended to the method's documentation
+/// in the ABI. It can't be combined with `#[near(deprecated_log = "...")]`.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::near;
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Counter {
+///     val: u64,
+/// }
+///
+/// #[near]
+/// impl Counter {
+///     #[deprecated_method(use = "increment_by")]
+///     pub fn increment(&mut self) {
+///         self.increment_by(1);
+///     }
+///
+///     pub fn increment_by(&mut self, amount: u64) {
+///         self.val += amount;
+///     }
+/// }
+/// ```
+pub fn deprecated_method() {}
+
+/// Strict arguments deserialization inner [`#[near]`](../attr.near.html) annotation.
+///
+/// By default, fields of the JSON input that don't match any argument of the method are ignored.
+/// With `#[near(deny_unknown_arguments)]` on a method they make the call fail instead. Used on
+/// an impl block, it applies to all methods of the block, which can opt out with
+/// `#[near(deny_unknown_arguments = false)]`.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::near;
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Counter {
+///     val: u64,
+/// }
+///
+/// #[near(deny_unknown_arguments)]
+/// impl Counter {
+///     pub fn increment_by(&mut self, amount: u64) {
+///         self.val += amount;
+///     }
+///
+///     #[near(deny_unknown_arguments = false)]
+///     pub fn set(&mut self, val: u64) {
+///         self.val = val;
+///     }
+/// }
+/// ```
+pub fn deny_unknown_arguments() {}
+
+/// Input size limit inner [`#[near]`](../attr.near.html) annotation.
+///
+/// `#[max_input_bytes(N)]` makes the method panic if its input is longer than `N` bytes. The
+/// length is checked before the input is read, so oversized payloads are rejected without
+/// paying for their allocation and deserialization.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::near;
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Contract {
+///     names: Vec<String>,
+/// }
+///
+/// #[near]
+/// impl Contract {
+///     #[max_input_bytes(256)]
+///     pub fn add_name(&mut self, name: String) {
+///         self.names.push(name);
+///     }
+/// }
+/// ```
+pub fn max_input_bytes() {}
+
+/// State migration inner [`#[near]`](../attr.near.html) annotation.
+///
+/// `#[near(migrate(from = OldState))]` turns a method into an initializer that runs over the
+/// existing state after a contract upgrade. The stored state is read as `OldState` and passed as
+/// the first argument of the method; the remaining arguments come from the input as usual, and
+/// the returned value is written as the new state.
+///
+/// The generated method can only be called by the contract account itself, and it panics if
+/// there is no state to migrate from, instead of falling back to a default.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::near;
+///
+/// #[near]
+/// pub struct OldCounter {
+///     val: u32,
+/// }
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Counter {
+///     val: u64,
+///     step: u64,
+/// }
+///
+/// #[near]
+/// impl Counter {
+///     #[near(migrate(from = OldCounter))]
+///     pub fn migrate(old: OldCounter, step: u64) -> Self {
+///         Self { val: old.val.into(), step }
+///     }
+/// }
+/// ```
+pub fn migrate() {}
+
 /// Result serialization inner [`#[near]`](../attr.near.html) annotation.
 ///
-/// Only one of `borsh` or `json` can be specified.
+/// Only one of `borsh`, `json` or `json_pretty` can be specified. `json_pretty` returns indented
+/// JSON, which is decoded the same way by callers but easier to read in the output of view calls
+/// when debugging, at the cost of a larger return value.
 ///
 /// # Examples
 ///
@@ -109,6 +336,11 @@ pub fn private() {}
 ///     pub fn add_borsh(&self, #[serializer(borsh)] _a: Vec<String>) {
 ///         // ..
 ///     }
+///
+///     #[result_serializer(json_pretty)]
+///     pub fn get_val(&self) -> u64 {
+///         self.val
+///     }
 /// }
 /// ```
 pub fn result_serializer() {}
@@ -144,4 +376,65 @@ pub fn result_serializer() {}
 ///     }
 /// }
 /// ```
+///
+/// ## Returning a promise
+///
+/// If the `Ok` type is a [`Promise`](crate::Promise), `Ok(promise)` is returned with
+/// [`Promise::as_return`](crate::Promise::as_return), so the result of the promise becomes the
+/// result of the method. `Err(e)` panics with `e` as usual, and no promise is scheduled.
+///
+/// ```rust
+/// use near_sdk::{near, AccountId, NearToken, Promise, PromiseError};
+///
+/// #[near(contract_state)]
+/// #[derive(Default)]
+/// pub struct Contract {}
+///
+/// #[near]
+/// impl Contract {
+///     #[handle_result]
+///     pub fn forward(
+///         &self,
+///         account_id: AccountId,
+///         #[callback_result] previous: Result<(), PromiseError>,
+///     ) -> Result<Promise, &'static str> {
+///         previous.map_err(|_| "Previous call failed")?;
+///         Ok(Promise::new(account_id).transfer(NearToken::from_near(1)))
+///     }
+/// }
+/// ```
 pub fn handle_result() {}
+
+/// Generic contract state inner [`#[near]`](../attr.near.html) annotation.
+///
+/// The exported functions of a contract can't be generic, so a contract state with type
+/// parameters needs the type arguments its methods are exported for. Pass the concrete type with
+/// `#[near(monomorphize = "...")]` to the contract state and to every impl block of it. The
+/// type parameters in the arguments and results of the methods are replaced by the matching type
+/// arguments, while the impl blocks themselves stay generic.
+///
+/// Only one instantiation can be exported per contract, since the method names must be unique.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+/// use near_sdk::near;
+/// use near_sdk::serde::Serialize;
+///
+/// #[near(contract_state, monomorphize = "Registry<u64>")]
+/// #[derive(Default)]
+/// pub struct Registry<T: BorshSerialize + BorshDeserialize> {
+///     items: Vec<T>,
+/// }
+///
+/// #[near(monomorphize = "Registry<u64>")]
+/// impl<T: BorshSerialize + BorshDeserialize + Serialize + Clone> Registry<T> {
+///     pub fn get(&self, index: u32) -> Option<T> {
+///         self.items.get(index as usize).cloned()
+///     }
+/// }
+/// ```
+pub fn monomorphize() {}
@@ -0,0 +1,85 @@
+use near_abi::__private::ChunkedAbiEntry;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// ABI chunk exported for a `#[near(event_json(...))]` enum: an entry without functions, with the
+/// schema of the enum in its definitions and an `events` section listing every event.
+#[derive(Serialize)]
+struct EventAbiEntry {
+    #[serde(flatten)]
+    entry: ChunkedAbiEntry,
+    events: Vec<AbiEvent>,
+}
+
+/// A single event of the `events` section, i.e. one variant of the enum.
+#[derive(Serialize)]
+struct AbiEvent {
+    standard: String,
+    version: String,
+    event: String,
+    /// Schema of the `data` field of the log, absent for events without data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Schema>,
+}
+
+/// Serializes the ABI chunk of the `#[near(event_json(...))]` enum `T`.
+///
+/// `versions` holds the version of every variant of `T`, in declaration order.
+pub fn event_abi_chunk<T: JsonSchema>(standard: &str, versions: &[&str]) -> Vec<u8> {
+    let mut gen = SchemaGenerator::default();
+    let events = add_event_schema::<T>(&mut gen, standard, versions);
+    serde_json::to_vec(&EventAbiEntry {
+        entry: ChunkedAbiEntry::new(vec![], gen.into_root_schema_for::<String>()),
+        events,
+    })
+    .unwrap()
+}
+
+/// Adds the schema of `T` to the definitions of `gen`, with each variant extended into its full
+/// NEP-297 log: the `standard` and the variant's `version` next to the `event` and `data` fields
+/// serialized by the enum. Returns the events described by the variants.
+fn add_event_schema<T: JsonSchema>(
+    gen: &mut SchemaGenerator,
+    standard: &str,
+    versions: &[&str],
+) -> Vec<AbiEvent> {
+    gen.subschema_for::<T>();
+    let Some(Schema::Object(schema)) = gen.definitions_mut().get_mut(&T::schema_name()) else {
+        return vec![];
+    };
+    let Some(variants) = schema.subschemas.as_mut().and_then(|s| s.one_of.as_mut()) else {
+        return vec![];
+    };
+    let mut events = Vec::with_capacity(variants.len());
+    for (variant, version) in variants.iter_mut().zip(versions) {
+        let Schema::Object(variant) = variant else { continue };
+        let object = variant.object();
+        object.properties.insert("standard".to_string(), const_string(standard));
+        object.properties.insert("version".to_string(), const_string(version));
+        object.required.insert("standard".to_string());
+        object.required.insert("version".to_string());
+
+        let event = match object.properties.get("event") {
+            Some(Schema::Object(event)) => event.enum_values.as_ref().and_then(|v| v.first()),
+            _ => None,
+        };
+        let Some(event) = event.and_then(|e| e.as_str()) else { continue };
+        events.push(AbiEvent {
+            standard: standard.to_string(),
+            version: version.to_string(),
+            event: event.to_string(),
+            data: object.properties.get("data").cloned(),
+        });
+    }
+    events
+}
+
+fn const_string(value: &str) -> Schema {
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        const_value: Some(value.into()),
+        ..Default::default()
+    })
+}
@@ -6,8 +6,12 @@ pub use near_abi::{
     AbiParameters, AbiType,
 };
 #[cfg(feature = "abi")]
+mod event_schema;
+#[cfg(feature = "abi")]
 mod result_type_ext;
 
+#[cfg(feature = "abi")]
+pub use event_schema::event_abi_chunk;
 #[cfg(feature = "abi")]
 pub use result_type_ext::ResultTypeExt;
 
@@ -33,13 +37,31 @@ use borsh::{to_vec, BorshSerialize};
 /// let lookup_map_1: LookupMap<u64, String> = LookupMap::new(StorageKey::Metadata { sub_key: String::from("yo") });
 /// let lookup_map_2: LookupMap<String, String> = LookupMap::new(StorageKey::FungibleToken);
 /// ```
-pub trait BorshIntoStorageKey: BorshSerialize {}
+pub trait BorshIntoStorageKey: BorshSerialize {
+    /// Returns the storage key, which is the Borsh serialization of `self` unless the variant
+    /// was given an explicit `#[storage_key(prefix = ...)]`.
+    fn borsh_storage_key(&self) -> Vec<u8> {
+        to_vec(self).unwrap()
+    }
+}
 
 impl<T> IntoStorageKey for T
 where
     T: BorshIntoStorageKey,
 {
     fn into_storage_key(self) -> Vec<u8> {
-        to_vec(&self).unwrap()
+        self.borsh_storage_key()
     }
 }
+
+/// Bound checked on the values returned by `#[result_serializer(borsh)]` methods, to point at the
+/// return type instead of the generated serialization code when it is not Borsh serializable.
+#[diagnostic::on_unimplemented(
+    message = "the return type `{Self}` does not implement `BorshSerialize`",
+    label = "the return type of a `#[result_serializer(borsh)]` method must implement `BorshSerialize`"
+)]
+pub trait BorshResult {}
+
+impl<T: BorshSerialize + ?Sized> BorshResult for T {}
+
+pub fn assert_borsh_result<T: BorshResult + ?Sized>(_: &T) {}
@@ -12,7 +12,7 @@ use crate::{AccountId, Gas, GasWeight, NearToken, PromiseIndex, PublicKey};
 
 /// Allow an access key to spend either an unlimited or limited amount of gas
 // This wrapper prevents incorrect construction
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Allowance {
     Unlimited,
     Limited(NonZeroU128),
@@ -27,6 +27,60 @@ impl Allowance {
     pub fn limited(balance: NearToken) -> Option<Allowance> {
         NonZeroU128::new(balance.as_yoctonear()).map(Allowance::Limited)
     }
+
+    /// Increases a limited allowance by `by`. An unlimited allowance stays unlimited.
+    ///
+    /// Returns `None` if the limited allowance would overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_sdk::{Allowance, NearToken};
+    ///
+    /// let allowance = Allowance::limited(NearToken::from_near(1)).unwrap();
+    /// assert_eq!(
+    ///     allowance.checked_increase(NearToken::from_near(2)),
+    ///     Allowance::limited(NearToken::from_near(3))
+    /// );
+    /// assert_eq!(
+    ///     Allowance::unlimited().checked_increase(NearToken::from_near(2)),
+    ///     Some(Allowance::unlimited())
+    /// );
+    /// ```
+    pub fn checked_increase(self, by: NearToken) -> Option<Allowance> {
+        match self {
+            Allowance::Unlimited => Some(Allowance::Unlimited),
+            Allowance::Limited(balance) => {
+                balance.checked_add(by.as_yoctonear()).map(Allowance::Limited)
+            }
+        }
+    }
+
+    /// Decreases a limited allowance by `by`.
+    ///
+    /// Returns `None` if the allowance is unlimited, since it can't be decreased, or if the
+    /// limited allowance would drop to zero or below.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_sdk::{Allowance, NearToken};
+    ///
+    /// let allowance = Allowance::limited(NearToken::from_near(3)).unwrap();
+    /// assert_eq!(
+    ///     allowance.checked_decrease(NearToken::from_near(2)),
+    ///     Allowance::limited(NearToken::from_near(1))
+    /// );
+    /// assert_eq!(allowance.checked_decrease(NearToken::from_near(3)), None);
+    /// assert_eq!(Allowance::unlimited().checked_decrease(NearToken::from_near(2)), None);
+    /// ```
+    pub fn checked_decrease(self, by: NearToken) -> Option<Allowance> {
+        match self {
+            Allowance::Unlimited => None,
+            Allowance::Limited(balance) => balance
+                .get()
+                .checked_sub(by.as_yoctonear())
+                .and_then(|balance| Allowance::limited(NearToken::from_yoctonear(balance))),
+        }
+    }
 }
 
 enum PromiseAction {
@@ -133,6 +187,34 @@ impl PromiseAction {
     }
 }
 
+impl PromiseAction {
+    /// Weight of unused gas assigned to this action, `None` if it is not a function call.
+    fn gas_weight(&self) -> Option<u64> {
+        match self {
+            PromiseAction::FunctionCall { .. } => Some(0),
+            PromiseAction::FunctionCallWeight { weight, .. } => Some(weight.0),
+            _ => None,
+        }
+    }
+
+    /// Sets the weight of unused gas of a function call action. Other actions are left untouched.
+    fn set_gas_weight(&mut self, gas_weight: u64) {
+        match self {
+            PromiseAction::FunctionCall { function_name, arguments, amount, gas } => {
+                *self = PromiseAction::FunctionCallWeight {
+                    function_name: std::mem::take(function_name),
+                    arguments: std::mem::take(arguments),
+                    amount: *amount,
+                    gas: *gas,
+                    weight: GasWeight(gas_weight),
+                };
+            }
+            PromiseAction::FunctionCallWeight { weight, .. } => *weight = GasWeight(gas_weight),
+            _ => {}
+        }
+    }
+}
+
 struct PromiseSingle {
     pub account_id: AccountId,
     pub actions: RefCell<Vec<PromiseAction>>,
@@ -147,8 +229,9 @@ impl PromiseSingle {
         if let Some(res) = promise_lock.as_ref() {
             return *res;
         }
-        let promise_index = if let Some(after) = self.after.borrow().as_ref() {
-            crate::env::promise_batch_then(after.construct_recursively(), &self.account_id)
+        let after = self.after.borrow().as_ref().and_then(Promise::construct_recursively);
+        let promise_index = if let Some(after) = after {
+            crate::env::promise_batch_then(after, &self.account_id)
         } else {
             crate::env::promise_batch_create(&self.account_id)
         };
@@ -162,24 +245,28 @@ impl PromiseSingle {
 }
 
 pub struct PromiseJoint {
-    pub promise_a: Promise,
-    pub promise_b: Promise,
+    pub promises: Vec<Promise>,
     /// Promise index that is computed only once.
     pub promise_index: RefCell<Option<PromiseIndex>>,
 }
 
 impl PromiseJoint {
-    pub fn construct_recursively(&self) -> PromiseIndex {
+    /// Returns `None` if all promises are [`Promise::noop`]. If only one of them isn't, it is used
+    /// on its own.
+    pub fn construct_recursively(&self) -> Option<PromiseIndex> {
         let mut promise_lock = self.promise_index.borrow_mut();
         if let Some(res) = promise_lock.as_ref() {
-            return *res;
+            return Some(*res);
         }
-        let res = crate::env::promise_and(&[
-            self.promise_a.construct_recursively(),
-            self.promise_b.construct_recursively(),
-        ]);
+        let indices: Vec<_> =
+            self.promises.iter().filter_map(Promise::construct_recursively).collect();
+        let res = match indices.as_slice() {
+            [] => return None,
+            [res] => *res,
+            indices => crate::env::promise_and(indices),
+        };
         *promise_lock = Some(res);
-        res
+        Some(res)
     }
 }
 
@@ -247,6 +334,7 @@ impl BorshSchema for Promise {
 enum PromiseSubtype {
     Single(Rc<PromiseSingle>),
     Joint(Rc<PromiseJoint>),
+    Noop,
 }
 
 impl Promise {
@@ -264,12 +352,40 @@ impl Promise {
         }
     }
 
+    /// Create a promise that schedules nothing.
+    ///
+    /// This is useful in branches that have nothing to schedule but must produce a [`Promise`]:
+    /// * Returned from a method, or from a method returning [`PromiseOrValue`], the method
+    ///   returns `()`, i.e. JSON `null` or no Borsh bytes, since there is no promise to take the
+    ///   result from.
+    /// * Chained with [`then`](Self::then), the other promise is scheduled as if the no-op was not
+    ///   there: `Promise::noop().then(p)` schedules `p` right away and `p.then(Promise::noop())`
+    ///   is `p`.
+    /// * Joined with [`and`](Self::and), only the other promise is awaited.
+    ///
+    /// Actions can't be added to a no-op promise.
+    ///
+    /// ```no_run
+    /// # use near_sdk::{AccountId, NearToken, Promise};
+    /// # let refund = NearToken::from_near(0);
+    /// # let predecessor: AccountId = "bob_near".parse().unwrap();
+    /// let promise = if refund.is_zero() {
+    ///     Promise::noop()
+    /// } else {
+    ///     Promise::new(predecessor).transfer(refund)
+    /// };
+    /// ```
+    pub fn noop() -> Self {
+        Self { subtype: PromiseSubtype::Noop, should_return: RefCell::new(false) }
+    }
+
     fn add_action(self, action: PromiseAction) -> Self {
         match &self.subtype {
             PromiseSubtype::Single(x) => x.actions.borrow_mut().push(action),
             PromiseSubtype::Joint(_) => {
                 crate::env::panic_str("Cannot add action to a joint promise.")
             }
+            PromiseSubtype::Noop => crate::env::panic_str("Cannot add action to a noop promise."),
         }
         self
     }
@@ -286,6 +402,42 @@ impl Promise {
         self.add_action(PromiseAction::DeployContract { code })
     }
 
+    /// Deploy a smart contract to the account on which this promise acts and call its
+    /// initialization method within the same receipt, e.g. in factory contracts.
+    ///
+    /// This should be used on a promise that creates the account with
+    /// [`Promise::create_account`], as initialization methods fail on already initialized state.
+    /// Since the actions of a promise are executed atomically, the account is not left with an
+    /// uninitialized contract if the initialization fails.
+    ///
+    /// ```no_run
+    /// # use near_sdk::{env, Gas, NearToken, Promise};
+    /// # let code = vec![];
+    /// Promise::new("sub.factory.near".parse().unwrap())
+    ///     .create_account()
+    ///     .transfer(NearToken::from_near(5))
+    ///     .add_full_access_key(env::signer_account_pk())
+    ///     .deploy_contract_and_init(
+    ///         code,
+    ///         "new".to_string(),
+    ///         br#"{"owner_id":"alice.near"}"#.to_vec(),
+    ///         NearToken::from_near(0),
+    ///         Gas::from_tgas(20),
+    ///     );
+    /// ```
+    /// Uses low-level [`crate::env::promise_batch_action_deploy_contract`] and
+    /// [`crate::env::promise_batch_action_function_call`]
+    pub fn deploy_contract_and_init(
+        self,
+        code: Vec<u8>,
+        init_method: String,
+        init_args: Vec<u8>,
+        init_deposit: NearToken,
+        init_gas: Gas,
+    ) -> Self {
+        self.deploy_contract(code).function_call(init_method, init_args, init_deposit, init_gas)
+    }
+
     /// A low-level interface for making a function call to the account that this promise acts on.
     /// Uses low-level [`crate::env::promise_batch_action_function_call`]
     pub fn function_call(
@@ -319,6 +471,80 @@ impl Promise {
         })
     }
 
+    /// Makes the last function call of this promise receive all of the gas left unused at the end
+    /// of the current method execution.
+    ///
+    /// The call is given an unused gas weight of `1` and every other function call of this
+    /// promise, including the promises it was chained after with [`Promise::then`] or joined with
+    /// [`Promise::and`], gets a weight of `0`. This is typically used for the final continuation of
+    /// a fan-out, which would otherwise only get its share of the unused gas.
+    ///
+    /// Promises that are not part of this promise are not affected, see
+    /// [`Promise::has_conflicting_gas_weights`] to check the weights of a promise.
+    ///
+    /// ```no_run
+    /// # use near_sdk::{Gas, GasWeight, NearToken, Promise};
+    /// let call = |method: &str| {
+    ///     Promise::new("bob_near".parse().unwrap()).function_call_weight(
+    ///         method.to_string(),
+    ///         vec![],
+    ///         NearToken::from_near(0),
+    ///         Gas::from_tgas(5),
+    ///         GasWeight::default(),
+    ///     )
+    /// };
+    /// call("a").and(call("b")).then(call("resolve")).with_all_remaining_gas();
+    /// ```
+    pub fn with_all_remaining_gas(self) -> Self {
+        let single = match &self.subtype {
+            PromiseSubtype::Single(x) => x,
+            PromiseSubtype::Joint(_) => {
+                crate::env::panic_str("Cannot set gas weight on a joint promise.")
+            }
+            PromiseSubtype::Noop => {
+                crate::env::panic_str("Cannot set gas weight on a noop promise.")
+            }
+        };
+        let last_call = single.actions.borrow().iter().rposition(|a| a.gas_weight().is_some());
+        let last_call = last_call.unwrap_or_else(|| {
+            crate::env::panic_str("Promise has no function call to receive the remaining gas.")
+        });
+        self.for_each_action(&mut |action| action.set_gas_weight(0));
+        single.actions.borrow_mut()[last_call].set_gas_weight(1);
+        self
+    }
+
+    /// Returns `true` if more than one function call of this promise, including the promises it
+    /// was chained after or joined with, has a non-zero unused gas weight.
+    ///
+    /// In that case the unused gas is split between those calls and none of them receives all of
+    /// it, e.g. when a call marked with [`Promise::with_all_remaining_gas`] is later combined with
+    /// calls using the default weight.
+    pub fn has_conflicting_gas_weights(&self) -> bool {
+        let mut weighted_calls = 0;
+        self.for_each_action(&mut |action| {
+            if matches!(action.gas_weight(), Some(weight) if weight > 0) {
+                weighted_calls += 1;
+            }
+        });
+        weighted_calls > 1
+    }
+
+    fn for_each_action(&self, f: &mut dyn FnMut(&mut PromiseAction)) {
+        match &self.subtype {
+            PromiseSubtype::Single(x) => {
+                if let Some(after) = x.after.borrow().as_ref() {
+                    after.for_each_action(f);
+                }
+                x.actions.borrow_mut().iter_mut().for_each(f);
+            }
+            PromiseSubtype::Joint(x) => {
+                x.promises.iter().for_each(|promise| promise.for_each_action(f));
+            }
+            PromiseSubtype::Noop => {}
+        }
+    }
+
     /// Transfer tokens to the account that this promise acts on.
     /// Uses low-level [`crate::env::promise_batch_action_transfer`]
     pub fn transfer(self, amount: NearToken) -> Self {
@@ -425,6 +651,19 @@ impl Promise {
         self.add_action(PromiseAction::DeleteAccount { beneficiary_id })
     }
 
+    /// Start a [`PromiseBatch`] of actions on the account that this promise acts on. The batch is
+    /// validated and added to this promise with [`PromiseBatch::build`].
+    pub fn batch(self) -> PromiseBatch {
+        match &self.subtype {
+            PromiseSubtype::Single(_) => {}
+            PromiseSubtype::Joint(_) => {
+                crate::env::panic_str("Cannot add action to a joint promise.")
+            }
+            PromiseSubtype::Noop => crate::env::panic_str("Cannot add action to a noop promise."),
+        }
+        PromiseBatch { promise: self, actions: vec![] }
+    }
+
     /// Merge this promise with another promise, so that we can schedule execution of another
     /// smart contract right after all merged promises finish.
     ///
@@ -440,16 +679,51 @@ impl Promise {
     /// ```
     /// Uses low-level [`crate::env::promise_and`]
     pub fn and(self, other: Promise) -> Promise {
+        Self::joint(vec![self, other])
+    }
+
+    fn joint(promises: Vec<Promise>) -> Promise {
         Promise {
             subtype: PromiseSubtype::Joint(Rc::new(PromiseJoint {
-                promise_a: self,
-                promise_b: other,
+                promises,
                 promise_index: RefCell::new(None),
             })),
             should_return: RefCell::new(false),
         }
     }
 
+    /// Merges an arbitrary number of promises, so that a single callback can be scheduled after
+    /// all of them finish. This has the same effect as chaining [`Promise::and`] over `promises`,
+    /// but joins them all with a single [`crate::env::promise_and`] call.
+    ///
+    /// The callback receives the results in the order the promises are passed in, e.g. through
+    /// `#[callback_vec]` or [`crate::env::promise_result`]. If `promises` is empty,
+    /// [`Promise::noop`] is returned, so a callback attached to it is scheduled right away and
+    /// sees no promise results.
+    ///
+    /// ```no_run
+    /// # use near_sdk::{AccountId, Gas, NearToken, Promise};
+    /// # let receivers: Vec<AccountId> = vec![];
+    /// let transfers = receivers
+    ///     .into_iter()
+    ///     .map(|receiver| Promise::new(receiver).transfer(NearToken::from_near(1)));
+    /// Promise::join(transfers).then(Promise::new("resolver_near".parse().unwrap()).function_call(
+    ///     "on_transfers".to_string(),
+    ///     vec![],
+    ///     NearToken::from_near(0),
+    ///     Gas::from_tgas(5),
+    /// ));
+    /// ```
+    /// Uses low-level [`crate::env::promise_and`]
+    pub fn join(promises: impl IntoIterator<Item = Promise>) -> Promise {
+        let mut promises: Vec<_> = promises.into_iter().collect();
+        match promises.len() {
+            0 => Promise::noop(),
+            1 => promises.pop().unwrap(),
+            _ => Self::joint(promises),
+        }
+    }
+
     /// Schedules execution of another promise right after the current promise finish executing.
     ///
     /// In the following code `bob_near` and `dave_near` will be created concurrently. `carol_near`
@@ -476,10 +750,47 @@ impl Promise {
                 *after = Some(self)
             }
             PromiseSubtype::Joint(_) => crate::env::panic_str("Cannot callback joint promise."),
+            PromiseSubtype::Noop => return self,
         }
         other
     }
 
+    /// Same as [`Promise::then`], but sets the unused gas weight of every function call of `other`
+    /// to `weight`.
+    ///
+    /// This makes the callback's share of the gas left unused at the end of the current method
+    /// explicit at the chaining site. A weight of `0` is typically used for resolvers, which then
+    /// only get the static gas they were scheduled with.
+    ///
+    /// ```no_run
+    /// # use near_sdk::{Gas, GasWeight, NearToken, Promise};
+    /// let transfer = Promise::new("token_near".parse().unwrap()).function_call_weight(
+    ///     "ft_transfer_call".to_string(),
+    ///     vec![],
+    ///     NearToken::from_yoctonear(1),
+    ///     Gas::from_tgas(30),
+    ///     GasWeight::default(),
+    /// );
+    /// let resolve = Promise::new("alice_near".parse().unwrap()).function_call_weight(
+    ///     "resolve_transfer".to_string(),
+    ///     vec![],
+    ///     NearToken::from_near(0),
+    ///     Gas::from_tgas(5),
+    ///     GasWeight::default(),
+    /// );
+    /// transfer.then_weighted(resolve, GasWeight(0));
+    /// ```
+    pub fn then_weighted(self, other: Promise, weight: GasWeight) -> Promise {
+        match &other.subtype {
+            PromiseSubtype::Single(x) => {
+                x.actions.borrow_mut().iter_mut().for_each(|action| action.set_gas_weight(weight.0))
+            }
+            PromiseSubtype::Joint(_) => crate::env::panic_str("Cannot callback joint promise."),
+            PromiseSubtype::Noop => {}
+        }
+        self.then(other)
+    }
+
     /// A specialized, relatively low-level API method. Allows to mark the given promise as the one
     /// that should be considered as a return value.
     ///
@@ -506,22 +817,228 @@ impl Promise {
     ///     }
     /// }
     /// ```
-    /// Makes the promise to use low-level [`crate::env::promise_return`].
+    ///
+    /// # When the return is scheduled
+    ///
+    /// Marking the promise does not schedule anything by itself. [`crate::env::promise_return`]
+    /// is called once the promise is constructed, which happens when the promise (or the chain
+    /// containing it) is dropped. A marked promise can therefore be stored in a local, or wrapped
+    /// in a [`PromiseOrValue`], and still only take effect at the end of the method. Since it is
+    /// the marked promise whose result is returned, call this on the last promise of a
+    /// [`then`](Self::then) chain.
+    ///
+    /// This makes intent explicit in methods that only return a promise on some branches:
+    /// ```
+    /// # use near_sdk::{ext_contract, near, AccountId, Promise, PromiseOrValue};
+    /// # use near_sdk::json_types::U128;
+    /// #[ext_contract(ext_receiver)]
+    /// pub trait Receiver {
+    ///     fn on_transfer(&mut self, amount: near_sdk::json_types::U128) -> near_sdk::json_types::U128;
+    /// }
+    ///
+    /// #[near(contract_state)]
+    /// #[derive(Default)]
+    /// struct Contract {}
+    ///
+    /// #[near]
+    /// impl Contract {
+    ///     pub fn send(&mut self, receiver_id: AccountId, amount: U128, notify: bool) -> PromiseOrValue<U128> {
+    ///         let result = if notify {
+    ///             ext_receiver::ext(receiver_id).on_transfer(amount).as_return().into()
+    ///         } else {
+    ///             PromiseOrValue::Value(U128(0))
+    ///         };
+    ///         // ... more bookkeeping, the promise is only scheduled when `result` is dropped.
+    ///         result
+    ///     }
+    /// }
+    /// ```
     #[allow(clippy::wrong_self_convention)]
     pub fn as_return(self) -> Self {
         *self.should_return.borrow_mut() = true;
         self
     }
 
-    fn construct_recursively(&self) -> PromiseIndex {
+    fn construct_recursively(&self) -> Option<PromiseIndex> {
         let res = match &self.subtype {
-            PromiseSubtype::Single(x) => x.construct_recursively(),
+            PromiseSubtype::Single(x) => Some(x.construct_recursively()),
             PromiseSubtype::Joint(x) => x.construct_recursively(),
-        };
+            PromiseSubtype::Noop => None,
+        }?;
         if *self.should_return.borrow() {
             crate::env::promise_return(res);
         }
-        res
+        Some(res)
+    }
+}
+
+/// A builder of the actions executed in a single receipt, obtained with [`Promise::batch`].
+///
+/// Unlike chaining the actions directly on a [`Promise`], the order of the actions is checked when
+/// the batch is collapsed into a promise with [`PromiseBatch::build`]: `create_account` has to be
+/// the first action and `delete_account` the last one, otherwise the runtime would fail the
+/// whole receipt.
+///
+/// A typical use is a factory contract creating and initializing a sub-account:
+/// ```no_run
+/// # use near_sdk::{env, Gas, NearToken, Promise};
+/// # let code = vec![];
+/// Promise::new("sub.factory.near".parse().unwrap())
+///     .batch()
+///     .create_account()
+///     .transfer(NearToken::from_near(5))
+///     .add_full_access_key(env::signer_account_pk())
+///     .deploy_contract(code)
+///     .function_call(
+///         "new".to_string(),
+///         br#"{"owner_id":"alice.near"}"#.to_vec(),
+///         NearToken::from_near(0),
+///         Gas::from_tgas(20),
+///     )
+///     .build();
+/// ```
+#[must_use = "the actions are only added to the promise by `PromiseBatch::build`"]
+pub struct PromiseBatch {
+    promise: Promise,
+    actions: Vec<PromiseAction>,
+}
+
+impl PromiseBatch {
+    fn add_action(mut self, action: PromiseAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Create account on which this batch acts. Must be the first action of the batch.
+    pub fn create_account(self) -> Self {
+        self.add_action(PromiseAction::CreateAccount)
+    }
+
+    /// Deploy a smart contract to the account on which this batch acts.
+    pub fn deploy_contract(self, code: Vec<u8>) -> Self {
+        self.add_action(PromiseAction::DeployContract { code })
+    }
+
+    /// Call a function of the account on which this batch acts, see [`Promise::function_call`].
+    pub fn function_call(
+        self,
+        function_name: String,
+        arguments: Vec<u8>,
+        amount: NearToken,
+        gas: Gas,
+    ) -> Self {
+        self.add_action(PromiseAction::FunctionCall { function_name, arguments, amount, gas })
+    }
+
+    /// Call a function of the account on which this batch acts with a weight of the unused gas,
+    /// see [`Promise::function_call_weight`].
+    pub fn function_call_weight(
+        self,
+        function_name: String,
+        arguments: Vec<u8>,
+        amount: NearToken,
+        gas: Gas,
+        weight: GasWeight,
+    ) -> Self {
+        self.add_action(PromiseAction::FunctionCallWeight {
+            function_name,
+            arguments,
+            amount,
+            gas,
+            weight,
+        })
+    }
+
+    /// Transfer tokens to the account on which this batch acts.
+    pub fn transfer(self, amount: NearToken) -> Self {
+        self.add_action(PromiseAction::Transfer { amount })
+    }
+
+    /// Stake the account for the given amount of tokens using the given public key.
+    pub fn stake(self, amount: NearToken, public_key: PublicKey) -> Self {
+        self.add_action(PromiseAction::Stake { amount, public_key })
+    }
+
+    /// Add full access key to the account on which this batch acts.
+    pub fn add_full_access_key(self, public_key: PublicKey) -> Self {
+        self.add_full_access_key_with_nonce(public_key, 0)
+    }
+
+    /// Add full access key with a provided nonce to the account on which this batch acts.
+    pub fn add_full_access_key_with_nonce(self, public_key: PublicKey, nonce: u64) -> Self {
+        self.add_action(PromiseAction::AddFullAccessKey { public_key, nonce })
+    }
+
+    /// Add a function call access key, see [`Promise::add_access_key_allowance`].
+    pub fn add_access_key_allowance(
+        self,
+        public_key: PublicKey,
+        allowance: Allowance,
+        receiver_id: AccountId,
+        function_names: String,
+    ) -> Self {
+        self.add_access_key_allowance_with_nonce(
+            public_key,
+            allowance,
+            receiver_id,
+            function_names,
+            0,
+        )
+    }
+
+    /// Add a function call access key with a provided nonce.
+    pub fn add_access_key_allowance_with_nonce(
+        self,
+        public_key: PublicKey,
+        allowance: Allowance,
+        receiver_id: AccountId,
+        function_names: String,
+        nonce: u64,
+    ) -> Self {
+        self.add_action(PromiseAction::AddAccessKey {
+            public_key,
+            allowance,
+            receiver_id,
+            function_names,
+            nonce,
+        })
+    }
+
+    /// Delete access key from the account on which this batch acts.
+    pub fn delete_key(self, public_key: PublicKey) -> Self {
+        self.add_action(PromiseAction::DeleteKey { public_key })
+    }
+
+    /// Delete the account on which this batch acts. Must be the last action of the batch.
+    pub fn delete_account(self, beneficiary_id: AccountId) -> Self {
+        self.add_action(PromiseAction::DeleteAccount { beneficiary_id })
+    }
+
+    /// Validate the order of the actions and add them to the promise the batch was started from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `create_account` is not the first action of the promise or `delete_account` is
+    /// not the last one.
+    pub fn build(self) -> Promise {
+        let PromiseBatch { promise, actions } = self;
+        if let PromiseSubtype::Single(x) = &promise.subtype {
+            let mut promise_actions = x.actions.borrow_mut();
+            promise_actions.extend(actions);
+            let last = promise_actions.len().saturating_sub(1);
+            for (i, action) in promise_actions.iter().enumerate() {
+                match action {
+                    PromiseAction::CreateAccount if i != 0 => crate::env::panic_str(
+                        "`create_account` must be the first action of a batch.",
+                    ),
+                    PromiseAction::DeleteAccount { .. } if i != last => crate::env::panic_str(
+                        "`delete_account` must be the last action of a batch.",
+                    ),
+                    _ => {}
+                }
+            }
+        }
+        promise
     }
 }
 
@@ -566,6 +1083,16 @@ impl schemars::JsonSchema for Promise {
 
 /// When the method can return either a promise or a value, it can be called with `PromiseOrValue::Promise`
 /// or `PromiseOrValue::Value` to specify which one should be returned.
+///
+/// # Serialization
+///
+/// Returned from a `#[near]` method, on its own or as the `Ok` value of a `#[handle_result]`
+/// method, it serializes as follows:
+/// * `PromiseOrValue::Value(value)` serializes as `value` would, in both JSON and Borsh.
+/// * `PromiseOrValue::Promise(promise)` serializes to JSON `null` or to no Borsh bytes, and
+///   marks the promise with [`Promise::as_return`], so the result of the promise becomes the
+///   result of the method.
+///
 /// # Example
 /// ```no_run
 /// # use near_sdk::{ext_contract, near, Gas, PromiseOrValue};
@@ -604,6 +1131,47 @@ where
     }
 }
 
+impl<T> PromiseOrValue<T> {
+    /// Returns the contained value.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if `self` is a [`PromiseOrValue::Promise`].
+    ///
+    /// # Example
+    /// ```
+    /// use near_sdk::PromiseOrValue;
+    ///
+    /// let value = PromiseOrValue::Value(5u32);
+    /// assert_eq!(value.expect_value("expected a value"), 5);
+    /// ```
+    pub fn expect_value(self, msg: &str) -> T {
+        match self {
+            PromiseOrValue::Value(value) => value,
+            PromiseOrValue::Promise(_) => crate::env::panic_str(msg),
+        }
+    }
+
+    /// Returns the contained value or computes it from `f` if `self` is a
+    /// [`PromiseOrValue::Promise`].
+    ///
+    /// Note that the promise is dropped in that case, which still schedules it.
+    ///
+    /// # Example
+    /// ```
+    /// use near_sdk::PromiseOrValue;
+    ///
+    /// let value = PromiseOrValue::Value(5u32);
+    /// assert_eq!(value.unwrap_value_or_else(|| 0), 5);
+    /// ```
+    pub fn unwrap_value_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            PromiseOrValue::Value(value) => value,
+            PromiseOrValue::Promise(_) => f(),
+        }
+    }
+}
+
 impl<T> From<Promise> for PromiseOrValue<T> {
     fn from(promise: Promise) -> Self {
         PromiseOrValue::Promise(promise)
@@ -639,8 +1207,8 @@ mod tests {
     use crate::test_utils::get_created_receipts;
     use crate::test_utils::test_env::{alice, bob};
     use crate::{
-        test_utils::VMContextBuilder, testing_env, AccountId, Allowance, NearToken, Promise,
-        PublicKey,
+        test_utils::VMContextBuilder, testing_env, AccountId, Allowance, Gas, GasWeight, NearToken,
+        Promise, PromiseOrValue, PublicKey,
     };
 
     fn pk() -> PublicKey {
@@ -693,6 +1261,44 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_allowance_checked_increase() {
+        let one = Allowance::limited(NearToken::from_yoctonear(1)).unwrap();
+        assert_eq!(
+            one.checked_increase(NearToken::from_yoctonear(2)),
+            Allowance::limited(NearToken::from_yoctonear(3))
+        );
+        assert_eq!(one.checked_increase(NearToken::from_yoctonear(0)), Some(one));
+        assert_eq!(
+            one.checked_increase(NearToken::from_yoctonear(u128::MAX - 1)),
+            Some(Allowance::limited(NearToken::from_yoctonear(u128::MAX)).unwrap())
+        );
+        assert_eq!(one.checked_increase(NearToken::from_yoctonear(u128::MAX)), None);
+
+        let unlimited = Allowance::unlimited();
+        assert_eq!(unlimited.checked_increase(NearToken::from_yoctonear(0)), Some(unlimited));
+        assert_eq!(
+            unlimited.checked_increase(NearToken::from_yoctonear(u128::MAX)),
+            Some(unlimited)
+        );
+    }
+
+    #[test]
+    fn test_allowance_checked_decrease() {
+        let three = Allowance::limited(NearToken::from_yoctonear(3)).unwrap();
+        assert_eq!(
+            three.checked_decrease(NearToken::from_yoctonear(2)),
+            Allowance::limited(NearToken::from_yoctonear(1))
+        );
+        assert_eq!(three.checked_decrease(NearToken::from_yoctonear(0)), Some(three));
+        assert_eq!(three.checked_decrease(NearToken::from_yoctonear(3)), None);
+        assert_eq!(three.checked_decrease(NearToken::from_yoctonear(4)), None);
+
+        let unlimited = Allowance::unlimited();
+        assert_eq!(unlimited.checked_decrease(NearToken::from_yoctonear(0)), None);
+        assert_eq!(unlimited.checked_decrease(NearToken::from_yoctonear(1)), None);
+    }
+
     #[test]
     fn test_add_full_access_key() {
         testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
@@ -860,4 +1466,367 @@ mod tests {
         });
         assert!(has_action);
     }
+
+    fn call(method: &str) -> Promise {
+        Promise::new(bob()).function_call_weight(
+            method.to_string(),
+            vec![],
+            NearToken::from_near(0),
+            Gas::from_tgas(5),
+            GasWeight::default(),
+        )
+    }
+
+    fn gas_weights() -> Vec<(String, u64)> {
+        get_created_receipts()
+            .into_iter()
+            .flat_map(|receipt| receipt.actions)
+            .filter_map(|action| match action {
+                MockAction::FunctionCallWeight { method_name, gas_weight, .. } => {
+                    Some((String::from_utf8(method_name).unwrap(), gas_weight.0))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_deploy_contract_and_init() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            Promise::new(bob()).create_account().deploy_contract_and_init(
+                vec![1, 2, 3],
+                "new".to_string(),
+                b"{}".to_vec(),
+                NearToken::from_yoctonear(1),
+                Gas::from_tgas(20),
+            );
+        }
+
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        let actions = &receipts[0].actions;
+        assert_eq!(actions.len(), 3);
+        assert!(matches!(&actions[0], MockAction::CreateAccount { .. }));
+        assert!(
+            matches!(&actions[1], MockAction::DeployContract { code, .. } if code == &[1, 2, 3])
+        );
+        assert!(matches!(
+            &actions[2],
+            MockAction::FunctionCallWeight { method_name, args, attached_deposit, prepaid_gas, .. }
+            if method_name == b"new"
+                && args == b"{}"
+                && *attached_deposit == NearToken::from_yoctonear(1)
+                && *prepaid_gas == Gas::from_tgas(20)
+        ));
+    }
+
+    #[test]
+    fn test_batch() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            Promise::new(bob())
+                .batch()
+                .create_account()
+                .transfer(NearToken::from_yoctonear(1))
+                .add_full_access_key(pk())
+                .deploy_contract(vec![1, 2, 3])
+                .function_call(
+                    "new".to_string(),
+                    vec![],
+                    NearToken::from_near(0),
+                    Gas::from_tgas(5),
+                )
+                .build();
+        }
+
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].receiver_id, bob());
+        let actions = &receipts[0].actions;
+        assert_eq!(actions.len(), 5);
+        assert!(matches!(&actions[0], MockAction::CreateAccount { .. }));
+        assert!(matches!(&actions[1], MockAction::Transfer { .. }));
+        assert!(matches!(&actions[2], MockAction::AddKeyWithFullAccess { .. }));
+        assert!(matches!(&actions[3], MockAction::DeployContract { .. }));
+        assert!(matches!(&actions[4], MockAction::FunctionCallWeight { .. }));
+    }
+
+    #[test]
+    fn test_batch_delete_account() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            Promise::new(bob())
+                .transfer(NearToken::from_yoctonear(1))
+                .batch()
+                .delete_key(pk())
+                .delete_account(alice())
+                .build();
+        }
+
+        let actions = get_actions().collect::<Vec<_>>();
+        assert_eq!(actions.len(), 3);
+        assert!(matches!(&actions[2], MockAction::DeleteAccount { .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "`create_account` must be the first action of a batch.")]
+    fn test_batch_create_account_not_first() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        let _ = Promise::new(bob())
+            .batch()
+            .transfer(NearToken::from_yoctonear(1))
+            .create_account()
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "`delete_account` must be the last action of a batch.")]
+    fn test_batch_delete_account_not_last() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        let _ = Promise::new(bob())
+            .batch()
+            .delete_account(alice())
+            .transfer(NearToken::from_yoctonear(1))
+            .build();
+    }
+
+    #[test]
+    fn test_with_all_remaining_gas() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            let promise = call("a").and(call("b")).then(call("resolve"));
+            assert!(promise.has_conflicting_gas_weights());
+            let promise = promise.with_all_remaining_gas();
+            assert!(!promise.has_conflicting_gas_weights());
+        }
+
+        assert_eq!(
+            gas_weights(),
+            vec![("a".to_string(), 0), ("b".to_string(), 0), ("resolve".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_with_all_remaining_gas_last_call_of_batch() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            Promise::new(bob())
+                .function_call("a".to_string(), vec![], NearToken::from_near(0), Gas::from_tgas(5))
+                .transfer(NearToken::from_yoctonear(1))
+                .function_call("b".to_string(), vec![], NearToken::from_near(0), Gas::from_tgas(5))
+                .with_all_remaining_gas();
+        }
+
+        assert_eq!(gas_weights(), vec![("a".to_string(), 0), ("b".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_promise_or_value_unwrap() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        assert_eq!(PromiseOrValue::Value(1u8).expect_value("no value"), 1);
+        assert_eq!(PromiseOrValue::Value(1u8).unwrap_value_or_else(|| 2), 1);
+
+        let promise =
+            PromiseOrValue::<u8>::from(Promise::new(bob()).transfer(NearToken::from_yoctonear(1)));
+        assert_eq!(promise.unwrap_value_or_else(|| 2), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "no value")]
+    fn test_promise_or_value_expect_value_panics() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        let promise =
+            PromiseOrValue::<u8>::from(Promise::new(bob()).transfer(NearToken::from_yoctonear(1)));
+        promise.expect_value("no value");
+    }
+
+    #[test]
+    fn test_then_weighted() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            call("a").then_weighted(
+                Promise::new(bob())
+                    .function_call_weight(
+                        "b".to_string(),
+                        vec![],
+                        NearToken::from_near(0),
+                        Gas::from_tgas(5),
+                        GasWeight(3),
+                    )
+                    .transfer(NearToken::from_yoctonear(1))
+                    .function_call(
+                        "c".to_string(),
+                        vec![],
+                        NearToken::from_near(0),
+                        Gas::from_tgas(5),
+                    ),
+                GasWeight(0),
+            );
+        }
+
+        assert_eq!(
+            gas_weights(),
+            vec![("a".to_string(), 1), ("b".to_string(), 0), ("c".to_string(), 0)]
+        );
+    }
+
+    #[test]
+    fn test_has_conflicting_gas_weights() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        assert!(!call("a").has_conflicting_gas_weights());
+        assert!(call("a").then(call("b")).has_conflicting_gas_weights());
+        assert!(!Promise::new(bob())
+            .function_call("a".to_string(), vec![], NearToken::from_near(0), Gas::from_tgas(5))
+            .then(call("b"))
+            .has_conflicting_gas_weights());
+        assert!(call("a").then(call("b").with_all_remaining_gas()).has_conflicting_gas_weights());
+    }
+
+    #[test]
+    #[should_panic(expected = "Promise has no function call to receive the remaining gas.")]
+    fn test_with_all_remaining_gas_without_function_call() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        Promise::new(alice()).create_account().with_all_remaining_gas();
+    }
+
+    #[test]
+    fn test_noop() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            Promise::noop();
+            Promise::noop().and(Promise::noop()).as_return();
+        }
+        assert!(get_created_receipts().is_empty());
+
+        {
+            Promise::noop()
+                .then(call("a"))
+                .and(Promise::noop())
+                .then(call("b").then(Promise::noop()));
+        }
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 2);
+        assert!(receipts[0].receipt_indices.is_empty());
+        assert_eq!(receipts[1].receipt_indices, vec![0]);
+        assert_eq!(gas_weights(), vec![("a".to_string(), 1), ("b".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_join() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            let transfers = (0..5).map(|i| {
+                Promise::new(format!("receiver{i}.near").parse().unwrap())
+                    .transfer(NearToken::from_yoctonear(1))
+            });
+            Promise::join(transfers).then(call("resolve"));
+        }
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 6);
+        for (i, receipt) in receipts[..5].iter().enumerate() {
+            assert_eq!(receipt.receiver_id, format!("receiver{i}.near"));
+            assert!(receipt.receipt_indices.is_empty());
+        }
+        // The resolver waits for each transfer exactly once, as they are joined by a single
+        // `promise_and`. The mock indexes receipts by their position in the action log, where
+        // every transfer takes up two entries: the receipt and its transfer action.
+        assert_eq!(receipts[5].receipt_indices, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_join_single_and_empty() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        {
+            Promise::join(vec![call("a")]).then(call("resolve"));
+        }
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[1].receipt_indices, vec![0]);
+
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+        {
+            Promise::join(Vec::new()).then(call("resolve"));
+        }
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        assert!(receipts[0].receipt_indices.is_empty());
+    }
+
+    #[test]
+    fn test_noop_return() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        let value: PromiseOrValue<u8> = Promise::noop().into();
+        assert_eq!(serde_json::to_vec(&value).unwrap(), b"null");
+        assert!(borsh::to_vec(&value).unwrap().is_empty());
+        drop(value);
+        assert!(get_created_receipts().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add action to a noop promise.")]
+    fn test_noop_add_action() {
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        Promise::noop().transfer(NearToken::from_yoctonear(1));
+    }
+
+    #[test]
+    fn test_promise_or_value_serialization() {
+        use crate::json_types::U128;
+        use crate::PromiseOrValue;
+
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        let value = PromiseOrValue::<U128>::Value(U128(5));
+        assert_eq!(serde_json::to_vec(&value).unwrap(), br#""5""#);
+        assert_eq!(borsh::to_vec(&value).unwrap(), borsh::to_vec(&U128(5)).unwrap());
+
+        let promise: PromiseOrValue<U128> = Promise::new(bob()).create_account().into();
+        assert_eq!(serde_json::to_vec(&promise).unwrap(), b"null");
+        match &promise {
+            PromiseOrValue::Promise(p) => assert!(*p.should_return.borrow()),
+            PromiseOrValue::Value(_) => unreachable!(),
+        }
+
+        let promise: PromiseOrValue<U128> = Promise::new(bob()).create_account().into();
+        assert!(borsh::to_vec(&promise).unwrap().is_empty());
+        match &promise {
+            PromiseOrValue::Promise(p) => assert!(*p.should_return.borrow()),
+            PromiseOrValue::Value(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_as_return_deferred_until_drop() {
+        use crate::PromiseOrValue;
+
+        testing_env!(VMContextBuilder::new().signer_account_id(alice()).build());
+
+        let result: PromiseOrValue<()> = Promise::new(bob()).create_account().as_return().into();
+        match &result {
+            PromiseOrValue::Promise(p) => assert!(*p.should_return.borrow()),
+            PromiseOrValue::Value(_) => unreachable!(),
+        }
+        assert!(get_created_receipts().is_empty());
+
+        drop(result);
+        assert_eq!(get_created_receipts().len(), 1);
+    }
 }
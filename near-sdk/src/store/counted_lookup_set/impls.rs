@@ -0,0 +1,18 @@
+use super::CountedLookupSet;
+use crate::store::key::ToKey;
+use borsh::BorshSerialize;
+
+impl<T, H> Extend<T> for CountedLookupSet<T, H>
+where
+    T: BorshSerialize,
+    H: ToKey,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(move |elem| {
+            self.insert(elem);
+        });
+    }
+}
@@ -0,0 +1,233 @@
+mod impls;
+
+use crate::store::key::{Identity, ToKey};
+use crate::store::{LookupSet, ERR_INCONSISTENT_STATE};
+use crate::{env, IntoStorageKey};
+use borsh::BorshSerialize;
+use std::borrow::Borrow;
+use std::fmt;
+
+use near_sdk_macros::near;
+
+const ERR_SET_FULL: &str = "CountedLookupSet length exceeds u32::MAX";
+
+/// A non-iterable set that stores its content directly on the storage trie and keeps track of
+/// how many elements it contains.
+///
+/// This is a [`LookupSet`] paired with an element counter. Elements are stored exactly as a
+/// [`LookupSet`] with the same prefix and hasher would store them, while the count is kept in the
+/// collection's own Borsh state, next to the prefix, the same way [`IterableSet`] keeps its
+/// length. Like the metadata of every other collection, the count is only persisted when the
+/// structure holding the set is written back to storage, e.g. as part of the contract state.
+///
+/// Keeping the count makes every [`insert`] and [`remove`] that changes the set slightly more
+/// expensive than the plain [`LookupSet`] equivalent. The count is not updated when the elements
+/// are modified through another collection sharing the same prefix.
+///
+/// # Examples
+/// ```
+/// use near_sdk::store::CountedLookupSet;
+///
+/// // Initializes a set, the generic types can be inferred to `CountedLookupSet<String, Identity>`
+/// // The `b"a"` parameter is a prefix for the storage keys of this data structure.
+/// let mut books = CountedLookupSet::new(b"a");
+/// assert!(books.is_empty());
+///
+/// books.insert("A Dance With Dragons".to_string());
+/// books.insert("The Odyssey".to_string());
+/// books.insert("The Odyssey".to_string());
+/// assert_eq!(books.len(), 2);
+///
+/// books.remove("The Odyssey");
+/// assert!(!books.contains("The Odyssey"));
+/// assert_eq!(books.len(), 1);
+/// ```
+///
+/// [`IterableSet`]: crate::store::IterableSet
+/// [`insert`]: Self::insert
+/// [`remove`]: Self::remove
+#[near(inside_nearsdk)]
+pub struct CountedLookupSet<T, H = Identity>
+where
+    T: BorshSerialize,
+    H: ToKey,
+{
+    // ser/de is independent of `T`,`H` ser/de, `BorshSerialize`/`BorshDeserialize`/`BorshSchema` bounds removed
+    #[cfg_attr(not(feature = "abi"), borsh(bound(serialize = "", deserialize = "")))]
+    #[cfg_attr(
+        feature = "abi",
+        borsh(bound(serialize = "", deserialize = ""), schema(params = ""))
+    )]
+    set: LookupSet<T, H>,
+    len: u32,
+}
+
+impl<T, H> fmt::Debug for CountedLookupSet<T, H>
+where
+    T: BorshSerialize,
+    H: ToKey,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CountedLookupSet").field("set", &self.set).field("len", &self.len).finish()
+    }
+}
+
+impl<T> CountedLookupSet<T, Identity>
+where
+    T: BorshSerialize,
+{
+    /// Initialize new [`CountedLookupSet`] with the prefix provided.
+    ///
+    /// This prefix can be anything that implements [`IntoStorageKey`]. The prefix is used when
+    /// storing and looking up values in storage to ensure no collisions with other collections.
+    #[inline]
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self::with_hasher(prefix)
+    }
+}
+
+impl<T, H> CountedLookupSet<T, H>
+where
+    T: BorshSerialize,
+    H: ToKey,
+{
+    /// Initialize a [`CountedLookupSet`] with a custom hash function.
+    ///
+    /// # Example
+    /// ```
+    /// use near_sdk::store::{CountedLookupSet, key::Sha256};
+    ///
+    /// let set = CountedLookupSet::<String, Sha256>::with_hasher(b"m");
+    /// assert_eq!(set.len(), 0);
+    /// ```
+    pub fn with_hasher<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { set: LookupSet::with_hasher(prefix), len: 0 }
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the set contains the specified value.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// [`BorshSerialize`] on the borrowed form *must* match those for the value type.
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: BorshSerialize,
+    {
+        self.set.contains(value)
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// If the set did not have this value present, true is returned.
+    ///
+    /// If the set did have this value present, false is returned.
+    pub fn insert(&mut self, value: T) -> bool {
+        let inserted = self.set.insert(value);
+        if inserted {
+            self.len = self.len.checked_add(1).unwrap_or_else(|| env::panic_str(ERR_SET_FULL));
+        }
+        inserted
+    }
+
+    /// Removes a value from the set. Returns whether the value was present in the set.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// [`BorshSerialize`] on the borrowed form *must* match those for the value type.
+    pub fn remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: BorshSerialize,
+    {
+        let removed = self.set.remove(value);
+        if removed {
+            self.len =
+                self.len.checked_sub(1).unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE));
+        }
+        removed
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::CountedLookupSet;
+    use crate::store::key::Sha256;
+    use crate::store::LookupSet;
+    use borsh::{to_vec, BorshDeserialize};
+
+    #[test]
+    fn test_counter() {
+        let mut set = CountedLookupSet::new(b"c");
+        assert!(set.is_empty());
+
+        assert!(set.insert(1u8));
+        assert!(set.insert(2));
+        assert_eq!(set.len(), 2);
+
+        // Inserting an existing element doesn't change the count.
+        assert!(!set.insert(1));
+        assert_eq!(set.len(), 2);
+
+        // Neither does removing an absent one.
+        assert!(!set.remove(&3));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(&1));
+        assert_eq!(set.len(), 1);
+        assert!(!set.remove(&1));
+        assert_eq!(set.len(), 1);
+
+        let mut set = CountedLookupSet::<u8, Sha256>::with_hasher(b"h");
+        set.extend([1, 2, 2, 3]);
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_count_persisted_in_state() {
+        let mut set = CountedLookupSet::new(b"c");
+        set.insert(1u8);
+        set.insert(2);
+
+        // The count is part of the serialized set, after the prefix of the inner `LookupSet`.
+        let state = to_vec(&set).unwrap();
+        assert_eq!(
+            state,
+            [to_vec(&LookupSet::<u8>::new(b"c")).unwrap(), to_vec(&2u32).unwrap()].concat()
+        );
+
+        let mut set = CountedLookupSet::<u8>::try_from_slice(&state).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(&2));
+        assert_eq!(set.len(), 1);
+
+        // Elements are stored under the same keys as a plain `LookupSet` would use.
+        assert!(LookupSet::<u8>::new(b"c").contains(&1));
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut set = CountedLookupSet::<u8>::new(b"m");
+        set.insert(1);
+
+        assert_eq!(
+            format!("{:?}", set),
+            "CountedLookupSet { set: LookupSet { prefix: [109] }, len: 1 }"
+        )
+    }
+}
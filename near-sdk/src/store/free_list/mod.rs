@@ -96,6 +96,11 @@ where
         self.len() == 0
     }
 
+    /// Number of slots in the list, including the free ones.
+    pub(crate) fn slots_len(&self) -> u32 {
+        self.elements.len()
+    }
+
     /// Flushes cached changes to storage. This retains any cached values in memory.
     pub fn flush(&mut self) {
         self.elements.flush()
@@ -188,6 +193,15 @@ where
         Iter::new(self)
     }
 
+    /// Generates iterator for shared references to each value in the bucket, starting at the
+    /// slot at `start` of the underlying vector. Slots before `start` are not loaded.
+    pub fn iter_from(&self, start: u32) -> impl Iterator<Item = &T> {
+        self.elements.iter().skip(start as usize).filter_map(|slot| match slot {
+            Slot::Occupied(value) => Some(value),
+            Slot::Empty { .. } => None,
+        })
+    }
+
     /// Generates iterator for exclusive references to each value in the bucket.
     pub fn iter_mut(&mut self) -> IterMut<T> {
         IterMut::new(self)
@@ -204,6 +218,19 @@ where
         Drain::new(self)
     }
 
+    /// Retains only the values for which `f` returns `true`, visiting them in index order.
+    /// Removed values leave empty slots, as with [`remove`](Self::remove).
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        for index in 0..self.elements.len() {
+            if matches!(self.elements.get(index), Some(Slot::Occupied(value)) if !f(value)) {
+                self.remove(FreeListIndex(index));
+            }
+        }
+    }
+
     /// Empty slots in the front of the list is swapped with occupied slots in back of the list.
     /// Defrag helps reduce gas cost in certain scenarios where lot of elements in front of the list are
     /// removed without getting replaced. Please see https://github.com/near/near-sdk-rs/issues/990
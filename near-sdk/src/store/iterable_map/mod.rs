@@ -434,6 +434,52 @@ where
         self.values.get(k).map(|v| &v.value)
     }
 
+    /// Returns a clone of the value corresponding to the key, or [`V::default()`](Default) if
+    /// the key is not in the map.
+    ///
+    /// Unlike `entry(k).or_default()`, this does not insert the default value, so nothing is
+    /// written to storage for an absent key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableMap;
+    ///
+    /// let mut map: IterableMap<String, u64> = IterableMap::new(b"b");
+    /// map.insert("a".to_string(), 5);
+    /// assert_eq!(map.get_or_default("a"), 5);
+    /// assert_eq!(map.get_or_default("b"), 0);
+    /// ```
+    pub fn get_or_default<Q: ?Sized>(&self, k: &Q) -> V
+    where
+        K: Borrow<Q>,
+        Q: BorshSerialize + ToOwned<Owned = K>,
+        V: Default + Clone,
+    {
+        self.get(k).cloned().unwrap_or_default()
+    }
+
+    /// Returns a reference to the value corresponding to the key, or `default` if the key is not
+    /// in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableMap;
+    ///
+    /// let mut map: IterableMap<String, u64> = IterableMap::new(b"b");
+    /// map.insert("a".to_string(), 5);
+    /// assert_eq!(*map.get_or("a", &0), 5);
+    /// assert_eq!(*map.get_or("b", &0), 0);
+    /// ```
+    pub fn get_or<'a, Q: ?Sized>(&'a self, k: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q>,
+        Q: BorshSerialize + ToOwned<Owned = K>,
+    {
+        self.get(k).unwrap_or(default)
+    }
+
     /// Returns a mutable reference to the value corresponding to the key.
     ///
     /// The key may be any borrowed form of the map's key type, but
@@ -467,6 +513,15 @@ where
     /// value is returned. The key is not updated, though; this matters for
     /// types that can be `==` without being identical.
     ///
+    /// The existing entry is always read from storage, since the map has to know whether `k` is
+    /// already tracked in its key vector. Unlike [`LookupMap`], there is therefore no cheaper
+    /// `set` that skips this read.
+    ///
+    /// Bulk inserts don't need any capacity reservation: the length of the key vector is held in
+    /// memory and only persisted as part of the map's own serialized state, and the new entries
+    /// are cached until the map is [`flush`](Self::flush)ed or dropped. Inserting `n` keys in a
+    /// row never writes an intermediate length to storage.
+    ///
     /// # Examples
     ///
     /// ```
@@ -645,6 +700,118 @@ where
     {
         Entry::new(key, &mut self.keys, &mut self.values)
     }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all pairs `(k, v)` for which `f(&k, &mut v)` returns `false`.
+    /// The elements are visited once each, and the predicate may modify the values it keeps.
+    ///
+    /// # Performance
+    ///
+    /// Like [`remove`](Self::remove), every removal swaps the last key into the removed key's
+    /// position, so the iteration order of the remaining elements may change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableMap;
+    ///
+    /// let mut map = IterableMap::new(b"m");
+    /// map.extend((0..8u8).map(|i| (i, i * 10)));
+    ///
+    /// // Keep only the elements with even keys, and double their values.
+    /// map.retain(|&k, v| {
+    ///     *v *= 2;
+    ///     k % 2 == 0
+    /// });
+    /// assert_eq!(map.len(), 4);
+    /// assert_eq!(map[&2], 40);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        K: BorshDeserialize + Clone,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut key_index = 0;
+        while key_index < self.keys.len() {
+            let key = self
+                .keys
+                .get(key_index)
+                .unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE))
+                .clone();
+            let value =
+                self.values.get_mut(&key).unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE));
+            if f(&key, &mut value.value) {
+                key_index += 1;
+            } else {
+                // The last key is swapped into `key_index`, so it is visited next.
+                self.remove_entry(&key);
+            }
+        }
+    }
+
+    /// An iterator visiting all key-value pairs in ascending key order.
+    /// The iterator element type is `(&'a K, &'a V)`.
+    ///
+    /// Unlike [`iter`](Self::iter), whose order changes as entries are removed, the order is
+    /// stable, e.g. to paginate over the map across calls. This loads every entry of the map and
+    /// sorts them when called, which costs `O(n log n)` compared to the `O(n)` of a full
+    /// [`iter`](Self::iter), so it is best suited to view methods over small maps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableMap;
+    ///
+    /// let mut map = IterableMap::new(b"m");
+    /// map.insert(3u8, "c".to_string());
+    /// map.insert(1u8, "a".to_string());
+    /// map.insert(2u8, "b".to_string());
+    /// map.remove(&1);
+    ///
+    /// let keys: Vec<_> = map.sorted_iter().map(|(k, _)| *k).collect();
+    /// assert_eq!(keys, [2, 3]);
+    /// ```
+    pub fn sorted_iter(&self) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: BorshDeserialize,
+    {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable_by_key(|&(k, _)| k);
+        entries.into_iter()
+    }
+
+    /// Checks that the keys vector and the values map agree with each other, i.e. that every key
+    /// has a value which points back at the key's position in the vector. Panics if they do not.
+    ///
+    /// Only available in tests and debug builds, or with the `unit-testing` feature. This loads
+    /// every entry of the map, so it is meant as a debugging aid in tests, e.g. to surface storage
+    /// prefix collisions that would otherwise silently corrupt the map. Values whose key is not in
+    /// the keys vector cannot be detected, as the values map is not iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableMap;
+    ///
+    /// let mut map = IterableMap::new(b"m");
+    /// map.insert(1u8, "a".to_string());
+    /// map.insert(2u8, "b".to_string());
+    /// map.remove(&1);
+    /// map.assert_index_consistent();
+    /// ```
+    #[cfg(any(test, debug_assertions, feature = "unit-testing"))]
+    pub fn assert_index_consistent(&self)
+    where
+        K: BorshDeserialize,
+    {
+        for (key_index, key) in self.keys.iter().enumerate() {
+            match self.values.get(key) {
+                Some(value) if value.key_index as usize == key_index => {}
+                _ => env::panic_str(ERR_INCONSISTENT_STATE),
+            }
+        }
+    }
 }
 
 impl<K, V, H> IterableMap<K, V, H>
@@ -656,6 +823,25 @@ where
     /// Flushes the intermediate values of the map before this is called when the structure is
     /// [`Drop`]ed. This will write all modified values to storage but keep all cached values
     /// in memory.
+    ///
+    /// Changes are otherwise only written when the map is dropped, usually at the end of the
+    /// method when the contract state is. Flush explicitly when the entries have to be in
+    /// storage earlier in the same call, e.g. when they are read with
+    /// [`env::storage_read`](crate::env::storage_read).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::env;
+    /// use near_sdk::store::IterableMap;
+    ///
+    /// let mut map: IterableMap<u32, String> = IterableMap::new(b"m");
+    /// map.insert(1, "a".to_string());
+    ///
+    /// let storage_usage = env::storage_usage();
+    /// map.flush();
+    /// assert!(env::storage_usage() > storage_usage);
+    /// ```
     pub fn flush(&mut self) {
         self.keys.flush();
         self.values.flush();
@@ -666,6 +852,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::IterableMap;
+    use crate::env;
+    use crate::store::key::{Identity, ToKey};
     use crate::test_utils::test_env::setup_free;
     use arbitrary::{Arbitrary, Unstructured};
     use borsh::{to_vec, BorshDeserialize};
@@ -673,6 +861,36 @@ mod tests {
     use rand::SeedableRng;
     use std::collections::HashMap;
 
+    #[test]
+    fn flush_to_raw_storage() {
+        let mut map = IterableMap::<_, _, Identity>::with_hasher(b"m");
+        map.insert(5u8, 8u8);
+
+        // Values are stored under the map prefix followed by `m`, next to the index of the key.
+        let storage_key = Identity::to_key(b"mm", &5u8, &mut Vec::new());
+        assert_eq!(env::storage_read(&storage_key), None);
+
+        map.flush();
+        assert_eq!(env::storage_read(&storage_key), Some(to_vec(&(8u8, 0u32)).unwrap()));
+    }
+
+    #[test]
+    fn test_get_or_default() {
+        let mut map: IterableMap<u8, u64> = IterableMap::new(b"b");
+        map.insert(1, 5);
+
+        assert_eq!(map.get_or_default(&1), 5);
+        assert_eq!(*map.get_or(&1, &7), 5);
+        assert_eq!(map.get_or_default(&2), 0);
+        assert_eq!(*map.get_or(&2, &7), 7);
+
+        // Absent keys are not inserted, unlike with `entry(k).or_default()`.
+        map.flush();
+        assert_eq!(map.len(), 1);
+        let map: IterableMap<u8, u64> = IterableMap::new(b"b");
+        assert!(!map.values.contains_key(&2));
+    }
+
     #[test]
     fn basic_functionality() {
         let mut map = IterableMap::new(b"b");
@@ -789,6 +1007,95 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_assert_index_consistent() {
+        use super::ValueAndIndex;
+        use crate::store::key::Identity;
+
+        let mut map = IterableMap::<u8, u8, Identity>::with_hasher(b"m");
+        map.extend([(1, 10), (2, 20), (3, 30)]);
+        map.remove(&1);
+        map.assert_index_consistent();
+        map.flush();
+        let bytes = to_vec(&map).unwrap();
+        let value_key = |key: u8| [b"mm".as_slice(), &to_vec(&key).unwrap()].concat();
+
+        // Intact state loaded from storage.
+        IterableMap::<u8, u8, Identity>::try_from_slice(&bytes).unwrap().assert_index_consistent();
+
+        // Value pointing to the wrong key position.
+        let misplaced = ValueAndIndex { value: 20u8, key_index: 0 };
+        crate::env::storage_write(&value_key(2), &to_vec(&misplaced).unwrap());
+        let map = IterableMap::<u8, u8, Identity>::try_from_slice(&bytes).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            map.assert_index_consistent()
+        }));
+        assert!(result.is_err());
+
+        // Missing value.
+        crate::env::storage_remove(&value_key(2));
+        let map = IterableMap::<u8, u8, Identity>::try_from_slice(&bytes).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            map.assert_index_consistent()
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut map = IterableMap::new(b"m");
+        map.extend((1..=6u8).map(|i| (i, i * 10)));
+
+        let mut visited = Vec::new();
+        map.retain(|&k, v| {
+            visited.push(k);
+            *v += 1;
+            k != 2 && k != 4
+        });
+        visited.sort();
+        assert_eq!(visited, [1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(map.len(), 4);
+        // Removing 2 swaps 6 into its position, removing 4 then swaps 5 into its position.
+        assert_eq!(
+            map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(1, 11), (6, 61), (3, 31), (5, 51)]
+        );
+        map.assert_index_consistent();
+        assert_eq!(map.get(&2), None);
+        assert_eq!(map.get(&4), None);
+
+        // Changes are persisted.
+        map.flush();
+        let mut map = IterableMap::<u8, u8>::try_from_slice(&to_vec(&map).unwrap()).unwrap();
+        assert_eq!(map[&6], 61);
+
+        map.retain(|_, _| false);
+        assert!(map.is_empty());
+        assert_eq!(map.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_sorted_iter() {
+        let mut map = IterableMap::new(b"m");
+        map.extend([5u8, 1, 4, 2, 6, 3].map(|i| (i, u32::from(i) * 10)));
+
+        map.remove(&1);
+        map.remove(&4);
+        // Removals swap the last keys into the freed positions, `iter` is no longer sorted.
+        assert_ne!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 5, 6]);
+        assert_eq!(
+            map.sorted_iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(2, 20), (3, 30), (5, 50), (6, 60)]
+        );
+
+        // Pagination over the sorted entries is stable across reloads.
+        map.flush();
+        let map = IterableMap::<u8, u32>::try_from_slice(&to_vec(&map).unwrap()).unwrap();
+        let page: Vec<_> = map.sorted_iter().skip(1).take(2).map(|(k, _)| *k).collect();
+        assert_eq!(page, [3, 5]);
+    }
 }
 
 // Hashbrown-like tests.
@@ -362,3 +362,77 @@ where
         self.elements.next_back()
     }
 }
+
+/// A draining iterator for [`IterableSet`], yielding the elements matching a predicate.
+///
+/// This `struct` is created by the [`drain_filter`] method on [`IterableSet`].
+/// See its documentation for more.
+///
+/// [`drain_filter`]: IterableSet::drain_filter
+pub struct DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    set: &'a mut IterableSet<T, H>,
+    pred: F,
+    /// Index of the next element to check.
+    element_index: u32,
+}
+
+impl<'a, T, H, F> DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(set: &'a mut IterableSet<T, H>, pred: F) -> Self {
+        Self { set, pred, element_index: 0 }
+    }
+}
+
+impl<'a, T, H, F> Iterator for DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(element) = self.set.elements.get(self.element_index) {
+            if (self.pred)(element) {
+                let element = element.clone();
+                // The last element is swapped into `element_index`, so it is checked next.
+                self.set.remove(&element);
+                return Some(element);
+            }
+            self.element_index += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.set.elements.len() - self.element_index) as usize))
+    }
+}
+
+impl<'a, T, H, F> FusedIterator for DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+}
+
+impl<'a, T, H, F> Drop for DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
@@ -4,7 +4,9 @@
 mod impls;
 mod iter;
 
-pub use self::iter::{Difference, Drain, Intersection, Iter, SymmetricDifference, Union};
+pub use self::iter::{
+    Difference, Drain, DrainFilter, Intersection, Iter, SymmetricDifference, Union,
+};
 use super::{LookupMap, ERR_INCONSISTENT_STATE};
 use crate::store::key::{Sha256, ToKey};
 use crate::store::Vector;
@@ -284,11 +286,18 @@ where
     ///     println!("{}", x);
     /// }
     /// ```
+    ///
+    /// The smaller of the two sets is iterated, checking each of its values for membership in the
+    /// larger one, so the values are visited in the smaller set's iteration order.
     pub fn intersection<'a>(&'a self, other: &'a IterableSet<T, H>) -> Intersection<'a, T, H>
     where
         T: BorshDeserialize,
     {
-        Intersection::new(self, other)
+        if self.len() <= other.len() {
+            Intersection::new(self, other)
+        } else {
+            Intersection::new(other, self)
+        }
     }
 
     /// Visits the values representing the union, i.e., all the values in `self` or `other`, without
@@ -461,6 +470,39 @@ where
         Drain::new(self)
     }
 
+    /// Removes the elements for which `pred` returns `true` and returns them in an iterator.
+    ///
+    /// The predicate is called once for each element. If the iterator is dropped before being
+    /// fully consumed, the remaining matching elements are still removed.
+    ///
+    /// # Performance
+    ///
+    /// Like [`remove`](Self::remove), every removal swaps the last element into the removed
+    /// element's position, where it is checked next. Elements are therefore not visited in
+    /// iteration order, and the order of the remaining elements may change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableSet;
+    ///
+    /// let mut set = IterableSet::new(b"m");
+    /// set.extend(0..8u8);
+    ///
+    /// let mut odd: Vec<u8> = set.drain_filter(|v| v % 2 == 1).collect();
+    /// odd.sort();
+    /// assert_eq!(odd, [1, 3, 5, 7]);
+    /// assert_eq!(set.len(), 4);
+    /// assert!(!set.contains(&1));
+    /// ```
+    pub fn drain_filter<F>(&mut self, pred: F) -> DrainFilter<T, H, F>
+    where
+        T: BorshDeserialize + Clone,
+        F: FnMut(&T) -> bool,
+    {
+        DrainFilter::new(self, pred)
+    }
+
     /// Returns `true` if the set contains the specified value.
     ///
     /// The value may be any borrowed form of the set's value type, but
@@ -534,6 +576,38 @@ where
         }
     }
 
+    /// Checks that the elements vector and the index map agree with each other, i.e. that every
+    /// element is indexed at its position in the vector. Panics if they do not.
+    ///
+    /// Only available in tests and debug builds, or with the `unit-testing` feature. This loads
+    /// every element of the set, so it is meant as a debugging aid in tests, e.g. to surface
+    /// storage prefix collisions that would otherwise silently corrupt the set. Index entries for
+    /// values that are not in the elements vector cannot be detected, as the index map is not
+    /// iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::IterableSet;
+    ///
+    /// let mut set = IterableSet::new(b"m");
+    /// set.insert(1u8);
+    /// set.insert(2u8);
+    /// set.remove(&1);
+    /// set.assert_index_consistent();
+    /// ```
+    #[cfg(any(test, debug_assertions, feature = "unit-testing"))]
+    pub fn assert_index_consistent(&self)
+    where
+        T: BorshDeserialize + Clone,
+    {
+        for (element_index, element) in self.elements.iter().enumerate() {
+            if self.index.get(element).map(|&index| index as usize) != Some(element_index) {
+                env::panic_str(ERR_INCONSISTENT_STATE);
+            }
+        }
+    }
+
     /// Flushes the intermediate values of the map before this is called when the structure is
     /// [`Drop`]ed. This will write all modified values to storage but keep all cached values
     /// in memory.
@@ -550,8 +624,8 @@ mod tests {
     use crate::test_utils::test_env::setup_free;
     use arbitrary::{Arbitrary, Unstructured};
     use borsh::{to_vec, BorshDeserialize};
-    use rand::RngCore;
     use rand::SeedableRng;
+    use rand::{Rng, RngCore};
     use std::collections::HashSet;
 
     #[test]
@@ -612,6 +686,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_assert_index_consistent() {
+        use crate::store::key::Identity;
+
+        let mut set = IterableSet::<u8, Identity>::with_hasher(b"s");
+        set.extend([1, 2, 3]);
+        set.remove(&1);
+        set.assert_index_consistent();
+        set.flush();
+        let bytes = to_vec(&set).unwrap();
+        let index_key = |value: u8| [b"sm".as_slice(), &to_vec(&value).unwrap()].concat();
+
+        // Intact state loaded from storage.
+        IterableSet::<u8, Identity>::try_from_slice(&bytes).unwrap().assert_index_consistent();
+
+        // Index entry pointing to the wrong position.
+        crate::env::storage_write(&index_key(2), &to_vec(&0u32).unwrap());
+        let set = IterableSet::<u8, Identity>::try_from_slice(&bytes).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            set.assert_index_consistent()
+        }));
+        assert!(result.is_err());
+
+        // Missing index entry.
+        crate::env::storage_remove(&index_key(2));
+        let set = IterableSet::<u8, Identity>::try_from_slice(&bytes).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            set.assert_index_consistent()
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_drain_filter() {
+        setup_free();
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(5);
+        let mut set = IterableSet::new(b"s");
+        let mut expected = IterableSet::new(b"e");
+        for _ in 0..200 {
+            let value = rng.next_u32() % 500;
+            set.insert(value);
+            expected.insert(value);
+        }
+
+        // Manual two-pass removal.
+        let matching: Vec<u32> = expected.iter().filter(|v| *v % 3 == 0).copied().collect();
+        for value in &matching {
+            expected.remove(value);
+        }
+
+        let mut drained: Vec<u32> = set.drain_filter(|v| v % 3 == 0).collect();
+        drained.sort();
+        let mut matching = matching;
+        matching.sort();
+        assert_eq!(drained, matching);
+        set.assert_index_consistent();
+        assert_eq!(set.len(), expected.len());
+        assert_eq!(
+            set.iter().copied().collect::<HashSet<_>>(),
+            expected.iter().copied().collect::<HashSet<_>>()
+        );
+        assert!(drained.iter().all(|v| !set.contains(v)));
+    }
+
+    #[test]
+    fn test_drain_filter_dropped_early() {
+        let mut set = IterableSet::new(b"s");
+        set.extend(0..20u32);
+
+        let mut drain = set.drain_filter(|v| v % 2 == 0);
+        assert!(drain.next().is_some());
+        drop(drain);
+        set.assert_index_consistent();
+
+        assert_eq!(set.len(), 10);
+        assert!(set.iter().all(|v| v % 2 == 1));
+        assert_eq!(set.drain_filter(|_| false).count(), 0);
+        assert_eq!(set.drain_filter(|_| true).count(), 10);
+        assert!(set.is_empty());
+    }
+
     #[test]
     fn test_extend() {
         let mut a = IterableSet::<u64>::new(b"m");
@@ -832,6 +987,62 @@ mod tests {
         assert!(!ys.is_disjoint(&xs));
     }
 
+    #[test]
+    fn set_operations_match_hash_set() {
+        setup_free();
+
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(1);
+        for _ in 0..64 {
+            crate::mock::with_mocked_blockchain(|b| b.take_storage());
+
+            let mut set1 = IterableSet::new(b"m");
+            let mut set2 = IterableSet::new(b"n");
+            let mut hs1 = HashSet::new();
+            let mut hs2 = HashSet::new();
+            for _ in 0..rng.gen_range(0..32) {
+                let value = rng.gen_range(0u8..48);
+                set1.insert(value);
+                hs1.insert(value);
+            }
+            for _ in 0..rng.gen_range(0..32) {
+                let value = rng.gen_range(0u8..48);
+                set2.insert(value);
+                hs2.insert(value);
+            }
+
+            assert_eq!(
+                set1.intersection(&set2).collect::<HashSet<_>>(),
+                hs1.intersection(&hs2).collect::<HashSet<_>>()
+            );
+            assert_eq!(
+                set1.union(&set2).collect::<HashSet<_>>(),
+                hs1.union(&hs2).collect::<HashSet<_>>()
+            );
+            assert_eq!(
+                set1.difference(&set2).collect::<HashSet<_>>(),
+                hs1.difference(&hs2).collect::<HashSet<_>>()
+            );
+            assert_eq!(
+                set2.difference(&set1).collect::<HashSet<_>>(),
+                hs2.difference(&hs1).collect::<HashSet<_>>()
+            );
+            assert_eq!(
+                set1.symmetric_difference(&set2).collect::<HashSet<_>>(),
+                hs1.symmetric_difference(&hs2).collect::<HashSet<_>>()
+            );
+            // No duplicates are yielded.
+            assert_eq!(set1.union(&set2).count(), hs1.union(&hs2).count());
+            assert_eq!(set1.intersection(&set2).count(), hs1.intersection(&hs2).count());
+
+            let (smaller, larger) =
+                if set1.len() <= set2.len() { (&set1, &set2) } else { (&set2, &set1) };
+            assert_eq!(
+                set1.intersection(&set2).collect::<Vec<_>>(),
+                smaller.iter().filter(|v| larger.contains(*v)).collect::<Vec<_>>()
+            );
+        }
+    }
+
     #[derive(Arbitrary, Debug)]
     enum Op {
         Insert(u8),
@@ -12,6 +12,13 @@ mod private {
 }
 
 /// Trait used to generate keys to store data based on a serializable structure.
+///
+/// With [`Identity`], the storage key of an entry is the collection prefix followed by the
+/// serialized key, so long or user-controlled keys (e.g. account ids) create deep trie paths.
+/// [`Sha256`] and [`Keccak256`] hash the prefixed key into a fixed 32 byte storage key instead,
+/// bounding the depth at the cost of a hash per access. The original key cannot be recovered from
+/// a hashed storage key, so lookup collections using them cannot be enumerated from storage;
+/// iterable collections keep working since they store their keys separately.
 pub trait ToKey: self::private::Sealed {
     /// Output type for the generated lookup key.
     type KeyType: AsRef<[u8]>;
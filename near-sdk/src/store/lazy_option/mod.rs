@@ -120,6 +120,38 @@ where
         let entry = self.cache.get_mut().unwrap_or_else(|| env::abort());
         entry.value_mut()
     }
+
+    /// Returns a reference to the value, first setting it to the result of `f` if it is [`None`].
+    ///
+    /// The value is loaded from storage at most once, and only written back on [`flush`] or
+    /// [`Drop`] if it had to be inserted.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_sdk::store::LazyOption;
+    ///
+    /// let mut a = LazyOption::new(b"a", None);
+    /// assert_eq!(a.get_or_insert_with(|| 5u32), &5);
+    /// assert_eq!(a.get_or_insert_with(|| 6u32), &5);
+    /// ```
+    ///
+    /// [`flush`]: Self::flush
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        if self.get().is_none() {
+            self.set(Some(f()));
+        }
+        self.get().as_ref().unwrap_or_else(|| env::abort())
+    }
+
+    /// Returns a reference to the value, first setting it to `value` if it is [`None`].
+    ///
+    /// See [`get_or_insert_with`](Self::get_or_insert_with) to only compute the value if needed.
+    pub fn get_or_insert(&mut self, value: T) -> &T {
+        self.get_or_insert_with(|| value)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -163,6 +195,31 @@ mod tests {
         assert!(!env::storage_has_key(b"a"));
     }
 
+    #[test]
+    pub fn test_get_or_insert_with() {
+        let mut a = LazyOption::new(b"a", None);
+        assert_eq!(a.get_or_insert_with(|| 4u32), &4);
+        assert_eq!(a.get_or_insert(5), &4);
+        // Inserted value is only written on flush.
+        assert!(!env::storage_has_key(b"a"));
+        a.flush();
+        assert_eq!(env::storage_read(b"a"), Some(borsh::to_vec(&4u32).unwrap()));
+
+        // An existing value is loaded once and not written back.
+        let mut b = LazyOption::<u32>::new(b"b", None);
+        b.set(Some(7));
+        drop(b);
+        let mut b = LazyOption::<u32> {
+            prefix: b"b".to_vec().into_boxed_slice(),
+            cache: Default::default(),
+        };
+        assert_eq!(b.get_or_insert_with(|| panic!("value exists")), &7);
+        env::storage_write(b"b", &borsh::to_vec(&8u32).unwrap());
+        assert_eq!(b.get(), &Some(7));
+        b.flush();
+        assert_eq!(env::storage_read(b"b"), Some(borsh::to_vec(&8u32).unwrap()));
+    }
+
     #[test]
     pub fn test_debug() {
         let mut lazy_option = LazyOption::new(b"m", None);
@@ -213,16 +213,25 @@ where
         V::try_from_slice(bytes).unwrap_or_else(|_| env::panic_str(ERR_ELEMENT_DESERIALIZATION))
     }
 
-    fn load_element<Q: ?Sized>(prefix: &[u8], key: &Q) -> (H::KeyType, Option<V>)
+    fn load_element<Q: ?Sized>(
+        prefix: &[u8],
+        key: &Q,
+        buffer: &mut Vec<u8>,
+    ) -> (H::KeyType, Option<V>)
     where
         Q: BorshSerialize,
         K: Borrow<Q>,
     {
-        let key = H::to_key(prefix, key, &mut Vec::new());
+        let key = H::to_key(prefix, key, buffer);
         let storage_bytes = env::storage_read(key.as_ref());
         (key, storage_bytes.as_deref().map(Self::deserialize_element))
     }
 
+    /// Returns whether the value for the key is already loaded in the cache.
+    fn is_cached(&self, k: &K) -> bool {
+        self.cache.map_value_ref(k, |v| v.value.get().is_some()).unwrap_or(false)
+    }
+
     /// Returns a reference to the value corresponding to the key.
     ///
     /// The key may be any borrowed form of the map's key type, but
@@ -247,13 +256,59 @@ where
         //* ToOwned bound, which forces a clone, is required to be able to keep the key in the cache
         let cached = self.cache.get(k.to_owned());
         let entry = cached.value.get_or_init(|| {
-            let (key, element) = Self::load_element(&self.prefix, k);
+            let (key, element) = Self::load_element(&self.prefix, k, &mut Vec::new());
             let _ = cached.hash.set(key);
             CacheEntry::new_cached(element)
         });
         entry.value().as_ref()
     }
 
+    /// Returns a clone of the value corresponding to the key, or [`V::default()`](Default) if
+    /// the key is not in the map.
+    ///
+    /// Unlike `entry(k).or_default()`, this does not insert the default value, so nothing is
+    /// written to storage for an absent key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::LookupMap;
+    ///
+    /// let mut map: LookupMap<String, u64> = LookupMap::new(b"m");
+    /// map.insert("a".to_string(), 5);
+    /// assert_eq!(map.get_or_default("a"), 5);
+    /// assert_eq!(map.get_or_default("b"), 0);
+    /// ```
+    pub fn get_or_default<Q: ?Sized>(&self, k: &Q) -> V
+    where
+        K: Borrow<Q>,
+        Q: BorshSerialize + ToOwned<Owned = K>,
+        V: Default + Clone,
+    {
+        self.get(k).cloned().unwrap_or_default()
+    }
+
+    /// Returns a reference to the value corresponding to the key, or `default` if the key is not
+    /// in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::LookupMap;
+    ///
+    /// let mut map: LookupMap<String, u64> = LookupMap::new(b"m");
+    /// map.insert("a".to_string(), 5);
+    /// assert_eq!(*map.get_or("a", &0), 5);
+    /// assert_eq!(*map.get_or("b", &0), 0);
+    /// ```
+    pub fn get_or<'a, Q: ?Sized>(&'a self, k: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q>,
+        Q: BorshSerialize + ToOwned<Owned = K>,
+    {
+        self.get(k).unwrap_or(default)
+    }
+
     pub(crate) fn get_mut_inner<Q: ?Sized>(&mut self, k: &Q) -> &mut CacheEntry<V>
     where
         K: Borrow<Q>,
@@ -263,7 +318,7 @@ where
         //* ToOwned bound, which forces a clone, is required to be able to keep the key in the cache
         let entry = self.cache.get_mut(k.to_owned());
         entry.value.get_or_init(|| {
-            let (key, value) = Self::load_element(prefix, k);
+            let (key, value) = Self::load_element(prefix, k, &mut Vec::new());
             let _ = entry.hash.set(key);
             CacheEntry::new_cached(value)
         });
@@ -304,6 +359,10 @@ where
     /// value is returned. The key is not updated, though; this matters for
     /// types that can be `==` without being identical.
     ///
+    /// Returning the old value requires reading it from storage if it isn't cached yet, which
+    /// costs gas proportional to its size. Use [`LookupMap::set`] to overwrite the value without
+    /// reading it.
+    ///
     /// # Example
     /// ```
     /// use near_sdk::store::LookupMap;
@@ -323,6 +382,84 @@ where
         self.get_mut_inner(&k).replace(Some(v))
     }
 
+    /// Returns references to the values corresponding to each of the keys, in the same order
+    /// as the keys are provided.
+    ///
+    /// This is equivalent to calling [`LookupMap::get`] for each key, but reuses a single buffer
+    /// for computing storage keys and does not require the keys to be cloned into the cache.
+    ///
+    /// # Example
+    /// ```
+    /// use near_sdk::store::LookupMap;
+    ///
+    /// let mut map: LookupMap<u32, String> = LookupMap::new(b"m");
+    /// map.insert(1, "a".to_string());
+    /// map.insert(3, "c".to_string());
+    ///
+    /// assert_eq!(map.get_many([1, 2, 3]), [Some(&"a".to_string()), None, Some(&"c".to_string())]);
+    /// ```
+    pub fn get_many<I>(&self, keys: I) -> Vec<Option<&V>>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut buf = Vec::new();
+        keys.into_iter()
+            .map(|k| {
+                if self.is_cached(&k) {
+                    return self.cache.get(k);
+                }
+                buf.clear();
+                let (key, element) = Self::load_element(&self.prefix, &k, &mut buf);
+                let cached = self.cache.get(k);
+                let _ = cached.hash.set(key);
+                let _ = cached.value.set(CacheEntry::new_cached(element));
+                cached
+            })
+            .map(|cached| cached.value.get().unwrap_or_else(|| env::abort()).value().as_ref())
+            .collect()
+    }
+
+    /// Inserts each of the key-value pairs into the map, returning the previous value for each
+    /// key in the same order as the entries are provided.
+    ///
+    /// This is equivalent to calling [`LookupMap::insert`] for each entry, but reuses a single
+    /// buffer for computing storage keys and does not require the keys to be cloned. If the
+    /// previous values are not needed, [`Extend::extend`] avoids loading them from storage.
+    ///
+    /// # Example
+    /// ```
+    /// use near_sdk::store::LookupMap;
+    ///
+    /// let mut map: LookupMap<u32, String> = LookupMap::new(b"m");
+    /// map.insert(1, "a".to_string());
+    ///
+    /// let prev = map.insert_many([(1, "b".to_string()), (2, "c".to_string())]);
+    /// assert_eq!(prev, [Some("a".to_string()), None]);
+    /// assert_eq!(map[&1], "b".to_string());
+    /// assert_eq!(map[&2], "c".to_string());
+    /// ```
+    pub fn insert_many<I>(&mut self, entries: I) -> Vec<Option<V>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut buf = Vec::new();
+        entries
+            .into_iter()
+            .map(|(k, v)| {
+                if self.is_cached(&k) {
+                    let entry = self.cache.get_mut(k).value.get_mut();
+                    return entry.unwrap_or_else(|| env::abort()).replace(Some(v));
+                }
+                buf.clear();
+                let (key, element) = Self::load_element(&self.prefix, &k, &mut buf);
+                let cached = self.cache.get_mut(k);
+                let _ = cached.hash.set(key);
+                let _ = cached.value.set(CacheEntry::new_modified(Some(v)));
+                element
+            })
+            .collect()
+    }
+
     /// Returns `true` if the map contains a value for the specified key.
     ///
     /// The key may be any borrowed form of the map's key type, but
@@ -428,6 +565,26 @@ where
     /// Flushes the intermediate values of the map before this is called when the structure is
     /// [`Drop`]ed. This will write all modified values to storage but keep all cached values
     /// in memory.
+    ///
+    /// Changes are otherwise only written when the map is dropped, usually at the end of the
+    /// method when the contract state is. Flush explicitly when the entries have to be in
+    /// storage earlier in the same call, e.g. when they are read with
+    /// [`env::storage_read`](crate::env::storage_read).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::borsh;
+    /// use near_sdk::env;
+    /// use near_sdk::store::LookupMap;
+    ///
+    /// let mut map: LookupMap<u32, String> = LookupMap::new(b"m");
+    /// map.insert(1, "a".to_string());
+    /// map.flush();
+    ///
+    /// let key = [b"m".as_slice(), &borsh::to_vec(&1u32).unwrap()].concat();
+    /// assert_eq!(env::storage_read(&key), Some(borsh::to_vec("a").unwrap()));
+    /// ```
     pub fn flush(&mut self) {
         let mut buf = Vec::new();
         for (k, v) in self.cache.inner().iter_mut() {
@@ -465,7 +622,7 @@ where
 mod tests {
     use super::LookupMap;
     use crate::env;
-    use crate::store::key::{Keccak256, ToKey};
+    use crate::store::key::{Identity, Keccak256, ToKey};
     use crate::test_utils::test_env::setup_free;
     use arbitrary::{Arbitrary, Unstructured};
     use rand::seq::SliceRandom;
@@ -473,6 +630,41 @@ mod tests {
     use rand::{Rng, SeedableRng};
     use std::collections::HashMap;
 
+    #[test]
+    fn set_does_not_read_previous_value() {
+        let mut map: LookupMap<u8, u64> = LookupMap::new(b"m");
+        // Bytes under the element's key that can't be deserialized as `u64`.
+        env::storage_write(&[b'm', 1], &[0xff]);
+
+        map.set(1, Some(5));
+        map.flush();
+        assert_eq!(map.get(&1), Some(&5));
+
+        let mut map: LookupMap<u8, u64> = LookupMap::new(b"m");
+        assert_eq!(map.insert(1, 6), Some(5));
+    }
+
+    #[test]
+    fn test_get_or_default() {
+        let mut map: LookupMap<u8, u64> = LookupMap::new(b"m");
+        map.insert(1, 5);
+
+        assert_eq!(map.get_or_default(&1), 5);
+        assert_eq!(*map.get_or(&1, &7), 5);
+        assert_eq!(map.get_or_default(&2), 0);
+        assert_eq!(*map.get_or(&2, &7), 7);
+
+        // Absent keys are not written, unlike with `entry(k).or_default()`.
+        map.flush();
+        assert!(env::storage_has_key(&[b'm', 1]));
+        assert!(!env::storage_has_key(&[b'm', 2]));
+        assert!(!map.contains_key(&2));
+
+        map.entry(2).or_default();
+        map.flush();
+        assert!(env::storage_has_key(&[b'm', 2]));
+    }
+
     #[test]
     fn test_insert() {
         let mut map = LookupMap::new(b"m");
@@ -612,6 +804,42 @@ mod tests {
         assert_eq!(lm1.get(&8), Some("New".to_string()));
     }
 
+    #[test]
+    fn test_get_insert_many_matches_loop() {
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(3);
+        let entries: Vec<(u64, u64)> = (0..200).map(|_| (rng.gen(), rng.gen())).collect();
+        let keys: Vec<u64> =
+            entries.iter().map(|(k, _)| *k).chain((0..50).map(|_| rng.gen())).collect();
+        let mut batched = LookupMap::<u64, u64, Keccak256>::with_hasher(b"b");
+        let mut looped = LookupMap::<u64, u64, Keccak256>::with_hasher(b"l");
+        batched.extend(entries[..100].iter().copied());
+        looped.extend(entries[..100].iter().copied());
+        batched.flush();
+        looped.flush();
+
+        let gas_before = env::used_gas();
+        let batched_prev = batched.insert_many(entries.iter().copied());
+        let batched_values: Vec<_> = batched.get_many(keys.iter().copied());
+        let batched_gas = env::used_gas().saturating_sub(gas_before);
+
+        let gas_before = env::used_gas();
+        let looped_prev: Vec<_> = entries.iter().map(|&(k, v)| looped.insert(k, v)).collect();
+        let looped_values: Vec<_> = keys.iter().map(|k| looped.get(k)).collect();
+        let looped_gas = env::used_gas().saturating_sub(gas_before);
+
+        assert_eq!(batched_prev, looped_prev);
+        assert_eq!(batched_values, looped_values);
+        assert!(batched_gas <= looped_gas);
+
+        batched.flush();
+        looped.flush();
+        for key in keys {
+            let batched_key = Keccak256::to_key(b"b", &key, &mut Vec::new());
+            let looped_key = Keccak256::to_key(b"l", &key, &mut Vec::new());
+            assert_eq!(env::storage_read(&batched_key), env::storage_read(&looped_key));
+        }
+    }
+
     #[test]
     fn test_extend() {
         let mut map = LookupMap::new(b"m");
@@ -639,6 +867,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn flush_to_raw_storage() {
+        let mut map = LookupMap::<_, _, Identity>::with_hasher(b"m");
+        map.insert(5u8, 8u8);
+
+        let storage_key = Identity::to_key(b"m", &5u8, &mut Vec::new());
+        assert_eq!(env::storage_read(&storage_key), None);
+
+        map.flush();
+        assert_eq!(env::storage_read(&storage_key), Some(vec![8]));
+
+        // Removals are written as well.
+        map.remove(&5);
+        map.flush();
+        assert_eq!(env::storage_read(&storage_key), None);
+    }
+
     #[test]
     fn flush_on_drop() {
         let mut map = LookupMap::<_, _, Keccak256>::with_hasher(b"m");
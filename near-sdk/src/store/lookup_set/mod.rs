@@ -45,6 +45,9 @@ use near_sdk_macros::near;
 /// assert!(!books.contains("The Odyssey"));
 /// ```
 ///
+/// A [`LookupSet`] does not track how many elements it holds. If a count is needed, use
+/// [`CountedLookupSet`](crate::store::CountedLookupSet) instead.
+///
 /// [`with_hasher`]: Self::with_hasher
 #[near(inside_nearsdk)]
 pub struct LookupSet<T, H = Identity>
@@ -146,7 +149,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::LookupSet;
-    use crate::store::key::{Identity, Keccak256, ToKey};
+    use crate::store::key::{Identity, Keccak256, Sha256, ToKey};
     use crate::test_utils::test_env::setup_free;
     use arbitrary::{Arbitrary, Unstructured};
     use rand::seq::SliceRandom;
@@ -374,6 +377,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sha256_hashed_key() {
+        let account_id: crate::AccountId = "alice.near".parse().unwrap();
+        let hashed_key = Sha256::to_key(b"m", &account_id, &mut Vec::new());
+        let identity_key = Identity::to_key(b"m", &account_id, &mut Vec::new());
+        {
+            let mut set = LookupSet::<_, Sha256>::with_hasher(b"m");
+            set.insert(account_id.clone());
+        }
+        assert_eq!(hashed_key, crate::env::sha256_array(&identity_key));
+        assert!(crate::env::storage_has_key(&hashed_key));
+        assert!(!crate::env::storage_has_key(&identity_key));
+
+        let set = LookupSet::<crate::AccountId, Sha256>::with_hasher(b"m");
+        assert!(set.contains(&account_id));
+        assert!(!set.contains(&"bob.near".parse::<crate::AccountId>().unwrap()));
+    }
+
     #[derive(Arbitrary, Debug)]
     enum Op {
         Insert(u8),
@@ -43,6 +43,9 @@
 //!
 //! - [`Vector`]: Analogous to [`Vec`] but not contiguous and persisted to storage.
 //!
+//! - [`Queue`]: First-in, first-out queue with `O(1)` push to the back and pop from the front,
+//!   persisted to storage.
+//!
 //! Maps:
 //!
 //! - [`LookupMap`]: Wrapper around key-value storage interactions, similar to
@@ -59,6 +62,8 @@
 //!
 //! - [`LookupSet`]: Non-iterable storage version of [`std::collections::HashSet`].
 //!
+//! - [`CountedLookupSet`]: [`LookupSet`] that also keeps track of the number of elements.
+//!
 //! - [`UnorderedSet`]: Analogous to [`std::collections::HashSet`], and is an iterable
 //!   version of [`LookupSet`] and persisted to storage.
 //!
@@ -83,12 +88,18 @@ pub use lazy_option::LazyOption;
 pub mod vec;
 pub use vec::Vector;
 
+pub mod queue;
+pub use queue::Queue;
+
 pub mod lookup_map;
 pub use self::lookup_map::LookupMap;
 
 mod lookup_set;
 pub use self::lookup_set::LookupSet;
 
+mod counted_lookup_set;
+pub use self::counted_lookup_set::CountedLookupSet;
+
 pub mod iterable_map;
 pub use self::iterable_map::IterableMap;
 pub mod iterable_set;
@@ -0,0 +1,457 @@
+//! A first-in, first-out queue with values persisted to storage and lazily loaded.
+//!
+//! Values in the [`Queue`] are kept in an in-memory cache and are only persisted on [`Drop`].
+//!
+//! The queue is implemented as a ring buffer over storage indices, so that both
+//! [`push_back`](Queue::push_back) and [`pop_front`](Queue::pop_front) are `O(1)` and no
+//! elements are shifted when the front of the queue is removed.
+//!
+//! # Examples
+//!
+//! ```
+//! use near_sdk::store::Queue;
+//!
+//! let mut queue: Queue<u32> = Queue::new(b"q");
+//! queue.push_back(1);
+//! queue.push_back(2);
+//!
+//! assert_eq!(queue.front(), Some(&1));
+//! assert_eq!(queue.pop_front(), Some(1));
+//! assert_eq!(queue.pop_front(), Some(2));
+//! assert_eq!(queue.pop_front(), None);
+//! ```
+
+mod iter;
+
+use std::fmt;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk_macros::near;
+
+pub use self::iter::Iter;
+use super::{IndexMap, ERR_INCONSISTENT_STATE};
+use crate::{env, IntoStorageKey};
+
+const ERR_QUEUE_FULL: &str = "Queue length exceeds u32::MAX";
+
+/// A FIFO queue that stores its content on the trie. This implementation will load and store
+/// values in the underlying storage lazily.
+///
+/// Elements are stored under `index -> element`, where indices are taken from a ring over the
+/// full `u32` range. The index of the first element (`head`) and the number of elements (`len`)
+/// are the only values serialized with the queue itself.
+///
+/// This implementation will cache all changes and loads and only updates values that are changed
+/// in storage after it's dropped through it's [`Drop`] implementation. These changes can be updated
+/// in storage before the variable is dropped by using [`Queue::flush`].
+///
+/// # Examples
+/// ```
+/// use near_sdk::store::Queue;
+///
+/// let mut queue = Queue::new(b"q");
+/// assert!(queue.is_empty());
+///
+/// queue.push_back("first".to_string());
+/// queue.push_back("second".to_string());
+/// assert_eq!(queue.len(), 2);
+///
+/// assert_eq!(queue.pop_front().as_deref(), Some("first"));
+/// assert_eq!(queue.front().map(String::as_str), Some("second"));
+/// ```
+#[near(inside_nearsdk)]
+pub struct Queue<T>
+where
+    T: BorshSerialize,
+{
+    /// Index of the first element in the queue.
+    pub(crate) head: u32,
+    pub(crate) len: u32,
+    // ser/de is independent of `T` ser/de, `BorshSerialize`/`BorshDeserialize`/`BorshSchema` bounds removed
+    #[cfg_attr(not(feature = "abi"), borsh(bound(serialize = "", deserialize = "")))]
+    #[cfg_attr(
+        feature = "abi",
+        borsh(bound(serialize = "", deserialize = ""), schema(params = ""))
+    )]
+    pub(crate) values: IndexMap<T>,
+}
+
+impl<T> Queue<T>
+where
+    T: BorshSerialize,
+{
+    /// Create new queue with zero elements. Prefixes storage access with the prefix provided.
+    ///
+    /// This prefix can be anything that implements [`IntoStorageKey`]. The prefix is used when
+    /// storing and looking up values in storage to ensure no collisions with other collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue: Queue<u8> = Queue::new(b"q");
+    /// ```
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { head: 0, len: 0, values: IndexMap::new(prefix) }
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// assert!(queue.is_empty());
+    ///
+    /// queue.push_back(1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Converts a position relative to the front of the queue into a storage index.
+    fn index(&self, position: u32) -> u32 {
+        self.head.wrapping_add(position)
+    }
+
+    /// Appends an element to the back of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if new length exceeds `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    ///
+    /// assert_eq!(queue.front(), Some(&1));
+    /// ```
+    pub fn push_back(&mut self, element: T) {
+        let index = self.index(self.len);
+        self.len = self.len.checked_add(1).unwrap_or_else(|| env::panic_str(ERR_QUEUE_FULL));
+        self.values.set(index, Some(element));
+    }
+
+    /// Removes all elements from the queue. This will remove all storage values for the
+    /// elements currently in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// queue.push_back(1);
+    ///
+    /// queue.clear();
+    ///
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        for position in 0..self.len {
+            let index = self.index(position);
+            self.values.set(index, None);
+        }
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Flushes the cache and writes all modified values to storage.
+    ///
+    /// This operation is performed on [`Drop`], but this method can be called to persist
+    /// intermediate writes in cases where [`Drop`] is not called or to identify storage changes.
+    pub fn flush(&mut self) {
+        self.values.flush();
+    }
+}
+
+impl<T> Queue<T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    /// Returns a reference to the element at the front of the queue, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// assert_eq!(queue.front(), None);
+    ///
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    /// assert_eq!(queue.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the element at `position` from the front of the queue, or `None` if the position
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    ///
+    /// assert_eq!(queue.get(1), Some(&2));
+    /// assert_eq!(queue.get(2), None);
+    /// ```
+    pub fn get(&self, position: u32) -> Option<&T> {
+        if position >= self.len {
+            return None;
+        }
+        self.values.get(self.index(position))
+    }
+
+    /// Removes the first element from the queue and returns it, or [`None`] if it is empty.
+    ///
+    /// The storage entry of the removed element is cleared when the queue is flushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    ///
+    /// assert_eq!(queue.pop_front(), Some(1));
+    /// assert_eq!(queue.pop_front(), Some(2));
+    /// assert_eq!(queue.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.values.remove(self.head);
+        self.head = self.head.wrapping_add(1);
+        self.len -= 1;
+        Some(value.unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE)))
+    }
+
+    /// Returns an iterator over the queue, from front to back. This iterator will lazily load
+    /// any values iterated over from storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Queue;
+    ///
+    /// let mut queue = Queue::new(b"q");
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    /// queue.push_back(4);
+    /// let mut iterator = queue.iter();
+    ///
+    /// assert_eq!(iterator.next(), Some(&1));
+    /// assert_eq!(iterator.next(), Some(&2));
+    /// assert_eq!(iterator.next(), Some(&4));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self)
+    }
+}
+
+impl<T> Drop for Queue<T>
+where
+    T: BorshSerialize,
+{
+    fn drop(&mut self) {
+        self.flush()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Extend<T> for Queue<T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for item in iter {
+            self.push_back(item)
+        }
+    }
+}
+
+impl<T> fmt::Debug for Queue<T>
+where
+    T: BorshSerialize + BorshDeserialize + fmt::Debug,
+{
+    #[cfg(feature = "expensive-debug")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.iter().collect::<Vec<_>>(), f)
+    }
+
+    #[cfg(not(feature = "expensive-debug"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Queue")
+            .field("head", &self.head)
+            .field("len", &self.len)
+            .field("prefix", &self.values.prefix)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    use super::Queue;
+    use crate::env;
+
+    fn storage_key(prefix: &[u8], index: u32) -> Vec<u8> {
+        [prefix, &index.to_le_bytes()].concat()
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(0);
+        let mut queue = Queue::new(b"q".to_vec());
+        let mut baseline = VecDeque::new();
+        for _ in 0..500 {
+            if rng.gen_bool(0.6) {
+                let value = rng.gen::<u64>();
+                queue.push_back(value);
+                baseline.push_back(value);
+            } else {
+                assert_eq!(queue.pop_front(), baseline.pop_front());
+            }
+            assert_eq!(queue.front(), baseline.front());
+            assert_eq!(queue.len() as usize, baseline.len());
+        }
+        assert!(queue.iter().eq(baseline.iter()));
+        while let Some(value) = baseline.pop_front() {
+            assert_eq!(queue.pop_front(), Some(value));
+        }
+        assert_eq!(queue.pop_front(), None);
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut queue = Queue::new(b"q");
+        queue.head = u32::MAX - 1;
+
+        queue.extend([1u8, 2, 3, 4]);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        queue.flush();
+
+        // Elements past `u32::MAX` wrap around to the start of the index space.
+        assert!(env::storage_has_key(&storage_key(b"q", u32::MAX)));
+        assert!(env::storage_has_key(&storage_key(b"q", 0)));
+        assert!(env::storage_has_key(&storage_key(b"q", 1)));
+
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(2));
+        assert_eq!(queue.head, 0);
+        assert_eq!(queue.front(), Some(&3));
+        queue.push_back(5);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_pop_releases_storage() {
+        let mut queue = Queue::new(b"q");
+        queue.extend([1u8, 2, 3]);
+        queue.flush();
+        assert!(env::storage_has_key(&storage_key(b"q", 0)));
+
+        assert_eq!(queue.pop_front(), Some(1));
+        queue.flush();
+        assert!(!env::storage_has_key(&storage_key(b"q", 0)));
+        assert!(env::storage_has_key(&storage_key(b"q", 1)));
+
+        queue.clear();
+        queue.flush();
+        assert!(!env::storage_has_key(&storage_key(b"q", 1)));
+        assert!(!env::storage_has_key(&storage_key(b"q", 2)));
+    }
+
+    #[test]
+    fn test_interleaved_reclaims_storage() {
+        let mut queue = Queue::new(b"q");
+        let mut next = 0u32;
+        for round in 0..50u32 {
+            for _ in 0..3 {
+                queue.push_back(next);
+                next += 1;
+            }
+            for _ in 0..2 {
+                let head = queue.head;
+                assert_eq!(queue.pop_front(), Some(head));
+            }
+            queue.flush();
+
+            // Only the slots of the elements still in the queue are kept in storage.
+            assert_eq!(queue.len(), round + 1);
+            assert!(!env::storage_has_key(&storage_key(b"q", queue.head.wrapping_sub(1))));
+            for index in queue.head..queue.head + queue.len() {
+                assert!(env::storage_has_key(&storage_key(b"q", index)));
+            }
+        }
+        assert!(queue.iter().copied().eq(100..150));
+    }
+
+    #[test]
+    fn test_persisted_across_serialization() {
+        let mut queue = Queue::new(b"q");
+        queue.extend([1u8, 2, 3]);
+        queue.pop_front();
+        queue.flush();
+
+        let serialized = borsh::to_vec(&queue).unwrap();
+        let deserialized: Queue<u8> = borsh::from_slice(&serialized).unwrap();
+        assert_eq!(deserialized.iter().copied().collect::<Vec<_>>(), [2, 3]);
+    }
+}
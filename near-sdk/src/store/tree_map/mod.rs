@@ -12,7 +12,7 @@ pub use entry::Entry;
 pub use iter::{Iter, IterMut, Keys, Range, RangeMut, Values, ValuesMut};
 use std::borrow::Borrow;
 use std::fmt;
-use std::ops::RangeBounds;
+use std::ops::{Bound, RangeBounds};
 
 use near_sdk_macros::near;
 
@@ -784,6 +784,108 @@ where
             }
         }
     }
+
+    fn height_of(&self, id: Option<FreeListIndex>) -> u32 {
+        id.and_then(|id| self.node(id).map(|n| n.ht)).unwrap_or_default()
+    }
+
+    // Join two AVL subtrees with `mid` in between, where all keys of `lft` are smaller and all
+    // keys of `rgt` are larger than the key of `mid`. The subtrees can differ in height by any
+    // amount: `mid` is attached along the spine of the taller one, at the first node that is at
+    // most one level taller than the other subtree, and the tree is rebalanced on the way back.
+    // Returns the root of the joined subtree.
+    fn join(
+        &mut self,
+        lft: Option<FreeListIndex>,
+        (mid_id, mut mid): (FreeListIndex, Node<K>),
+        rgt: Option<FreeListIndex>,
+    ) -> FreeListIndex {
+        let (lht, rht) = (self.height_of(lft), self.height_of(rgt));
+        if lht > rht + 1 {
+            let l_id = expect(lft);
+            let mut l_node = expect(self.node(l_id)).clone();
+            l_node.rgt = Some(self.join(l_node.rgt, (mid_id, mid), rgt));
+            self.update_height(&mut l_node, l_id);
+            self.enforce_balance(&mut l_node, l_id)
+        } else if rht > lht + 1 {
+            let r_id = expect(rgt);
+            let mut r_node = expect(self.node(r_id)).clone();
+            r_node.lft = Some(self.join(lft, (mid_id, mid), r_node.lft));
+            self.update_height(&mut r_node, r_id);
+            self.enforce_balance(&mut r_node, r_id)
+        } else {
+            mid.lft = lft;
+            mid.rgt = rgt;
+            self.update_height(&mut mid, mid_id);
+            mid_id
+        }
+    }
+
+    // Detach the node holding the min key of the subtree at `at`, rebalancing the path to it.
+    // Returns the new root of the subtree and the detached node.
+    fn detach_min(
+        &mut self,
+        at: FreeListIndex,
+    ) -> (Option<FreeListIndex>, (FreeListIndex, Node<K>)) {
+        let mut node = expect(self.node(at)).clone();
+        match node.lft {
+            None => (node.rgt, (at, node)),
+            Some(lft) => {
+                let (lft, min) = self.detach_min(lft);
+                node.lft = lft;
+                self.update_height(&mut node, at);
+                (Some(self.enforce_balance(&mut node, at)), min)
+            }
+        }
+    }
+
+    // Remove all keys within `bounds` from the subtree at `at` and push them to `removed`.
+    // Subtrees outside of the bounds are kept intact and only re-joined with what is left of
+    // their siblings, so the tree is rebalanced once per level rather than once per removed key.
+    // Returns the new root of the subtree.
+    fn remove_range_at<Q>(
+        &mut self,
+        at: Option<FreeListIndex>,
+        bounds: (Bound<&Q>, Bound<&Q>),
+        removed: &mut Vec<K>,
+    ) -> Option<FreeListIndex>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let id = at?;
+        let node = expect(self.node(id)).clone();
+        let key: &Q = node.key.borrow();
+        let below_start = match bounds.0 {
+            Bound::Included(start) => key < start,
+            Bound::Excluded(start) => key <= start,
+            Bound::Unbounded => false,
+        };
+        let above_end = match bounds.1 {
+            Bound::Included(end) => key > end,
+            Bound::Excluded(end) => key >= end,
+            Bound::Unbounded => false,
+        };
+
+        if below_start {
+            let rgt = self.remove_range_at(node.rgt, bounds, removed);
+            Some(self.join(node.lft, (id, node), rgt))
+        } else if above_end {
+            let (lft, rgt) = (self.remove_range_at(node.lft, bounds, removed), node.rgt);
+            Some(self.join(lft, (id, node), rgt))
+        } else {
+            let lft = self.remove_range_at(node.lft, bounds, removed);
+            let rgt = self.remove_range_at(node.rgt, bounds, removed);
+            removed.push(expect(self.nodes.remove(id)).key);
+            match rgt {
+                Some(rgt) => {
+                    let (rgt, min) = self.detach_min(rgt);
+                    Some(self.join(lft, min, rgt))
+                }
+                None => lft,
+            }
+        }
+    }
 }
 
 impl<K, V, H> TreeMap<K, V, H>
@@ -914,6 +1016,91 @@ where
     {
         RangeMut::new(self, (range.start_bound(), range.end_bound()))
     }
+
+    /// Returns the largest key in the map that is less than or equal to the given key.
+    ///
+    /// The lookup walks down the tree from its root, so only the nodes on a single path are
+    /// loaded from storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::TreeMap;
+    ///
+    /// let mut map = TreeMap::new(b"t");
+    /// map.extend([(10, "a"), (20, "b")].map(|(k, v)| (k, v.to_string())));
+    /// assert_eq!(map.floor_key(&20), Some(&20));
+    /// assert_eq!(map.floor_key(&15), Some(&10));
+    /// assert_eq!(map.floor_key(&5), None);
+    /// ```
+    pub fn floor_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: BorshDeserialize + Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.tree.floor_key(key)
+    }
+
+    /// Returns the smallest key in the map that is greater than or equal to the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::TreeMap;
+    ///
+    /// let mut map = TreeMap::new(b"t");
+    /// map.extend([(10, "a"), (20, "b")].map(|(k, v)| (k, v.to_string())));
+    /// assert_eq!(map.ceil_key(&10), Some(&10));
+    /// assert_eq!(map.ceil_key(&15), Some(&20));
+    /// assert_eq!(map.ceil_key(&25), None);
+    /// ```
+    pub fn ceil_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: BorshDeserialize + Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.tree.ceil_key(key)
+    }
+
+    /// Returns the largest key in the map that is strictly less than the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::TreeMap;
+    ///
+    /// let mut map = TreeMap::new(b"t");
+    /// map.extend([(10, "a"), (20, "b")].map(|(k, v)| (k, v.to_string())));
+    /// assert_eq!(map.lower_key(&20), Some(&10));
+    /// assert_eq!(map.lower_key(&10), None);
+    /// ```
+    pub fn lower_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: BorshDeserialize + Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.tree.lower(key)
+    }
+
+    /// Returns the smallest key in the map that is strictly greater than the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::TreeMap;
+    ///
+    /// let mut map = TreeMap::new(b"t");
+    /// map.extend([(10, "a"), (20, "b")].map(|(k, v)| (k, v.to_string())));
+    /// assert_eq!(map.higher_key(&10), Some(&20));
+    /// assert_eq!(map.higher_key(&20), None);
+    /// ```
+    pub fn higher_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: BorshDeserialize + Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.tree.higher(key)
+    }
 }
 
 impl<K, V, H> TreeMap<K, V, H>
@@ -950,6 +1137,46 @@ where
         })
     }
 
+    /// Removes all keys within the given range from the map, returning the number of removed
+    /// entries. The range is specified like in [`range`](Self::range), but unlike it, an empty
+    /// or inverted range doesn't panic and simply removes nothing.
+    ///
+    /// Subtrees on either side of the range are kept as they are and re-joined, so the tree is
+    /// rebalanced along the boundaries of the range rather than after each removed key. Removed
+    /// values are not loaded from storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::TreeMap;
+    ///
+    /// let mut expiring = TreeMap::new(b"e");
+    /// expiring.extend((0..10u64).map(|timestamp| (timestamp, timestamp.to_string())));
+    ///
+    /// assert_eq!(expiring.range_remove(..4), 4);
+    /// assert_eq!(expiring.range_remove(..4), 0);
+    /// assert_eq!(expiring.keys().copied().collect::<Vec<_>>(), [4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn range_remove<R, Q>(&mut self, range: R) -> u64
+    where
+        K: Borrow<Q> + BorshDeserialize + Clone,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
+    {
+        let mut removed = Vec::new();
+        self.tree.root = self.tree.remove_range_at(
+            self.tree.root,
+            (range.start_bound(), range.end_bound()),
+            &mut removed,
+        );
+        let count = removed.len() as u64;
+        for key in removed {
+            // Set instead of remove to avoid loading the value from storage.
+            self.values.set(key, None);
+        }
+        count
+    }
+
     /// Gets the given key's corresponding entry in the map for in-place manipulation.
     /// ```
     /// use near_sdk::store::TreeMap;
@@ -1292,6 +1519,46 @@ mod tests {
         map.clear();
     }
 
+    #[test]
+    fn test_navigation_keys() {
+        let mut map: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
+        map.extend([10, 20, 30, 40, 50].map(|x| (x, 1)));
+
+        // Present key.
+        assert_eq!(map.floor_key(&30), Some(&30));
+        assert_eq!(map.ceil_key(&30), Some(&30));
+        assert_eq!(map.lower_key(&30), Some(&20));
+        assert_eq!(map.higher_key(&30), Some(&40));
+
+        // Absent key between two keys.
+        assert_eq!(map.floor_key(&35), Some(&30));
+        assert_eq!(map.ceil_key(&35), Some(&40));
+        assert_eq!(map.lower_key(&35), Some(&30));
+        assert_eq!(map.higher_key(&35), Some(&40));
+
+        // Below the minimum.
+        assert_eq!(map.floor_key(&5), None);
+        assert_eq!(map.ceil_key(&5), Some(&10));
+        assert_eq!(map.lower_key(&5), None);
+        assert_eq!(map.higher_key(&5), Some(&10));
+
+        // Above the maximum.
+        assert_eq!(map.floor_key(&55), Some(&50));
+        assert_eq!(map.ceil_key(&55), None);
+        assert_eq!(map.lower_key(&55), Some(&50));
+        assert_eq!(map.higher_key(&55), None);
+
+        // Flushed and reloaded from storage.
+        map.flush();
+        let map: TreeMap<u32, u32> = borsh::from_slice(&borsh::to_vec(&map).unwrap()).unwrap();
+        assert_eq!(map.floor_key(&35), Some(&30));
+        assert_eq!(map.higher_key(&50), None);
+
+        let empty: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
+        assert_eq!(empty.floor_key(&1), None);
+        assert_eq!(empty.ceil_key(&1), None);
+    }
+
     #[test]
     fn test_remove_1() {
         let mut map: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
@@ -1599,6 +1866,109 @@ mod tests {
         assert_eq!(map.remove(&1), None);
     }
 
+    /// Check that the height stored at every node matches the actual height of its subtree.
+    fn check_heights<K, V, H>(map: &TreeMap<K, V, H>, at: Option<FreeListIndex>) -> u32
+    where
+        K: Ord + Clone + BorshSerialize + BorshDeserialize,
+        V: BorshSerialize + BorshDeserialize,
+        H: ToKey,
+    {
+        at.map(|id| {
+            let node = map.tree.node(id).unwrap();
+            let ht = 1 + check_heights(map, node.lft).max(check_heights(map, node.rgt));
+            assert_eq!(node.ht, ht);
+            ht
+        })
+        .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_range_remove() {
+        setup_free();
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(3);
+        for _ in 0..50 {
+            let mut map: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
+            let mut expected = BTreeMap::new();
+            for _ in 0..(rng.next_u32() % 200) {
+                let k = rng.next_u32() % 300;
+                map.insert(k, k);
+                expected.insert(k, k);
+            }
+
+            let (a, b) = (rng.next_u32() % 320, rng.next_u32() % 320);
+            let (start, end) = (a.min(b), a.max(b));
+            let bounds = match rng.next_u32() % 4 {
+                0 => (Bound::Included(start), Bound::Excluded(end)),
+                1 => (Bound::Excluded(start), Bound::Included(end)),
+                2 => (Bound::Unbounded, Bound::Included(end)),
+                _ => (Bound::Included(start), Bound::Unbounded),
+            };
+            let count = map.range_remove(bounds);
+            let removed: Vec<u32> = expected.range(bounds).map(|(k, _)| *k).collect();
+            expected.retain(|k, _| !removed.contains(k));
+
+            assert_eq!(count, removed.len() as u64);
+            assert_eq!(map.len() as usize, expected.len());
+            assert!(map.iter().eq(expected.iter()));
+            assert!(removed.iter().all(|k| map.get(k).is_none()));
+            if let Some(root) = map.tree.root {
+                assert!(is_balanced(&map, root));
+            }
+            check_heights(&map, map.tree.root);
+        }
+    }
+
+    #[test]
+    fn test_range_remove_edge_cases() {
+        let mut map: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
+        assert_eq!(map.range_remove(..), 0);
+
+        map.extend((0..10).map(|k| (k, k)));
+        assert_eq!(map.range_remove(5..5), 0);
+        assert_eq!(map.range_remove((Bound::Excluded(5), Bound::Excluded(6))), 0);
+        assert_eq!(map.range_remove((Bound::Included(7), Bound::Included(3))), 0);
+        assert_eq!(map.range_remove(20..), 0);
+        assert_eq!(map.len(), 10);
+
+        assert_eq!(map.range_remove(..), 10);
+        assert!(map.is_empty());
+        assert_eq!(map.tree.root, None);
+        assert_eq!(map.iter().next(), None);
+
+        // The map is usable again after being emptied.
+        map.insert(1, 1);
+        assert_eq!(map.range_remove(1..=1), 1);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_range_remove_large_stays_balanced() {
+        setup_free();
+        let mut map: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
+        map.extend((0..1000).map(|k| (k, k)));
+
+        assert_eq!(map.range_remove(..900), 900);
+        assert_eq!(map.len(), 100);
+        assert!(height(&map) <= max_tree_height(100));
+        assert!(is_balanced(&map, map.tree.root.unwrap()));
+        check_heights(&map, map.tree.root);
+        assert!(map.keys().copied().eq(900..1000));
+
+        // Prune the middle, leaving both ends.
+        assert_eq!(map.range_remove(910..990), 80);
+        assert!(height(&map) <= max_tree_height(20));
+        assert!(is_balanced(&map, map.tree.root.unwrap()));
+        check_heights(&map, map.tree.root);
+        assert!(map.keys().copied().eq((900..910).chain(990..1000)));
+
+        // The tree keeps working for further updates.
+        map.extend((0..50).map(|k| (k, k)));
+        assert_eq!(map.remove(&995), Some(995));
+        assert!(is_balanced(&map, map.tree.root.unwrap()));
+        check_heights(&map, map.tree.root);
+        assert_eq!(map.len(), 69);
+    }
+
     #[test]
     fn test_iter() {
         let mut map: TreeMap<u32, u32> = TreeMap::new(next_trie_id());
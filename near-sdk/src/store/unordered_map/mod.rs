@@ -438,6 +438,52 @@ where
         self.values.get(k).map(|v| &v.value)
     }
 
+    /// Returns a clone of the value corresponding to the key, or [`V::default()`](Default) if
+    /// the key is not in the map.
+    ///
+    /// Unlike `entry(k).or_default()`, this does not insert the default value, so nothing is
+    /// written to storage for an absent key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::UnorderedMap;
+    ///
+    /// let mut map: UnorderedMap<String, u64> = UnorderedMap::new(b"b");
+    /// map.insert("a".to_string(), 5);
+    /// assert_eq!(map.get_or_default("a"), 5);
+    /// assert_eq!(map.get_or_default("b"), 0);
+    /// ```
+    pub fn get_or_default<Q: ?Sized>(&self, k: &Q) -> V
+    where
+        K: Borrow<Q>,
+        Q: BorshSerialize + ToOwned<Owned = K>,
+        V: Default + Clone,
+    {
+        self.get(k).cloned().unwrap_or_default()
+    }
+
+    /// Returns a reference to the value corresponding to the key, or `default` if the key is not
+    /// in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::UnorderedMap;
+    ///
+    /// let mut map: UnorderedMap<String, u64> = UnorderedMap::new(b"b");
+    /// map.insert("a".to_string(), 5);
+    /// assert_eq!(*map.get_or("a", &0), 5);
+    /// assert_eq!(*map.get_or("b", &0), 0);
+    /// ```
+    pub fn get_or<'a, Q: ?Sized>(&'a self, k: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q>,
+        Q: BorshSerialize + ToOwned<Owned = K>,
+    {
+        self.get(k).unwrap_or(default)
+    }
+
     /// Returns a mutable reference to the value corresponding to the key.
     ///
     /// The key may be any borrowed form of the map's key type, but
@@ -471,6 +517,10 @@ where
     /// value is returned. The key is not updated, though; this matters for
     /// types that can be `==` without being identical.
     ///
+    /// The existing entry is always read from storage, since the map has to know whether `k` is
+    /// already tracked in its key vector. Unlike [`LookupMap`], there is therefore no cheaper
+    /// `set` that skips this read.
+    ///
     /// # Examples
     ///
     /// ```
@@ -629,6 +679,45 @@ where
     {
         Entry::new(self.values.entry(key), &mut self.keys)
     }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all pairs `(k, v)` for which `f(&k, &mut v)` returns `false`.
+    /// The elements are visited once each, in iteration order, and the predicate may modify the
+    /// values it keeps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::UnorderedMap;
+    ///
+    /// let mut map = UnorderedMap::new(b"m");
+    /// map.extend((0..8u8).map(|i| (i, i * 10)));
+    ///
+    /// // Keep only the elements with even keys, and double their values.
+    /// map.retain(|&k, v| {
+    ///     *v *= 2;
+    ///     k % 2 == 0
+    /// });
+    /// assert_eq!(map.len(), 4);
+    /// assert_eq!(map[&2], 40);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        K: BorshDeserialize + Clone,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let values = &mut self.values;
+        self.keys.retain(|key| {
+            let value =
+                values.get_mut(key).unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE));
+            let keep = f(key, &mut value.value);
+            if !keep {
+                values.remove(key);
+            }
+            keep
+        });
+    }
 }
 
 impl<K, V, H> UnorderedMap<K, V, H>
@@ -640,6 +729,25 @@ where
     /// Flushes the intermediate values of the map before this is called when the structure is
     /// [`Drop`]ed. This will write all modified values to storage but keep all cached values
     /// in memory.
+    ///
+    /// Changes are otherwise only written when the map is dropped, usually at the end of the
+    /// method when the contract state is. Flush explicitly when the entries have to be in
+    /// storage earlier in the same call, e.g. when they are read with
+    /// [`env::storage_read`](crate::env::storage_read).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::env;
+    /// use near_sdk::store::UnorderedMap;
+    ///
+    /// let mut map: UnorderedMap<u32, String> = UnorderedMap::new(b"m");
+    /// map.insert(1, "a".to_string());
+    ///
+    /// let storage_usage = env::storage_usage();
+    /// map.flush();
+    /// assert!(env::storage_usage() > storage_usage);
+    /// ```
     pub fn flush(&mut self) {
         self.keys.flush();
         self.values.flush();
@@ -694,6 +802,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::UnorderedMap;
+    use crate::env;
+    use crate::store::key::{Identity, ToKey};
     use crate::test_utils::test_env::setup_free;
     use arbitrary::{Arbitrary, Unstructured};
     use borsh::{to_vec, BorshDeserialize};
@@ -701,6 +811,36 @@ mod tests {
     use rand::SeedableRng;
     use std::collections::HashMap;
 
+    #[test]
+    fn flush_to_raw_storage() {
+        let mut map = UnorderedMap::<_, _, Identity>::with_hasher(b"m");
+        map.insert(5u8, 8u8);
+
+        // Values are stored under the map prefix followed by `m`, next to the index of the key.
+        let storage_key = Identity::to_key(b"mm", &5u8, &mut Vec::new());
+        assert_eq!(env::storage_read(&storage_key), None);
+
+        map.flush();
+        assert_eq!(env::storage_read(&storage_key), Some(to_vec(&(8u8, 0u32)).unwrap()));
+    }
+
+    #[test]
+    fn test_get_or_default() {
+        let mut map: UnorderedMap<u8, u64> = UnorderedMap::new(b"b");
+        map.insert(1, 5);
+
+        assert_eq!(map.get_or_default(&1), 5);
+        assert_eq!(*map.get_or(&1, &7), 5);
+        assert_eq!(map.get_or_default(&2), 0);
+        assert_eq!(*map.get_or(&2, &7), 7);
+
+        // Absent keys are not inserted, unlike with `entry(k).or_default()`.
+        map.flush();
+        assert_eq!(map.len(), 1);
+        let map: UnorderedMap<u8, u64> = UnorderedMap::new(b"b");
+        assert!(!map.values.contains_key(&2));
+    }
+
     #[test]
     fn basic_functionality() {
         let mut map = UnorderedMap::new(b"b");
@@ -867,4 +1007,37 @@ mod tests {
 
         insta::assert_snapshot!(format!("{:#?}", defs));
     }
+
+    #[test]
+    fn test_retain() {
+        let mut map = UnorderedMap::new(b"m");
+        map.extend((1..=6u8).map(|i| (i, i * 10)));
+
+        let mut visited = Vec::new();
+        map.retain(|&k, v| {
+            visited.push(k);
+            *v += 1;
+            k != 2 && k != 4
+        });
+        visited.sort();
+        assert_eq!(visited, [1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(map.len(), 4);
+        // Removed elements leave their slots empty, so the order of the rest is unchanged.
+        assert_eq!(
+            map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(1, 11), (3, 31), (5, 51), (6, 61)]
+        );
+        assert_eq!(map.get(&2), None);
+        assert_eq!(map.get(&4), None);
+
+        // Changes are persisted.
+        map.flush();
+        let mut map = UnorderedMap::<u8, u8>::try_from_slice(&to_vec(&map).unwrap()).unwrap();
+        assert_eq!(map[&6], 61);
+
+        map.retain(|_, _| false);
+        assert!(map.is_empty());
+        assert_eq!(map.iter().count(), 0);
+    }
 }
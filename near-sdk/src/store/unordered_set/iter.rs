@@ -362,3 +362,78 @@ where
         self.elements.next_back()
     }
 }
+
+/// A draining iterator for [`UnorderedSet`], yielding the elements matching a predicate.
+///
+/// This `struct` is created by the [`drain_filter`] method on [`UnorderedSet`].
+/// See its documentation for more.
+///
+/// [`drain_filter`]: UnorderedSet::drain_filter
+pub struct DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    set: &'a mut UnorderedSet<T, H>,
+    pred: F,
+    /// Index of the next slot to check, including the ones left empty by removals.
+    slot_index: u32,
+}
+
+impl<'a, T, H, F> DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    pub(crate) fn new(set: &'a mut UnorderedSet<T, H>, pred: F) -> Self {
+        Self { set, pred, slot_index: 0 }
+    }
+}
+
+impl<'a, T, H, F> Iterator for DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.slot_index < self.set.elements.slots_len() {
+            let index = FreeListIndex(self.slot_index);
+            self.slot_index += 1;
+            if matches!(self.set.elements.get(index), Some(element) if (self.pred)(element)) {
+                // Removing from the free list leaves an empty slot, other elements don't move.
+                let element = self.set.elements.remove(index)?;
+                self.set.index.remove(&element);
+                return Some(element);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.set.len() as usize))
+    }
+}
+
+impl<'a, T, H, F> FusedIterator for DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+}
+
+impl<'a, T, H, F> Drop for DrainFilter<'a, T, H, F>
+where
+    T: BorshSerialize + BorshDeserialize + Ord + Clone,
+    H: ToKey,
+    F: FnMut(&T) -> bool,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
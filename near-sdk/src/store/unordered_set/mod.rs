@@ -4,7 +4,9 @@
 mod impls;
 mod iter;
 
-pub use self::iter::{Difference, Drain, Intersection, Iter, SymmetricDifference, Union};
+pub use self::iter::{
+    Difference, Drain, DrainFilter, Intersection, Iter, SymmetricDifference, Union,
+};
 use super::{FreeList, LookupMap, ERR_INCONSISTENT_STATE};
 use crate::store::free_list::FreeListIndex;
 use crate::store::key::{Sha256, ToKey};
@@ -445,6 +447,38 @@ where
         Iter::new(self)
     }
 
+    /// An iterator visiting the elements of the set starting at `start_index`, in the same order
+    /// as [`iter`](Self::iter).
+    ///
+    /// Unlike `iter().skip(start_index)`, the elements before `start_index` are not loaded, so
+    /// this can be used to page through the set at a constant cost per page.
+    ///
+    /// `start_index` is an index in the vector backing the set, which keeps a hole for every
+    /// removed element until it is reused by an insert or the set is [`defrag`](Self::defrag)ged.
+    /// The index is therefore only equal to the number of elements skipped as long as no element
+    /// was removed, and a page boundary can move between calls when elements are removed or
+    /// inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::UnorderedSet;
+    ///
+    /// let mut set = UnorderedSet::new(b"m");
+    /// for i in 0..10u32 {
+    ///     set.insert(i);
+    /// }
+    ///
+    /// let page: Vec<_> = set.iter_from(4).take(3).collect();
+    /// assert_eq!(page, set.iter().skip(4).take(3).collect::<Vec<_>>());
+    /// ```
+    pub fn iter_from(&self, start_index: u32) -> impl Iterator<Item = &T>
+    where
+        T: BorshDeserialize,
+    {
+        self.elements.iter_from(start_index)
+    }
+
     /// Clears the set, returning all elements in an iterator.
     ///
     /// # Examples
@@ -469,6 +503,34 @@ where
         Drain::new(self)
     }
 
+    /// Removes the elements for which `pred` returns `true` and returns them in an iterator.
+    ///
+    /// The predicate is called once for each element, in iteration order. If the iterator is
+    /// dropped before being fully consumed, the remaining matching elements are still removed.
+    ///
+    /// Like [`remove`](Self::remove), removals leave empty placeholders behind, so the order of
+    /// the remaining elements is unchanged. Use [`defrag`](Self::defrag) to remove them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::UnorderedSet;
+    ///
+    /// let mut set = UnorderedSet::new(b"m");
+    /// set.extend(0..8u8);
+    ///
+    /// let odd: Vec<u8> = set.drain_filter(|v| v % 2 == 1).collect();
+    /// assert_eq!(odd, [1, 3, 5, 7]);
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [0, 2, 4, 6]);
+    /// ```
+    pub fn drain_filter<F>(&mut self, pred: F) -> DrainFilter<T, H, F>
+    where
+        T: BorshDeserialize + Clone,
+        F: FnMut(&T) -> bool,
+    {
+        DrainFilter::new(self, pred)
+    }
+
     /// Returns `true` if the set contains the specified value.
     ///
     /// The value may be any borrowed form of the set's value type, but
@@ -627,6 +689,21 @@ mod tests {
         assert!(set.is_empty());
     }
 
+    #[test]
+    fn test_iter_from() {
+        let mut set = UnorderedSet::new(b"b");
+        set.extend(0..20u32);
+
+        for k in 0..=21 {
+            assert!(set.iter_from(k).eq(set.iter().skip(k as usize)));
+        }
+
+        // Holes left by removals are skipped, but still count towards the start index.
+        set.remove(&3);
+        assert_eq!(set.iter_from(2).take(3).collect::<Vec<_>>(), [&2, &4, &5]);
+        assert_eq!(set.iter_from(3).next(), Some(&4));
+    }
+
     #[test]
     fn test_drain() {
         let mut s = UnorderedSet::new(b"m");
@@ -650,6 +727,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_drain_filter() {
+        setup_free();
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(5);
+        let mut set = UnorderedSet::new(b"s");
+        let mut expected = UnorderedSet::new(b"e");
+        for _ in 0..200 {
+            let value = rng.next_u32() % 500;
+            set.insert(value);
+            expected.insert(value);
+        }
+
+        // Manual two-pass removal.
+        let matching: Vec<u32> = expected.iter().filter(|v| *v % 3 == 0).copied().collect();
+        for value in &matching {
+            expected.remove(value);
+        }
+
+        let mut drained: Vec<u32> = set.drain_filter(|v| v % 3 == 0).collect();
+        drained.sort();
+        let mut matching = matching;
+        matching.sort();
+        assert_eq!(drained, matching);
+        assert_eq!(set.len(), expected.len());
+        assert_eq!(
+            set.iter().copied().collect::<HashSet<_>>(),
+            expected.iter().copied().collect::<HashSet<_>>()
+        );
+        assert!(drained.iter().all(|v| !set.contains(v)));
+    }
+
+    #[test]
+    fn test_drain_filter_dropped_early() {
+        let mut set = UnorderedSet::new(b"s");
+        set.extend(0..20u32);
+
+        let mut drain = set.drain_filter(|v| v % 2 == 0);
+        assert!(drain.next().is_some());
+        drop(drain);
+
+        assert_eq!(set.len(), 10);
+        assert!(set.iter().all(|v| v % 2 == 1));
+        assert_eq!(set.drain_filter(|_| false).count(), 0);
+        assert_eq!(set.drain_filter(|_| true).count(), 10);
+        assert!(set.is_empty());
+    }
+
     #[test]
     fn test_extend() {
         let mut a = UnorderedSet::<u64>::new(b"m");
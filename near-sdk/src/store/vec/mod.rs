@@ -56,6 +56,7 @@ mod impls;
 mod iter;
 
 use std::{
+    cmp::Ordering,
     fmt,
     ops::{Bound, Range, RangeBounds},
 };
@@ -304,6 +305,96 @@ where
         self.values.get(index)
     }
 
+    /// Returns references to the elements in the given `range` of indices. The range is clamped
+    /// to the length of the vector, so a range extending past the end will only return the
+    /// elements that exist and a range starting past the end will return an empty [`Vec`].
+    ///
+    /// This is useful for paginated views over the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Vector;
+    ///
+    /// let mut vec = Vector::new(b"v");
+    /// vec.extend([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(vec.get_range(1..3), [&2, &3]);
+    /// assert_eq!(vec.get_range(2..10), [&3, &4]);
+    /// assert!(vec.get_range(5..10).is_empty());
+    /// ```
+    pub fn get_range(&self, range: Range<u32>) -> Vec<&T> {
+        let end = core::cmp::min(range.end, self.len());
+        (range.start..end)
+            .map(|index| {
+                self.values.get(index).unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE))
+            })
+            .collect()
+    }
+
+    /// Binary searches this sorted vector with a comparator function, like
+    /// [`slice::binary_search_by`].
+    ///
+    /// The comparator should return the order of the element it is given relative to the
+    /// target. Only the elements probed by the search are loaded from storage, so a lookup reads
+    /// `O(log n)` elements.
+    ///
+    /// If a matching element is found, returns [`Ok`] with its index; if several elements match,
+    /// any one of them may be returned. Otherwise returns [`Err`] with the index where a matching
+    /// element could be inserted while keeping the vector sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Vector;
+    ///
+    /// let mut vec = Vector::new(b"v");
+    /// vec.extend([(1, 100u64), (3, 300), (5, 500)]);
+    ///
+    /// assert_eq!(vec.binary_search_by(|(key, _)| key.cmp(&3)), Ok(1));
+    /// assert_eq!(vec.binary_search_by(|(key, _)| key.cmp(&4)), Err(2));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<u32, u32>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let element =
+                self.values.get(mid).unwrap_or_else(|| env::panic_str(ERR_INCONSISTENT_STATE));
+            match f(element) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Binary searches this sorted vector for `x`, like [`slice::binary_search`]. See
+    /// [`binary_search_by`](Self::binary_search_by) for the meaning of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::store::Vector;
+    ///
+    /// let mut vec = Vector::new(b"v");
+    /// vec.extend([1, 3, 5, 7]);
+    ///
+    /// assert_eq!(vec.binary_search(&5), Ok(2));
+    /// assert_eq!(vec.binary_search(&0), Err(0));
+    /// assert_eq!(vec.binary_search(&8), Err(4));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<u32, u32>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|element| element.cmp(x))
+    }
+
     /// Returns a mutable reference to the element at the `index` provided.
     ///
     /// # Examples
@@ -559,6 +650,54 @@ mod tests {
         vec.set(2, 0);
     }
 
+    #[test]
+    fn test_get_range() {
+        let mut vec = Vector::new(b"b");
+        vec.extend([0u8, 1, 2, 3, 4]);
+
+        assert_eq!(vec.get_range(1..4), [&1, &2, &3]);
+        assert_eq!(vec.get_range(0..5), vec.iter().collect::<Vec<_>>());
+        // Partially out of range requests are clamped to the length.
+        assert_eq!(vec.get_range(3..100), [&3, &4]);
+        // Fully out of range and empty requests return nothing.
+        assert!(vec.get_range(5..10).is_empty());
+        assert!(vec.get_range(100..u32::MAX).is_empty());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = vec.get_range(4..2);
+        assert!(reversed.is_empty());
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let mut vec = Vector::new(b"b");
+        assert_eq!(vec.binary_search(&1), Err(0));
+
+        vec.extend([1u32, 3, 3, 5, 8, 13]);
+        assert_eq!(vec.binary_search(&1), Ok(0));
+        assert_eq!(vec.binary_search(&13), Ok(5));
+        assert_eq!(vec.binary_search(&5), Ok(3));
+        assert!(matches!(vec.binary_search(&3), Ok(1 | 2)));
+
+        // Missing values report the index that keeps the vector sorted.
+        assert_eq!(vec.binary_search(&0), Err(0));
+        assert_eq!(vec.binary_search(&4), Err(3));
+        assert_eq!(vec.binary_search(&9), Err(5));
+        assert_eq!(vec.binary_search(&100), Err(6));
+
+        // Matches the slice implementation on data loaded back from storage.
+        vec.flush();
+        let sorted: Vec<u32> = vec.iter().copied().collect();
+        let vec = Vector::<u32>::try_from_slice(&to_vec(&vec).unwrap()).unwrap();
+        for x in 0..15 {
+            let expected = sorted.binary_search(&x).map(|i| i as u32).map_err(|i| i as u32);
+            let actual = vec.binary_search_by(|element| element.cmp(&x));
+            assert_eq!(actual.is_ok(), expected.is_ok());
+            if actual.is_err() {
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
     #[test]
     fn test_get_mut_none() {
         let mut vec: Vector<bool> = Vector::new(b"b");
@@ -848,6 +987,37 @@ mod tests {
         crate::mock::with_mocked_blockchain(|m| assert!(m.take_storage().is_empty()));
     }
 
+    #[test]
+    fn drain_edge_cases() {
+        let mut vec = Vector::new(b"v");
+        let mut baseline = vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        vec.extend(baseline.clone());
+
+        // Empty range leaves the vector untouched.
+        assert!(Iterator::eq(vec.drain(3..3), baseline.drain(3..3)));
+        assert!(Iterator::eq(vec.iter(), baseline.iter()));
+
+        // Draining a prefix shifts the tail down.
+        assert!(Iterator::eq(vec.drain(..4), baseline.drain(..4)));
+        assert!(Iterator::eq(vec.iter(), baseline.iter()));
+
+        // Draining to the end doesn't need a shift.
+        assert!(Iterator::eq(vec.drain(3..), baseline.drain(3..)));
+        assert!(Iterator::eq(vec.iter(), baseline.iter()));
+        // Freed slots are released.
+        vec.flush();
+        crate::mock::with_mocked_blockchain(|m| {
+            assert_eq!(m.take_storage().len(), baseline.len());
+        });
+
+        // Unlike `Vec::drain`, out of bounds ranges are clamped to the length of the vector.
+        let mut vec = Vector::new(b"w");
+        vec.extend([0u8, 1, 2]);
+        assert_eq!(vec.drain(1..10).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(vec.drain(5..10).count(), 0);
+        assert_eq!(vec.iter().copied().collect::<Vec<_>>(), [0]);
+    }
+
     #[test]
     fn test_indexing() {
         let mut v: Vector<i32> = Vector::new(b"b");
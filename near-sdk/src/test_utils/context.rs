@@ -174,6 +174,19 @@ impl VMContextBuilder {
         self
     }
 
+    /// Sets the raw input of the call, as read by [`env::input`](crate::env::input).
+    pub fn input(&mut self, bytes: Vec<u8>) -> &mut Self {
+        self.context.input = bytes;
+        self
+    }
+
+    /// Sets the input of the call to `value` serialized as JSON, as read by
+    /// [`env::input_json`](crate::env::input_json).
+    pub fn input_json<T: serde::Serialize>(&mut self, value: &T) -> &mut Self {
+        self.context.input = serde_json::to_vec(value).expect("Failed to serialize input to JSON.");
+        self
+    }
+
     pub fn is_view(&mut self, is_view: bool) -> &mut Self {
         self.context.view_config =
             if is_view { Some(ViewConfig { max_gas_burnt: 200000000000000 }) } else { None };
@@ -93,11 +93,47 @@ pub fn get_logs() -> Vec<String> {
     crate::mock::with_mocked_blockchain(|b| b.logs())
 }
 
+/// Returns the [NEP-297](https://nomicon.io/Standards/EventsFormat) events logged so far, i.e.
+/// the logs prefixed with `EVENT_JSON:` parsed as JSON. Other logs are skipped.
+/// Only available in unit tests.
+///
+/// # Panics
+/// Panics if the payload of an `EVENT_JSON:` log is not valid JSON.
+pub fn get_events() -> Vec<serde_json::Value> {
+    get_logs()
+        .iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .map(|event| {
+            serde_json::from_str(event)
+                .unwrap_or_else(|e| panic!("Invalid event JSON `{}`: {}", event, e))
+        })
+        .collect()
+}
+
 /// Accessing receipts created by the contract. Only available in unit tests.
 pub fn get_created_receipts() -> Vec<Receipt> {
     crate::mock::with_mocked_blockchain(|b| b.created_receipts())
 }
 
+/// Returns the bytes passed to the last [`env::value_return`](crate::env::value_return) call,
+/// i.e. the serialized result of a contract method. Only available in unit tests.
+pub fn get_return_value() -> Option<Vec<u8>> {
+    crate::mock::with_mocked_blockchain(|b| b.return_value())
+}
+
+/// Same as [`get_return_value`], but deserializes the returned value from JSON. Only available
+/// in unit tests.
+///
+/// # Panics
+///
+/// Panics if the returned value is not a valid JSON representation of `T`.
+pub fn get_return_json<T: serde::de::DeserializeOwned>() -> Option<T> {
+    get_return_value().map(|value| {
+        serde_json::from_slice(&value)
+            .unwrap_or_else(|err| panic!("returned value is not valid JSON: {}", err))
+    })
+}
+
 /// Objects stored on the trie directly should have identifiers. If identifier is not provided
 /// explicitly than `Default` trait would use this index to generate an id.
 #[cfg(test)]
@@ -111,3 +147,22 @@ pub(crate) fn next_trie_id() -> Vec<u8> {
         id.to_le_bytes().to_vec()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env;
+
+    #[test]
+    fn return_value() {
+        testing_env!(VMContextBuilder::new().build());
+        assert_eq!(get_return_value(), None);
+
+        // Equivalent of the generated wrapper of a view method returning `Vec<u64>`.
+        let result: Vec<u64> = vec![1, 2, 3];
+        env::value_return(&serde_json::to_vec(&result).unwrap());
+
+        assert_eq!(get_return_value(), Some(b"[1,2,3]".to_vec()));
+        assert_eq!(get_return_json::<Vec<u64>>(), Some(result));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::types::{AccountId, AccountIdRef};
+
+/// Extension trait for navigating the [`AccountId`] hierarchy.
+///
+/// Named accounts form a tree: `app.alice.near` is created by `alice.near`, which is in turn
+/// created by `near`. These helpers avoid splitting and formatting account ids by hand when
+/// deriving or validating subaccounts, e.g. in factory contracts.
+///
+/// [`split_first_label`](Self::split_first_label) complements [`parent`](Self::parent) by working
+/// on borrowed data only, so it can be used in hot loops without allocating. The length and raw
+/// bytes are available through [`AccountIdRef::len`] and [`AccountIdRef::as_bytes`].
+///
+/// # Examples
+/// ```
+/// use near_sdk::{AccountId, AccountIdExt, AccountIdRef};
+///
+/// let app: AccountId = "app.alice.near".parse().unwrap();
+/// assert_eq!(app.parent(), Some("alice.near".parse().unwrap()));
+/// assert!(app.is_subaccount_of(AccountIdRef::new_or_panic("near")));
+///
+/// let near: AccountId = "near".parse().unwrap();
+/// assert_eq!(near.parent(), None);
+///
+/// let (label, rest) = app.split_first_label();
+/// assert_eq!(label, "app");
+/// assert_eq!(rest, Some(AccountIdRef::new_or_panic("alice.near")));
+/// ```
+pub trait AccountIdExt: seal::AccountIdExtSeal {
+    /// Returns the account id without its leftmost label, or [`None`] for top-level and implicit
+    /// accounts.
+    fn parent(&self) -> Option<AccountId>;
+
+    /// Returns `true` if this account is a subaccount of `other` at any depth, e.g. both
+    /// `alice.near` and `app.alice.near` are subaccounts of `near`. An account is not a
+    /// subaccount of itself.
+    ///
+    /// Use [`AccountIdRef::is_sub_account_of`] to only match direct subaccounts.
+    fn is_subaccount_of(&self, other: &AccountIdRef) -> bool;
+
+    /// Splits off the leftmost label, returning it together with the rest of the account id,
+    /// which is [`None`] for top-level and implicit accounts.
+    ///
+    /// This is the borrowing counterpart of [`parent`](Self::parent).
+    fn split_first_label(&self) -> (&str, Option<&AccountIdRef>);
+}
+
+impl AccountIdExt for AccountIdRef {
+    fn parent(&self) -> Option<AccountId> {
+        self.get_parent_account_id().map(ToOwned::to_owned)
+    }
+
+    fn is_subaccount_of(&self, other: &AccountIdRef) -> bool {
+        self.as_str()
+            .strip_suffix(other.as_str())
+            .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.'))
+    }
+
+    fn split_first_label(&self) -> (&str, Option<&AccountIdRef>) {
+        match self.get_parent_account_id() {
+            Some(parent) => (&self.as_str()[..self.len() - parent.len() - 1], Some(parent)),
+            None => (self.as_str(), None),
+        }
+    }
+}
+
+// This is the "sealed trait" pattern:
+// https://rust-lang.github.io/api-guidelines/future-proofing.html
+mod seal {
+    pub trait AccountIdExtSeal {}
+
+    impl AccountIdExtSeal for super::AccountIdRef {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccountIdExt;
+    use crate::{AccountId, AccountIdRef};
+
+    fn id(account_id: &str) -> AccountId {
+        account_id.parse().unwrap()
+    }
+
+    #[test]
+    fn parent() {
+        assert_eq!(id("alice.near").parent(), Some(id("near")));
+        assert_eq!(id("c.b.a.alice.near").parent(), Some(id("b.a.alice.near")));
+        assert_eq!(id("near").parent(), None);
+        assert_eq!(id("system").parent(), None);
+        assert_eq!(
+            id("248e104d1d4764d713c4211c13808c8fc887869c580f4178e60538ac5c2a0b26").parent(),
+            None
+        );
+    }
+
+    #[test]
+    fn is_subaccount_of() {
+        let near = AccountIdRef::new_or_panic("near");
+        let alice = AccountIdRef::new_or_panic("alice.near");
+
+        assert!(id("alice.near").is_subaccount_of(near));
+        assert!(id("c.b.a.alice.near").is_subaccount_of(near));
+        assert!(id("c.b.a.alice.near").is_subaccount_of(alice));
+        assert!(!id("near").is_subaccount_of(near));
+        assert!(!id("alice.near").is_subaccount_of(alice));
+        assert!(!id("malice.near").is_subaccount_of(alice));
+        assert!(!id("alice.near").is_subaccount_of(AccountIdRef::new_or_panic("testnet")));
+        assert!(!id("248e104d1d4764d713c4211c13808c8fc887869c580f4178e60538ac5c2a0b26")
+            .is_subaccount_of(near));
+    }
+
+    #[test]
+    fn split_first_label() {
+        let app = id("app.alice.near");
+        let (label, rest) = app.split_first_label();
+        assert_eq!(label, "app");
+        let rest = rest.unwrap();
+        assert_eq!(rest, "alice.near");
+        assert_eq!(rest.split_first_label(), ("alice", Some(AccountIdRef::new_or_panic("near"))));
+        assert_eq!(id("near").split_first_label(), ("near", None));
+
+        let implicit = id("248e104d1d4764d713c4211c13808c8fc887869c580f4178e60538ac5c2a0b26");
+        assert_eq!(implicit.split_first_label(), (implicit.as_str(), None));
+        let eth_implicit = id("0xb794f5ea0ba39494ce839613fffba74279579268");
+        assert_eq!(eth_implicit.split_first_label(), (eth_implicit.as_str(), None));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::env;
+use crate::types::{Gas, NearToken};
+
+/// Extension trait for subtracting [`NearToken`] and [`Gas`] amounts without losing the context
+/// of why the subtraction failed.
+///
+/// `checked_sub` on these types returns a bare [`Option`], so callers end up re-adding the same
+/// error on every use. These helpers attach the error directly.
+///
+/// # Examples
+/// ```
+/// use near_sdk::{CheckedSubExt, NearToken};
+///
+/// let balance = NearToken::from_near(5);
+///
+/// let rest = balance.sub_or(NearToken::from_near(2), "insufficient balance").unwrap();
+/// assert_eq!(rest, NearToken::from_near(3));
+///
+/// let err = balance.sub_or(NearToken::from_near(6), "insufficient balance").unwrap_err();
+/// assert_eq!(err, "insufficient balance");
+///
+/// assert_eq!(balance.require_sub(NearToken::from_near(5), "insufficient balance"), NearToken::from_near(0));
+/// ```
+pub trait CheckedSubExt: Sized + seal::CheckedSubExtSeal {
+    /// Subtracts `rhs`, returning `err` if the result would underflow.
+    fn sub_or<E>(self, rhs: Self, err: E) -> Result<Self, E>;
+
+    /// Subtracts `rhs`, panicking through [`env::panic_str`] with `message` if the result would
+    /// underflow.
+    fn require_sub(self, rhs: Self, message: &str) -> Self {
+        self.sub_or(rhs, ()).unwrap_or_else(|()| env::panic_str(message))
+    }
+}
+
+impl CheckedSubExt for NearToken {
+    fn sub_or<E>(self, rhs: Self, err: E) -> Result<Self, E> {
+        self.checked_sub(rhs).ok_or(err)
+    }
+}
+
+impl CheckedSubExt for Gas {
+    fn sub_or<E>(self, rhs: Self, err: E) -> Result<Self, E> {
+        self.checked_sub(rhs).ok_or(err)
+    }
+}
+
+// This is the "sealed trait" pattern:
+// https://rust-lang.github.io/api-guidelines/future-proofing.html
+mod seal {
+    pub trait CheckedSubExtSeal {}
+
+    impl CheckedSubExtSeal for super::NearToken {}
+    impl CheckedSubExtSeal for super::Gas {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CheckedSubExt;
+    use crate::{Gas, NearToken};
+
+    #[test]
+    fn sub_or() {
+        assert_eq!(
+            NearToken::from_yoctonear(10).sub_or(NearToken::from_yoctonear(4), "err"),
+            Ok(NearToken::from_yoctonear(6))
+        );
+        assert_eq!(
+            NearToken::from_yoctonear(10).sub_or(NearToken::from_yoctonear(11), "err"),
+            Err("err")
+        );
+        assert_eq!(Gas::from_tgas(10).sub_or(Gas::from_tgas(10), "err"), Ok(Gas::from_gas(0)));
+        assert_eq!(Gas::from_gas(0).sub_or(Gas::from_gas(1), 7u8), Err(7));
+    }
+
+    #[test]
+    fn require_sub() {
+        assert_eq!(
+            NearToken::from_near(2).require_sub(NearToken::from_near(1), "insufficient balance"),
+            NearToken::from_near(1)
+        );
+        assert_eq!(
+            Gas::from_tgas(5).require_sub(Gas::from_tgas(2), "out of gas"),
+            Gas::from_tgas(3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient balance")]
+    fn require_sub_insufficient() {
+        NearToken::from_near(1).require_sub(NearToken::from_near(2), "insufficient balance");
+    }
+}
@@ -0,0 +1,106 @@
+use crate::types::Gas;
+
+/// Nominal amount of gas burnt per nanosecond of compute: 1 Tgas corresponds to roughly 1 ms.
+const NOMINAL_GAS_PER_NANOSECOND: u64 = 1_000_000;
+
+/// Extension trait for presenting [`Gas`] amounts in human terms in off-chain tooling, such as
+/// simulators and CLIs.
+///
+/// The compute time estimates are based on the nominal protocol rate of 1 Tgas ≈ 1 ms of
+/// compute. The actual time spent executing a receipt varies with the hardware and with the
+/// kind of operations that burnt the gas, so these values are only approximations and should
+/// not be relied upon inside contracts.
+///
+/// # Examples
+/// ```
+/// use near_sdk::{Gas, GasExt};
+/// use std::time::Duration;
+///
+/// assert_eq!(Gas::from_tgas(5).as_teragas_f64(), 5.0);
+/// assert_eq!(Gas::from_teragas_f64(0.5), Gas::from_ggas(500));
+/// assert_eq!(Gas::from_tgas(300).estimated_compute_time(), Duration::from_millis(300));
+/// ```
+pub trait GasExt: Sized + seal::GasExtSeal {
+    /// Returns the amount of gas in teragas, including the fractional part.
+    fn as_teragas_f64(&self) -> f64;
+
+    /// Converts an amount of teragas into [`Gas`], rounding to the nearest unit. Negative and
+    /// NaN values become zero, and values that are too large saturate.
+    fn from_teragas_f64(tgas: f64) -> Self;
+
+    /// Returns the approximate compute time it takes to burn this amount of gas.
+    fn estimated_compute_time(self) -> std::time::Duration;
+
+    /// Returns the approximate amount of gas burnt by `duration` of compute, saturating on
+    /// overflow.
+    fn from_estimated_compute_time(duration: std::time::Duration) -> Self;
+}
+
+impl GasExt for Gas {
+    fn as_teragas_f64(&self) -> f64 {
+        self.as_gas() as f64 / Gas::from_tgas(1).as_gas() as f64
+    }
+
+    fn from_teragas_f64(tgas: f64) -> Self {
+        // `as` casts from float saturate and map NaN to zero.
+        Gas::from_gas((tgas * Gas::from_tgas(1).as_gas() as f64).round() as u64)
+    }
+
+    fn estimated_compute_time(self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.as_gas() / NOMINAL_GAS_PER_NANOSECOND)
+    }
+
+    fn from_estimated_compute_time(duration: std::time::Duration) -> Self {
+        let gas = duration.as_nanos().saturating_mul(NOMINAL_GAS_PER_NANOSECOND.into());
+        Gas::from_gas(u64::try_from(gas).unwrap_or(u64::MAX))
+    }
+}
+
+// This is the "sealed trait" pattern:
+// https://rust-lang.github.io/api-guidelines/future-proofing.html
+mod seal {
+    pub trait GasExtSeal {}
+
+    impl GasExtSeal for super::Gas {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GasExt;
+    use crate::Gas;
+    use std::time::Duration;
+
+    #[test]
+    fn teragas_f64() {
+        assert_eq!(Gas::from_tgas(1).as_teragas_f64(), 1.0);
+        assert_eq!(Gas::from_gas(0).as_teragas_f64(), 0.0);
+        assert_eq!(Gas::from_ggas(2_500).as_teragas_f64(), 2.5);
+
+        for gas in
+            [Gas::from_gas(1), Gas::from_ggas(123), Gas::from_tgas(300), Gas::from_tgas(1_000_000)]
+        {
+            let round_trip = Gas::from_teragas_f64(gas.as_teragas_f64());
+            assert!(round_trip.as_gas().abs_diff(gas.as_gas()) <= gas.as_gas() / 1_000_000_000);
+        }
+
+        assert_eq!(Gas::from_teragas_f64(-1.0), Gas::from_gas(0));
+        assert_eq!(Gas::from_teragas_f64(f64::NAN), Gas::from_gas(0));
+        assert_eq!(Gas::from_teragas_f64(f64::INFINITY), Gas::from_gas(u64::MAX));
+    }
+
+    #[test]
+    fn estimated_compute_time() {
+        assert_eq!(Gas::from_tgas(1).estimated_compute_time(), Duration::from_millis(1));
+        assert_eq!(Gas::from_ggas(1).estimated_compute_time(), Duration::from_micros(1));
+        assert_eq!(
+            Gas::from_estimated_compute_time(Duration::from_millis(300)),
+            Gas::from_tgas(300)
+        );
+
+        let gas = Gas::from_gas(123_456_789_012);
+        let round_trip = Gas::from_estimated_compute_time(gas.estimated_compute_time());
+        assert!(gas.as_gas() - round_trip.as_gas() < 1_000_000);
+
+        assert_eq!(Gas::from_estimated_compute_time(Duration::MAX), Gas::from_gas(u64::MAX));
+    }
+}
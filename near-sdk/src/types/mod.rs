@@ -7,7 +7,11 @@ pub use self::public_key::{CurveType, PublicKey};
 mod primitives;
 pub use self::primitives::*;
 
-pub use near_account_id::{AccountId, AccountIdRef};
+pub use near_account_id::{AccountId, AccountIdRef, ParseAccountError};
+
+mod account_id;
+pub use self::account_id::AccountIdExt;
+
 /// A wrapper struct for `u64` that represents gas. And provides helpful methods to convert to and from tera-gas and giga-gas.
 pub use near_gas::NearGas as Gas;
 /// A wrapper struct for `u128` that represents tokens. And provides helpful methods to convert with a proper precision.
@@ -17,6 +21,15 @@ mod error;
 pub use self::error::Abort;
 pub use self::error::FunctionError;
 
+mod checked_sub;
+pub use self::checked_sub::CheckedSubExt;
+
+mod gas;
+pub use self::gas::GasExt;
+
+mod token;
+pub use self::token::{NearTokenExt, ParseNearTokenError};
+
 /// Raw type for duration in nanoseconds
 pub type Duration = u64;
 
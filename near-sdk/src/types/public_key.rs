@@ -113,6 +113,21 @@ impl PublicKey {
         }
     }
 
+    /// Creates a public key from its curve and raw key data, without the curve prefix byte.
+    ///
+    /// Fails if `data` is not 32 bytes long for [`CurveType::ED25519`] or 64 bytes long for
+    /// [`CurveType::SECP256K1`].
+    ///
+    /// # Examples
+    /// ```
+    /// use near_sdk::{CurveType, PublicKey};
+    ///
+    /// let key = PublicKey::from_parts(CurveType::ED25519, vec![0; 32]).unwrap();
+    /// assert_eq!(key.curve_type(), CurveType::ED25519);
+    /// assert_eq!(key.key_data(), &[0; 32]);
+    ///
+    /// assert!(PublicKey::from_parts(CurveType::SECP256K1, vec![0; 32]).is_err());
+    /// ```
     pub fn from_parts(curve: CurveType, data: Vec<u8>) -> Result<Self, ParsePublicKeyError> {
         let expected_length = curve.data_len();
         if data.len() != expected_length {
@@ -141,6 +156,11 @@ impl PublicKey {
     pub fn curve_type(&self) -> CurveType {
         CurveType::from_u8(self.data[0]).unwrap_or_else(|_| crate::env::abort())
     }
+
+    /// Returns the raw key data, without the curve prefix byte.
+    pub fn key_data(&self) -> &[u8] {
+        &self.data[1..]
+    }
 }
 
 impl From<PublicKey> for Vec<u8> {
@@ -308,6 +328,32 @@ mod tests {
         assert_eq!(actual, "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp");
     }
 
+    #[test]
+    fn test_public_key_parts_round_trip() {
+        for key in [
+            "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+            "secp256k1:qMoRgcoXai4mBPsdbHi1wfyxF9TdbPCF4qSDQTRP3TfescSRoUdSx6nmeQoN3aiwGzwMyGXAb1gUjBTv5AY8DXj",
+        ] {
+            let key = PublicKey::from_str(key).unwrap();
+            let curve = key.curve_type();
+            assert_eq!(key.key_data().len(), curve.data_len());
+            assert_eq!(&key.as_bytes()[1..], key.key_data());
+
+            let rebuilt = PublicKey::from_parts(curve, key.key_data().to_vec()).unwrap();
+            assert_eq!(rebuilt, key);
+        }
+        assert_eq!(expected_key().curve_type(), CurveType::ED25519);
+    }
+
+    #[test]
+    fn test_public_key_from_parts_invalid_length() {
+        assert!(PublicKey::from_parts(CurveType::ED25519, vec![1; 31]).is_err());
+        assert!(PublicKey::from_parts(CurveType::ED25519, vec![1; 64]).is_err());
+        assert!(PublicKey::from_parts(CurveType::SECP256K1, vec![1; 32]).is_err());
+        assert!(PublicKey::from_parts(CurveType::SECP256K1, vec![1; 65]).is_err());
+        assert!(PublicKey::from_parts(CurveType::SECP256K1, Vec::new()).is_err());
+    }
+
     #[test]
     fn test_public_key_borsh_format_change() {
         // Original struct to reference Borsh serialization from
@@ -0,0 +1,267 @@
+use crate::types::NearToken;
+
+/// Number of decimal places of one NEAR in yoctoNEAR.
+const NEAR_DECIMALS: u8 = 24;
+
+/// Extension trait for rendering [`NearToken`] amounts for humans, for example in logs and
+/// off-chain tooling.
+///
+/// Amounts in sub-NEAR denominations can be created with [`NearToken::from_millinear`] and
+/// [`NearToken::from_micronear`].
+///
+/// # Examples
+/// ```
+/// use near_sdk::{NearToken, NearTokenExt};
+///
+/// let fee = NearToken::from_millinear(1_250);
+/// assert_eq!(fee.to_string_with_precision(2), "1.25 NEAR");
+/// assert_eq!(fee.to_string_with_precision(1), "1.3 NEAR");
+/// assert_eq!(NearToken::from_micronear(1).to_string_with_precision(3), "0.000 NEAR");
+///
+/// assert_eq!(NearToken::parse_amount("1.5 NEAR"), Ok(NearToken::from_millinear(1_500)));
+/// assert_eq!(NearToken::parse_amount("1500"), Ok(NearToken::from_yoctonear(1_500)));
+/// ```
+pub trait NearTokenExt: seal::NearTokenExtSeal {
+    /// Renders the amount in NEAR rounded half up to `decimals` decimal places, always showing
+    /// exactly that many places. Amounts smaller than the precision render as zero. Precision is
+    /// capped at 24 decimal places, which is exact.
+    fn to_string_with_precision(&self, decimals: u8) -> String;
+
+    /// Parses a user-provided amount, e.g. `"1.5 NEAR"`, `"2NEAR"`, `"100 yoctoNEAR"` or
+    /// `"1500000000000000000000000"`.
+    ///
+    /// Units are case-insensitive. Without a unit the amount is a plain integer in yoctoNEAR,
+    /// decimal amounts require the `NEAR` unit to not be ambiguous. Unlike the [`FromStr`]
+    /// implementation of [`NearToken`], which requires a unit, this returns a
+    /// [`ParseNearTokenError`] describing why the amount is rejected.
+    ///
+    /// [`FromStr`]: std::str::FromStr
+    fn parse_amount(s: &str) -> Result<NearToken, ParseNearTokenError>;
+}
+
+/// Errors returned by [`NearTokenExt::parse_amount`].
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseNearTokenError {
+    /// The amount is empty.
+    Empty,
+    /// The amount is negative.
+    Negative,
+    /// The amount is not a decimal number.
+    InvalidNumber,
+    /// The unit is neither `NEAR` nor `yoctoNEAR`.
+    InvalidUnit,
+    /// The amount has decimal places but no unit.
+    MissingUnit,
+    /// The amount has more decimal places than its unit allows: 24 for `NEAR`, none for
+    /// `yoctoNEAR`.
+    TooManyDecimals,
+    /// The amount doesn't fit in a `u128` of yoctoNEAR.
+    Overflow,
+}
+
+impl std::fmt::Display for ParseNearTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Self::Empty => "the amount is empty",
+            Self::Negative => "the amount can't be negative",
+            Self::InvalidNumber => "the amount is not a valid decimal number",
+            Self::InvalidUnit => "unknown unit, expected `NEAR` or `yoctoNEAR`",
+            Self::MissingUnit => "decimal amounts must have the `NEAR` unit",
+            Self::TooManyDecimals => "too many decimal places for the unit of the amount",
+            Self::Overflow => "the amount is too large",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for ParseNearTokenError {}
+
+impl NearTokenExt for NearToken {
+    fn to_string_with_precision(&self, decimals: u8) -> String {
+        let decimals = decimals.min(NEAR_DECIMALS);
+        let scale = 10u128.pow(u32::from(NEAR_DECIMALS - decimals));
+        let yoctonear = self.as_yoctonear();
+        // Cannot overflow: the quotient is at most `u128::MAX / 10`, unless `scale` is 1, in
+        // which case the remainder is 0.
+        let rounded = yoctonear / scale + u128::from(yoctonear % scale >= scale.div_ceil(2));
+
+        let unit = 10u128.pow(u32::from(decimals));
+        let (whole, fraction) = (rounded / unit, rounded % unit);
+        if decimals == 0 {
+            format!("{} NEAR", whole)
+        } else {
+            format!("{}.{:0>width$} NEAR", whole, fraction, width = usize::from(decimals))
+        }
+    }
+
+    fn parse_amount(s: &str) -> Result<NearToken, ParseNearTokenError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseNearTokenError::Empty);
+        }
+        if s.starts_with('-') {
+            return Err(ParseNearTokenError::Negative);
+        }
+
+        let (number, unit) =
+            s.split_at(s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len()));
+        let decimals = if unit.is_empty() {
+            None
+        } else if unit.eq_ignore_ascii_case("NEAR") {
+            Some(NEAR_DECIMALS)
+        } else if unit.eq_ignore_ascii_case("yoctoNEAR") {
+            Some(0)
+        } else {
+            return Err(ParseNearTokenError::InvalidUnit);
+        };
+
+        let number = number.trim_end();
+        let (whole, fraction) = match number.split_once('.') {
+            Some((whole, fraction)) if !fraction.is_empty() => (whole, fraction),
+            Some(_) => return Err(ParseNearTokenError::InvalidNumber),
+            None => (number, ""),
+        };
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            return Err(ParseNearTokenError::InvalidNumber);
+        }
+
+        let decimals = match decimals {
+            Some(decimals) => usize::from(decimals),
+            None if fraction.is_empty() => 0,
+            None => return Err(ParseNearTokenError::MissingUnit),
+        };
+        if fraction.len() > decimals {
+            return Err(ParseNearTokenError::TooManyDecimals);
+        }
+
+        // Both parts only contain digits, so parsing can only fail on overflow. The fraction has
+        // at most 24 digits and always fits.
+        let whole = whole.parse::<u128>().map_err(|_| ParseNearTokenError::Overflow)?;
+        let fraction = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse::<u128>().unwrap_or_default()
+                * 10u128.pow((decimals - fraction.len()) as u32)
+        };
+        whole
+            .checked_mul(10u128.pow(decimals as u32))
+            .and_then(|whole| whole.checked_add(fraction))
+            .map(NearToken::from_yoctonear)
+            .ok_or(ParseNearTokenError::Overflow)
+    }
+}
+
+// This is the "sealed trait" pattern:
+// https://rust-lang.github.io/api-guidelines/future-proofing.html
+mod seal {
+    pub trait NearTokenExtSeal {}
+
+    impl NearTokenExtSeal for super::NearToken {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NearTokenExt, ParseNearTokenError};
+    use crate::NearToken;
+
+    #[test]
+    fn sub_near_constructors() {
+        assert_eq!(NearToken::from_millinear(1_000), NearToken::from_near(1));
+        assert_eq!(NearToken::from_micronear(1_000), NearToken::from_millinear(1));
+        assert_eq!(NearToken::from_micronear(1).as_yoctonear(), 10u128.pow(18));
+    }
+
+    #[test]
+    fn exact_near_values() {
+        assert_eq!(NearToken::from_near(0).to_string_with_precision(0), "0 NEAR");
+        assert_eq!(NearToken::from_near(0).to_string_with_precision(2), "0.00 NEAR");
+        assert_eq!(NearToken::from_near(5).to_string_with_precision(0), "5 NEAR");
+        assert_eq!(NearToken::from_near(5).to_string_with_precision(3), "5.000 NEAR");
+        assert_eq!(
+            NearToken::from_near(1).to_string_with_precision(24),
+            "1.000000000000000000000000 NEAR"
+        );
+        assert_eq!(
+            NearToken::from_yoctonear(1).to_string_with_precision(30),
+            "0.000000000000000000000001 NEAR"
+        );
+    }
+
+    #[test]
+    fn rounding_at_boundary() {
+        // 0.0005 NEAR rounds up at three decimals, one yoctoNEAR less rounds down.
+        let half = NearToken::from_micronear(500);
+        assert_eq!(half.to_string_with_precision(3), "0.001 NEAR");
+        let below_half = half.saturating_sub(NearToken::from_yoctonear(1));
+        assert_eq!(below_half.to_string_with_precision(3), "0.000 NEAR");
+
+        // Rounding carries into the whole part.
+        let almost_two = NearToken::from_near(2).saturating_sub(NearToken::from_yoctonear(1));
+        assert_eq!(almost_two.to_string_with_precision(2), "2.00 NEAR");
+        assert_eq!(NearToken::from_millinear(1_500).to_string_with_precision(0), "2 NEAR");
+        assert_eq!(NearToken::from_millinear(1_499).to_string_with_precision(0), "1 NEAR");
+
+        // Amounts below the precision render as zero.
+        assert_eq!(NearToken::from_yoctonear(1).to_string_with_precision(6), "0.000000 NEAR");
+    }
+
+    #[test]
+    fn max_amount() {
+        let max = NearToken::from_yoctonear(u128::MAX);
+        assert_eq!(max.to_string_with_precision(0), "340282366920938 NEAR");
+        assert_eq!(
+            max.to_string_with_precision(24),
+            "340282366920938.463463374607431768211455 NEAR"
+        );
+    }
+
+    #[test]
+    fn parse_amount() {
+        let parse = NearToken::parse_amount;
+        assert_eq!(parse("1500000000000000000000000"), Ok(NearToken::from_millinear(1_500)));
+        assert_eq!(parse("0"), Ok(NearToken::from_yoctonear(0)));
+        assert_eq!(parse("1.5 NEAR"), Ok(NearToken::from_millinear(1_500)));
+        assert_eq!(parse("  2near "), Ok(NearToken::from_near(2)));
+        assert_eq!(parse("0.000001 Near"), Ok(NearToken::from_micronear(1)));
+        assert_eq!(parse("0.000000000000000000000001 NEAR"), Ok(NearToken::from_yoctonear(1)));
+        assert_eq!(parse("100 yoctoNEAR"), Ok(NearToken::from_yoctonear(100)));
+        assert_eq!(
+            parse("340282366920938463463374607431768211455"),
+            Ok(NearToken::from_yoctonear(u128::MAX))
+        );
+        assert_eq!(
+            parse("340282366920938.463463374607431768211455 NEAR"),
+            Ok(NearToken::from_yoctonear(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn parse_amount_errors() {
+        let parse = NearToken::parse_amount;
+        assert_eq!(parse(""), Err(ParseNearTokenError::Empty));
+        assert_eq!(parse("   "), Err(ParseNearTokenError::Empty));
+        assert_eq!(parse("-1"), Err(ParseNearTokenError::Negative));
+        assert_eq!(parse("-1.5 NEAR"), Err(ParseNearTokenError::Negative));
+        assert_eq!(parse("NEAR"), Err(ParseNearTokenError::InvalidNumber));
+        assert_eq!(parse("+1"), Err(ParseNearTokenError::InvalidNumber));
+        assert_eq!(parse("1.1.1 NEAR"), Err(ParseNearTokenError::InvalidNumber));
+        assert_eq!(parse(".5 NEAR"), Err(ParseNearTokenError::InvalidNumber));
+        assert_eq!(parse("1. NEAR"), Err(ParseNearTokenError::InvalidNumber));
+        assert_eq!(parse("1 000"), Err(ParseNearTokenError::InvalidNumber));
+        assert_eq!(parse("1 milliNEAR"), Err(ParseNearTokenError::InvalidUnit));
+        assert_eq!(parse("1 NEARS"), Err(ParseNearTokenError::InvalidUnit));
+        assert_eq!(parse("1.5"), Err(ParseNearTokenError::MissingUnit));
+        assert_eq!(
+            parse("0.0000000000000000000000001 NEAR"),
+            Err(ParseNearTokenError::TooManyDecimals)
+        );
+        assert_eq!(parse("1.5 yoctoNEAR"), Err(ParseNearTokenError::TooManyDecimals));
+        assert_eq!(
+            parse("340282366920938463463374607431768211456"),
+            Err(ParseNearTokenError::Overflow)
+        );
+        assert_eq!(parse("340282366920939 NEAR"), Err(ParseNearTokenError::Overflow));
+    }
+}
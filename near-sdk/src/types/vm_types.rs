@@ -35,6 +35,38 @@ pub enum PromiseResult {
     Failed,
 }
 
+impl PromiseResult {
+    /// Converts the result into a [`Result`], so it can be handled with `?` and combinators in
+    /// low-level callbacks instead of a `match`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_sdk::{PromiseError, PromiseResult};
+    ///
+    /// fn parse(result: PromiseResult) -> Result<u32, PromiseError> {
+    ///     let bytes = result.into_result()?;
+    ///     Ok(near_sdk::serde_json::from_slice(&bytes).unwrap())
+    /// }
+    ///
+    /// assert_eq!(parse(PromiseResult::Successful(b"7".to_vec())), Ok(7));
+    /// assert_eq!(parse(PromiseResult::Failed), Err(PromiseError::Failed));
+    /// ```
+    pub fn into_result(self) -> Result<Vec<u8>, PromiseError> {
+        match self {
+            PromiseResult::Successful(value) => Ok(value),
+            PromiseResult::Failed => Err(PromiseError::Failed),
+        }
+    }
+
+    /// Returns the value of a successful result, or [`None`] if the promise failed.
+    pub fn ok(self) -> Option<Vec<u8>> {
+        match self {
+            PromiseResult::Successful(value) => Some(value),
+            PromiseResult::Failed => None,
+        }
+    }
+}
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "unit-testing"))]
 impl From<PromiseResult> for VmPromiseResult {
     fn from(p: PromiseResult) -> Self {
@@ -49,6 +81,58 @@ impl From<PromiseResult> for VmPromiseResult {
 #[non_exhaustive]
 #[derive(Debug, PartialEq, Eq)]
 pub enum PromiseError {
+    /// Promise result failed. For the callback of a
+    /// [`promise_yield_create`](crate::env::promise_yield_create) this means that it timed out.
+    Failed,
+}
+
+/// Errors returned by [`env::promise_result_checked`](crate::env::promise_result_checked).
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq)]
+pub enum PromiseResultError {
+    /// Promise result failed.
+    Failed,
+    /// Promise succeeded, but its result is longer than the requested maximum.
+    TooLong,
+}
+
+/// Errors returned by [`env::promise_result_as`](crate::env::promise_result_as) and
+/// [`env::promise_result_as_borsh`](crate::env::promise_result_as_borsh).
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallbackError {
     /// Promise result failed.
     Failed,
+    /// Promise succeeded, but its result is longer than the requested maximum.
+    TooLong,
+    /// Promise succeeded, but its result couldn't be deserialized into the requested type.
+    Deserialize,
+}
+
+impl From<PromiseResultError> for CallbackError {
+    fn from(err: PromiseResultError) -> Self {
+        match err {
+            PromiseResultError::Failed => Self::Failed,
+            PromiseResultError::TooLong => Self::TooLong,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PromiseError, PromiseResult};
+
+    #[test]
+    fn promise_result_into_result() {
+        assert_eq!(PromiseResult::Successful(vec![1, 2]).into_result(), Ok(vec![1, 2]));
+        assert_eq!(PromiseResult::Successful(vec![]).into_result(), Ok(vec![]));
+        assert_eq!(PromiseResult::Failed.into_result(), Err(PromiseError::Failed));
+    }
+
+    #[test]
+    fn promise_result_ok() {
+        assert_eq!(PromiseResult::Successful(vec![1, 2]).ok(), Some(vec![1, 2]));
+        assert_eq!(PromiseResult::Successful(vec![]).ok(), Some(vec![]));
+        assert_eq!(PromiseResult::Failed.ok(), None);
+    }
 }
@@ -0,0 +1,81 @@
+use core::alloc::{GlobalAlloc, Layout};
+use core::arch::wasm32;
+use core::cell::UnsafeCell;
+use core::ptr;
+
+const WASM_PAGE_SIZE: usize = 64 * 1024;
+
+/// A minimal bump allocator which only ever grows the Wasm linear memory and never reuses freed
+/// memory.
+///
+/// Contract executions are short-lived and all memory is discarded when the function call ends,
+/// so a bump allocator gives fast and fully deterministic allocations at the cost of never
+/// reclaiming memory within a single call. Contracts which allocate and free large amounts of
+/// memory in a loop should use a general purpose allocator instead.
+///
+/// This allocator is set as the global allocator when the `bump_alloc` feature is enabled. It can
+/// also be configured manually with default features disabled:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: near_sdk::BumpAllocator = near_sdk::BumpAllocator::new();
+/// ```
+pub struct BumpAllocator {
+    /// Address of the next free byte, or zero if no memory has been allocated yet.
+    next: UnsafeCell<usize>,
+    /// Address of the end of the memory owned by the allocator.
+    end: UnsafeCell<usize>,
+}
+
+// SAFETY: contracts are executed on a single thread.
+unsafe impl Sync for BumpAllocator {}
+
+impl BumpAllocator {
+    /// Creates a new allocator. Memory is claimed from the end of the existing linear memory on
+    /// the first allocation.
+    pub const fn new() -> Self {
+        Self { next: UnsafeCell::new(0), end: UnsafeCell::new(0) }
+    }
+}
+
+impl Default for BumpAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for BumpAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let next = &mut *self.next.get();
+        let end = &mut *self.end.get();
+        if *end == 0 {
+            // First allocation, start after the memory which was initialized by the module.
+            *end = wasm32::memory_size(0) * WASM_PAGE_SIZE;
+            *next = *end;
+        }
+
+        let start = match next.checked_add(layout.align() - 1) {
+            Some(offset) => offset & !(layout.align() - 1),
+            None => return ptr::null_mut(),
+        };
+        let new_next = match start.checked_add(layout.size()) {
+            Some(new_next) => new_next,
+            None => return ptr::null_mut(),
+        };
+
+        if new_next > *end {
+            let pages = (new_next - *end).div_ceil(WASM_PAGE_SIZE);
+            if wasm32::memory_grow(0, pages) == usize::MAX {
+                return ptr::null_mut();
+            }
+            *end += pages * WASM_PAGE_SIZE;
+        }
+
+        *next = new_next;
+        start as *mut u8
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
+        // Memory is never reclaimed, it is released when the execution finishes.
+    }
+}
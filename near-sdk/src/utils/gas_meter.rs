@@ -0,0 +1,82 @@
+use crate::{env, Gas};
+
+/// Measures the gas used by a section of a contract method.
+///
+/// The meter records [`env::used_gas`] when it is started and reports how much gas was used since
+/// then, which includes the cost of the calls made to read it.
+///
+/// # Examples
+///
+/// ```
+/// use near_sdk::{env, utils::GasMeter};
+///
+/// let meter = GasMeter::start();
+/// env::storage_write(b"key", b"value");
+/// meter.log_elapsed("storage_write");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GasMeter {
+    start: Gas,
+}
+
+impl GasMeter {
+    /// Starts measuring from the gas used so far.
+    pub fn start() -> Self {
+        Self { start: env::used_gas() }
+    }
+
+    /// Gas used since the meter was started.
+    pub fn elapsed(&self) -> Gas {
+        env::used_gas().saturating_sub(self.start)
+    }
+
+    /// Logs the gas used since the meter was started as `"<label>: N gas"`.
+    pub fn log_elapsed(&self, label: &str) {
+        let elapsed = self.elapsed();
+        env::log_str(&format!("{}: {} gas", label, elapsed.as_gas()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GasMeter;
+    use crate::test_utils::{get_logs, VMContextBuilder};
+    use crate::{env, testing_env, Gas};
+
+    fn measure_storage_write(key: &[u8]) -> Gas {
+        let meter = GasMeter::start();
+        env::storage_write(key, b"value");
+        meter.elapsed()
+    }
+
+    #[test]
+    fn elapsed() {
+        testing_env!(VMContextBuilder::new().build());
+        let meter = GasMeter::start();
+        let idle = meter.elapsed();
+        let first = measure_storage_write(b"key1");
+        assert!(first > Gas::from_gas(0));
+        assert!(meter.elapsed() > idle.saturating_add(first));
+
+        // The mocked runtime charges the same gas for the same operations.
+        assert_eq!(measure_storage_write(b"key2"), first);
+    }
+
+    #[test]
+    fn log_elapsed() {
+        testing_env!(VMContextBuilder::new().build());
+        let before = env::used_gas();
+        let meter = GasMeter::start();
+        meter.log_elapsed("noop");
+        let after = env::used_gas();
+
+        let logs = get_logs();
+        assert_eq!(logs.len(), 1);
+        let gas: u64 = logs[0]
+            .strip_prefix("noop: ")
+            .and_then(|log| log.strip_suffix(" gas"))
+            .and_then(|gas| gas.parse().ok())
+            .unwrap();
+        assert!(gas > 0 && gas < (after.saturating_sub(before)).as_gas());
+    }
+}
@@ -6,8 +6,16 @@ mod stable_map;
 pub(crate) use self::stable_map::StableMap;
 mod cache_entry;
 pub(crate) use cache_entry::{CacheEntry, EntryState};
+mod gas_meter;
+pub use self::gas_meter::GasMeter;
+mod validators;
+pub use self::validators::{select_by_stake, validator_stakes};
+#[cfg(target_arch = "wasm32")]
+mod bump_alloc;
+#[cfg(target_arch = "wasm32")]
+pub use self::bump_alloc::BumpAllocator;
 
-use crate::{env, NearToken, PromiseResult};
+use crate::{env, AccountId, ParseAccountError, PromiseResult};
 
 /// Helper macro to log a message through [`env::log_str`].
 /// This macro can be used similar to the [`std::format`] macro.
@@ -75,6 +83,85 @@ macro_rules! require {
     };
 }
 
+/// Creates a [`&'static AccountIdRef`](crate::AccountIdRef) from a string literal, validated at
+/// compile time.
+///
+/// Unlike parsing an [`AccountId`](crate::AccountId) from a string, this has no runtime cost, so
+/// it suits hardcoded accounts like a treasury or a registry. An invalid literal fails to
+/// compile. The macro can be used in `const` items, and [`ToOwned::to_owned`] turns the result
+/// into an owned [`AccountId`](crate::AccountId) where one is needed.
+///
+/// # Examples
+///
+/// ```
+/// use near_sdk::{account_id, AccountId, AccountIdRef};
+///
+/// const TREASURY: &AccountIdRef = account_id!("treasury.near");
+///
+/// let owner: AccountId = account_id!("alice.near").to_owned();
+/// assert_eq!(owner, "alice.near".parse::<AccountId>().unwrap());
+/// assert_eq!(TREASURY, "treasury.near");
+/// ```
+///
+/// ```compile_fail
+/// let invalid = near_sdk::account_id!("Alice.near");
+/// ```
+#[macro_export]
+macro_rules! account_id {
+    ($id:literal $(,)?) => {{
+        const ACCOUNT_ID: &$crate::AccountIdRef = $crate::AccountIdRef::new_or_panic($id);
+        ACCOUNT_ID
+    }};
+}
+
+/// Helper macro requiring that exactly 1 yoctoNEAR is attached to the call.
+///
+/// This is the usual confirmation that a method changing ownership of assets was called with a
+/// full access key, and does the same as [`assert_one_yocto`] without having to import it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use near_sdk::require_one_yocto;
+///
+/// # fn main() {
+/// require_one_yocto!();
+/// # }
+/// ```
+#[macro_export]
+macro_rules! require_one_yocto {
+    () => {
+        $crate::require!(
+            $crate::env::attached_deposit() == $crate::NearToken::from_yoctonear(1),
+            "Requires attached deposit of exactly 1 yoctoNEAR"
+        )
+    };
+}
+
+/// Helper macro requiring that at least `amount` of [`NearToken`](crate::NearToken) is attached to the call.
+///
+/// # Examples
+///
+/// ```no_run
+/// use near_sdk::{require_min_deposit, NearToken};
+///
+/// # fn main() {
+/// require_min_deposit!(NearToken::from_millinear(1));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! require_min_deposit {
+    ($amount:expr $(,)?) => {{
+        let amount: $crate::NearToken = $amount;
+        if $crate::env::attached_deposit() < amount {
+            $crate::env::panic_str(&::std::format!(
+                "Requires attached deposit of at least {} yoctoNEAR",
+                amount.as_yoctonear()
+            ))
+        }
+    }};
+}
+
 /// Assert that predecessor_account_id == current_account_id, meaning contract called itself.
 pub fn assert_self() {
     require!(env::predecessor_account_id() == env::current_account_id(), "Method is private");
@@ -82,10 +169,7 @@ pub fn assert_self() {
 
 /// Assert that 1 yoctoNEAR was attached.
 pub fn assert_one_yocto() {
-    require!(
-        env::attached_deposit() == NearToken::from_yoctonear(1),
-        "Requires attached deposit of exactly 1 yoctoNEAR"
-    )
+    require_one_yocto!()
 }
 
 /// Returns true if promise was successful.
@@ -107,6 +191,28 @@ pub fn promise_result_as_success() -> Option<Vec<u8>> {
     }
 }
 
+/// Checks that all of the given strings are valid account ids, without allocating an
+/// [`AccountId`] for each of them. Returns the index of the first invalid one along with the reason
+/// it is invalid.
+///
+/// Uses [`AccountId::validate`].
+///
+/// # Examples
+/// ```
+/// use near_sdk::utils::validate_account_ids;
+///
+/// assert!(validate_account_ids(["alice.near", "bob.near"]).is_ok());
+/// assert_eq!(validate_account_ids(["alice.near", "Bob.near"]).unwrap_err().0, 1);
+/// ```
+pub fn validate_account_ids<'a, I>(ids: I) -> Result<(), (usize, ParseAccountError)>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    ids.into_iter()
+        .enumerate()
+        .try_for_each(|(index, id)| AccountId::validate(id).map_err(|err| (index, err)))
+}
+
 /// Deprecated helper function which used to generate code to initialize the [`GlobalAllocator`].
 /// This is now initialized by default. Disable `wee_alloc` feature to configure manually.
 ///
@@ -124,7 +230,8 @@ macro_rules! setup_alloc {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::get_logs;
+    use crate::test_utils::{get_logs, VMContextBuilder};
+    use crate::{testing_env, NearToken};
 
     #[test]
     fn test_log_simple() {
@@ -139,4 +246,55 @@ mod tests {
 
         assert_eq!(get_logs(), vec!["hello user_name (25)".to_string()]);
     }
+
+    #[test]
+    fn test_require_one_yocto() {
+        testing_env!(VMContextBuilder::new()
+            .attached_deposit(NearToken::from_yoctonear(1))
+            .build());
+        require_one_yocto!();
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn test_require_one_yocto_panics() {
+        testing_env!(VMContextBuilder::new()
+            .attached_deposit(NearToken::from_yoctonear(2))
+            .build());
+        require_one_yocto!();
+    }
+
+    #[test]
+    fn test_require_min_deposit() {
+        testing_env!(VMContextBuilder::new()
+            .attached_deposit(NearToken::from_millinear(1))
+            .build());
+        require_min_deposit!(NearToken::from_millinear(1));
+        require_min_deposit!(NearToken::from_yoctonear(0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Requires attached deposit of at least 1000000000000000000000 yoctoNEAR"
+    )]
+    fn test_require_min_deposit_panics() {
+        testing_env!(VMContextBuilder::new()
+            .attached_deposit(NearToken::from_yoctonear(1))
+            .build());
+        require_min_deposit!(NearToken::from_millinear(1));
+    }
+
+    #[test]
+    fn test_validate_account_ids() {
+        use super::validate_account_ids;
+        use near_account_id::ParseErrorKind;
+
+        assert_eq!(validate_account_ids(["alice.near", "bob.near", "near"]), Ok(()));
+        assert_eq!(validate_account_ids(Vec::<&str>::new()), Ok(()));
+
+        let ids = ["alice.near".to_string(), "bob..near".to_string(), "a".to_string()];
+        let (index, err) = validate_account_ids(ids.iter().map(String::as_str)).unwrap_err();
+        assert_eq!(index, 1);
+        assert_eq!(err.kind(), &ParseErrorKind::RedundantSeparator);
+    }
 }
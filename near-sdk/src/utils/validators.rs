@@ -0,0 +1,122 @@
+use crate::{env, AccountId, NearToken};
+
+/// Returns the current stake of each of the `candidates` that is a validator in the current epoch.
+///
+/// The runtime does not expose the validator set itself, so the accounts to look up must be known
+/// in advance. Candidates without stake are skipped, and the order of `candidates` is preserved.
+///
+/// # Examples
+///
+/// ```
+/// use near_sdk::{utils::validator_stakes, AccountId};
+///
+/// let candidates: Vec<AccountId> = vec!["alice.near".parse().unwrap(), "bob.near".parse().unwrap()];
+/// for (account_id, stake) in validator_stakes(&candidates) {
+///     near_sdk::log!("{} stakes {}", account_id, stake);
+/// }
+/// ```
+pub fn validator_stakes<'a, I>(candidates: I) -> Vec<(AccountId, NearToken)>
+where
+    I: IntoIterator<Item = &'a AccountId>,
+{
+    candidates
+        .into_iter()
+        .filter_map(|account_id| {
+            let stake = env::validator_stake(account_id);
+            (!stake.is_zero()).then(|| (account_id.clone(), stake))
+        })
+        .collect()
+}
+
+/// Picks one of `stakes` with a probability proportional to its stake, e.g. as returned by
+/// [`validator_stakes`].
+///
+/// `random` is reduced modulo the total stake, so it should be uniformly distributed over the
+/// `u128` range, e.g. derived from [`env::random_seed_array`]. Returns [`None`] if the total stake
+/// is zero.
+///
+/// # Examples
+///
+/// ```
+/// use near_sdk::{env, utils::select_by_stake, AccountId, NearToken};
+///
+/// let stakes: Vec<(AccountId, NearToken)> = vec![
+///     ("alice.near".parse().unwrap(), NearToken::from_near(100)),
+///     ("bob.near".parse().unwrap(), NearToken::from_near(300)),
+/// ];
+/// let seed = env::random_seed_array();
+/// let random = u128::from_le_bytes(seed[..16].try_into().unwrap());
+/// assert!(select_by_stake(&stakes, random).is_some());
+/// ```
+pub fn select_by_stake(stakes: &[(AccountId, NearToken)], random: u128) -> Option<&AccountId> {
+    let total = stakes
+        .iter()
+        .try_fold(0u128, |total, (_, stake)| total.checked_add(stake.as_yoctonear()))
+        .unwrap_or_else(|| env::panic_str("Total stake overflows u128"));
+    if total == 0 {
+        return None;
+    }
+    let mut point = random % total;
+    stakes.iter().find_map(|(account_id, stake)| {
+        if point < stake.as_yoctonear() {
+            Some(account_id)
+        } else {
+            point -= stake.as_yoctonear();
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::VMContextBuilder;
+    use crate::{test_vm_config, testing_env, RuntimeFeesConfig};
+    use std::collections::HashMap;
+
+    fn id(account_id: &str) -> AccountId {
+        account_id.parse().unwrap()
+    }
+
+    #[test]
+    fn test_validator_stakes() {
+        let validators = HashMap::from([
+            ("alice.near".to_string(), NearToken::from_near(100)),
+            ("bob.near".to_string(), NearToken::from_near(300)),
+        ]);
+        testing_env!(
+            VMContextBuilder::new().build(),
+            test_vm_config(),
+            RuntimeFeesConfig::test(),
+            validators
+        );
+
+        let candidates = [id("bob.near"), id("carol.near"), id("alice.near")];
+        assert_eq!(
+            validator_stakes(&candidates),
+            vec![
+                (id("bob.near"), NearToken::from_near(300)),
+                (id("alice.near"), NearToken::from_near(100)),
+            ]
+        );
+        assert!(validator_stakes(&[id("carol.near")]).is_empty());
+    }
+
+    #[test]
+    fn test_select_by_stake() {
+        let stakes = vec![
+            (id("alice.near"), NearToken::from_yoctonear(1)),
+            (id("bob.near"), NearToken::from_yoctonear(0)),
+            (id("carol.near"), NearToken::from_yoctonear(3)),
+        ];
+
+        assert_eq!(select_by_stake(&stakes, 0), Some(&id("alice.near")));
+        assert_eq!(select_by_stake(&stakes, 1), Some(&id("carol.near")));
+        assert_eq!(select_by_stake(&stakes, 3), Some(&id("carol.near")));
+        assert_eq!(select_by_stake(&stakes, 4), Some(&id("alice.near")));
+        assert_eq!(select_by_stake(&stakes, u128::MAX), Some(&id("carol.near")));
+
+        assert_eq!(select_by_stake(&[], 7), None);
+        assert_eq!(select_by_stake(&stakes[1..2], 7), None);
+    }
+}
@@ -0,0 +1,60 @@
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::store::LookupMap;
+use near_sdk::{BorshStorageKey, IntoStorageKey};
+
+#[derive(BorshSerialize, BorshStorageKey)]
+#[borsh(crate = "near_sdk::borsh")]
+enum StorageKeyV1 {
+    #[storage_key(prefix = b"v1")]
+    Accounts,
+    Implicit,
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+#[borsh(crate = "near_sdk::borsh")]
+enum StorageKeyV2 {
+    #[storage_key(prefix = b"v2/acc")]
+    Accounts,
+    #[storage_key(prefix = "v2/named")]
+    Named { id: u32, name: String },
+    #[storage_key(prefix = b"v2/tuple")]
+    Tuple(u8, u8),
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+#[borsh(crate = "near_sdk::borsh", use_discriminant = true)]
+enum StorageKeyV3 {
+    #[storage_key(prefix = b"v3")]
+    Accounts = 1,
+    Implicit = 7,
+}
+
+#[test]
+fn test_storage_key_prefix() {
+    assert_eq!(StorageKeyV1::Accounts.into_storage_key(), b"v1");
+    assert_eq!(StorageKeyV1::Implicit.into_storage_key(), [1]);
+    assert_eq!(StorageKeyV2::Accounts.into_storage_key(), b"v2/acc");
+    assert_eq!(
+        StorageKeyV2::Named { id: 1, name: "a".to_string() }.into_storage_key(),
+        b"v2/named\x01\x00\x00\x00\x01\x00\x00\x00a"
+    );
+    assert_eq!(StorageKeyV2::Tuple(3, 4).into_storage_key(), b"v2/tuple\x03\x04");
+    assert_eq!(StorageKeyV3::Accounts.into_storage_key(), b"v3");
+    assert_eq!(StorageKeyV3::Implicit.into_storage_key(), [7]);
+}
+
+#[test]
+fn test_explicit_prefixes_do_not_alias() {
+    // Both variants have discriminant 0, so they would share the key `[0]` without a prefix.
+    let mut v1: LookupMap<u32, String> = LookupMap::new(StorageKeyV1::Accounts);
+    v1.insert(1, "v1".to_string());
+    v1.flush();
+
+    let mut v2: LookupMap<u32, String> = LookupMap::new(StorageKeyV2::Accounts);
+    assert!(!v2.contains_key(&1));
+    v2.insert(1, "v2".to_string());
+    v2.flush();
+
+    let v1: LookupMap<u32, String> = LookupMap::new(StorageKeyV1::Accounts);
+    assert_eq!(v1.get(&1).map(String::as_str), Some("v1"));
+}
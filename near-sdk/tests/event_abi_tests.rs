@@ -0,0 +1,110 @@
+use near_sdk::__private::ChunkedAbiEntry;
+use near_sdk::serde_json::{json, Value};
+use near_sdk::{near, AccountId};
+
+#[near(event_json(standard = "test_standard"))]
+pub enum SwapEvents<'a> {
+    #[event_version("1.0.0")]
+    Swap { token_in: AccountId, token_out: AccountId, amount_in: u128 },
+
+    #[event_version("2.0.0")]
+    Memo(&'a str),
+
+    #[event_version("3.0.0")]
+    Paused,
+}
+
+pub mod v1 {
+    use near_sdk::near;
+
+    #[near(event_json(standard = "test_standard"))]
+    pub enum PauseEvent {
+        #[event_version("1.0.0")]
+        Paused,
+    }
+}
+
+pub mod v2 {
+    use near_sdk::near;
+
+    #[near(event_json(standard = "test_standard"))]
+    pub enum PauseEvent {
+        #[event_version("2.0.0")]
+        Paused,
+    }
+}
+
+#[allow(improper_ctypes_definitions)]
+fn event_abi(abi: extern "C" fn() -> (*const u8, usize)) -> Value {
+    let (ptr, len) = abi();
+    let chunk = unsafe { std::slice::from_raw_parts(ptr, len) };
+    // The chunk still has to be readable by the tooling combining the ABI.
+    let entry: ChunkedAbiEntry = near_sdk::serde_json::from_slice(chunk).unwrap();
+    assert!(entry.body.functions.is_empty());
+    near_sdk::serde_json::from_slice(chunk).unwrap()
+}
+
+fn event_schema(abi: &Value, name: &str) -> Value {
+    abi["root_schema"]["definitions"][name].clone()
+}
+
+#[test]
+fn test_event_schema_in_abi() {
+    let abi = event_abi(SwapEvents::__near_abi_event);
+    let schema = event_schema(&abi, "SwapEvents");
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 3);
+
+    let expected = [("swap", "1.0.0"), ("memo", "2.0.0"), ("paused", "3.0.0")];
+    for (variant, (event, version)) in variants.iter().zip(expected) {
+        let properties = &variant["properties"];
+        assert_eq!(properties["standard"], json!({ "type": "string", "const": "test_standard" }));
+        assert_eq!(properties["version"], json!({ "type": "string", "const": version }));
+        assert_eq!(properties["event"]["enum"], json!([event]));
+
+        let required = variant["required"].as_array().unwrap();
+        for field in ["standard", "version", "event"] {
+            assert!(required.contains(&Value::from(field)));
+        }
+    }
+
+    let swap_data = &variants[0]["properties"]["data"];
+    assert_eq!(
+        swap_data["required"],
+        json!(["amount_in", "token_in", "token_out"]),
+        "unexpected swap data schema: {}",
+        swap_data
+    );
+    assert_eq!(variants[1]["properties"]["data"]["type"], "string");
+    assert!(variants[2]["properties"].get("data").is_none());
+
+    let events = abi["events"].as_array().unwrap();
+    assert_eq!(events.len(), 3);
+    for (event, (name, version)) in events.iter().zip(expected) {
+        assert_eq!(event["standard"], "test_standard");
+        assert_eq!(event["version"], version);
+        assert_eq!(event["event"], name);
+    }
+    assert_eq!(events[0]["data"], *swap_data);
+    assert_eq!(events[1]["data"], json!({ "type": "string" }));
+    assert!(events[2].get("data").is_none());
+}
+
+#[test]
+fn test_same_name_event_schemas_in_abi() {
+    for (abi, version) in [
+        (v1::PauseEvent::__near_abi_event as extern "C" fn() -> _, "1.0.0"),
+        (v2::PauseEvent::__near_abi_event, "2.0.0"),
+    ] {
+        let abi = event_abi(abi);
+        assert_eq!(
+            abi["events"],
+            json!([{ "standard": "test_standard", "version": version, "event": "paused" }])
+        );
+        let schema = event_schema(&abi, "PauseEvent");
+        let properties = &schema["oneOf"][0]["properties"];
+        assert_eq!(properties["standard"]["const"], "test_standard");
+        assert_eq!(properties["version"]["const"], version);
+        assert_eq!(properties["event"]["enum"], json!(["paused"]));
+    }
+}
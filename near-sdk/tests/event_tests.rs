@@ -1,4 +1,5 @@
-use near_sdk::test_utils::get_logs;
+use near_sdk::serde_json::json;
+use near_sdk::test_utils::{get_events, get_logs};
 use near_sdk::{near, AccountId};
 
 #[near(event_json(standard = "test_standard"))]
@@ -78,3 +79,38 @@ fn test_json_emit() {
         r#"EVENT_JSON:{"standard":"another_standard","version":"1.0.0","event":"test"}"#
     );
 }
+
+#[test]
+fn test_get_events() {
+    near_sdk::log!("not an event");
+    TestEvents::Swap {
+        token_in: "wrap.near".parse().unwrap(),
+        token_out: "test.near".parse().unwrap(),
+        amount_in: 100,
+        amount_out: 200,
+        test: 1u8,
+    }
+    .emit();
+    TestEvents::EmptyEvent::<String>.emit();
+
+    let events = get_events();
+    assert_eq!(events.len(), 2);
+
+    assert_eq!(events[0]["standard"], "test_standard");
+    assert_eq!(events[0]["version"], "1.0.0");
+    assert_eq!(events[0]["event"], "swap");
+    assert_eq!(
+        events[0]["data"],
+        json!({
+            "token_in": "wrap.near",
+            "token_out": "test.near",
+            "amount_in": 100,
+            "amount_out": 200,
+            "test": 1,
+        })
+    );
+    assert_eq!(
+        events[1],
+        json!({ "standard": "test_standard", "version": "3.0.0", "event": "empty_event" })
+    );
+}
@@ -0,0 +1,86 @@
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::{accounts, get_created_receipts, VMContextBuilder};
+use near_sdk::{ext_contract, near, testing_env, Gas, Promise, PromiseError, PromiseOrValue};
+
+#[ext_contract(ext_calculator)]
+pub trait Calculator {
+    #[gas(Gas::from_tgas(10))]
+    fn mult(&self, a: u64, b: u64) -> u128;
+
+    fn sum(&self, a: u128, b: u128) -> u128;
+
+    fn sum_later(&self, a: u128, b: u128) -> PromiseOrValue<u128>;
+
+    fn reset(&mut self);
+}
+
+#[near(contract_state)]
+#[derive(Default)]
+pub struct Contract {
+    last_result: Option<u128>,
+}
+
+#[near]
+impl Contract {
+    pub fn mult_and_store(&mut self, a: u64, b: u64) -> Promise {
+        ext_calculator::ext(accounts(1)).mult(a, b).then(Self::ext(accounts(0)).on_mult(a))
+    }
+
+    #[private]
+    pub fn on_mult(
+        &mut self,
+        a: u64,
+        #[callback_result] result: Result<ext_calculator::returns::mult, PromiseError>,
+    ) -> u128 {
+        let result = result.unwrap_or(a.into());
+        self.last_result = Some(result);
+        result
+    }
+}
+
+fn prepaid_gas() -> Vec<(String, Gas)> {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| receipt.actions)
+        .filter_map(|action| match action {
+            MockAction::FunctionCallWeight { method_name, prepaid_gas, .. } => {
+                Some((String::from_utf8(method_name).unwrap(), prepaid_gas))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_ext_static_gas() {
+    testing_env!(VMContextBuilder::new().build());
+
+    let _ = ext_calculator::ext(accounts(1)).mult(2, 3);
+    let _ = ext_calculator::ext(accounts(1)).with_static_gas(Gas::from_tgas(20)).mult(2, 3);
+    let _ = ext_calculator::ext(accounts(1)).with_static_gas(Gas::from_gas(0)).mult(2, 3);
+    let _ = ext_calculator::ext(accounts(1)).sum(2, 3);
+
+    assert_eq!(
+        prepaid_gas(),
+        vec![
+            ("mult".to_string(), Gas::from_tgas(10)),
+            ("mult".to_string(), Gas::from_tgas(20)),
+            ("mult".to_string(), Gas::from_gas(0)),
+            ("sum".to_string(), Gas::from_gas(0)),
+        ]
+    );
+}
+
+#[test]
+fn test_ext_return_types() {
+    let _: ext_calculator::returns::sum = 5u128;
+    let _: ext_calculator::returns::sum_later = 5u128;
+    let _: ext_calculator::returns::reset = ();
+
+    testing_env!(VMContextBuilder::new().build());
+    let mut contract = Contract::default();
+    let _ = contract.mult_and_store(2, 3);
+    assert_eq!(contract.on_mult(2, Ok(6)), 6);
+    assert_eq!(contract.on_mult(2, Err(PromiseError::Failed)), 2);
+    assert_eq!(contract.last_result, Some(2));
+}
@@ -0,0 +1,64 @@
+use near_sdk::FunctionError;
+use std::fmt;
+
+#[derive(FunctionError)]
+enum CodedError {
+    #[error_code(1)]
+    NotFound,
+    #[error_code(42)]
+    Banned { account_id: String },
+    #[error_code(7)]
+    Custom(String),
+}
+
+impl fmt::Display for CodedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodedError::NotFound => write!(f, "not found"),
+            CodedError::Banned { account_id } => write!(f, "account {} is banned", account_id),
+            CodedError::Custom(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+#[derive(FunctionError)]
+#[error_code(500)]
+struct CodedStruct;
+
+impl fmt::Display for CodedStruct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "internal error")
+    }
+}
+
+#[test]
+fn test_error_codes() {
+    assert_eq!(CodedError::NotFound.code(), 1);
+    assert_eq!(CodedError::Banned { account_id: "bob.near".to_string() }.code(), 42);
+    assert_eq!(CodedError::Custom("oops".to_string()).code(), 7);
+    assert_eq!(CodedStruct.code(), 500);
+}
+
+#[test]
+#[should_panic(expected = "E1: not found")]
+fn test_panic_unit_variant() {
+    CodedError::NotFound.panic();
+}
+
+#[test]
+#[should_panic(expected = "E42: account bob.near is banned")]
+fn test_panic_struct_variant() {
+    CodedError::Banned { account_id: "bob.near".to_string() }.panic();
+}
+
+#[test]
+#[should_panic(expected = "E7: oops")]
+fn test_panic_tuple_variant() {
+    CodedError::Custom("oops".to_string()).panic();
+}
+
+#[test]
+#[should_panic(expected = "E500: internal error")]
+fn test_panic_struct() {
+    CodedStruct.panic();
+}
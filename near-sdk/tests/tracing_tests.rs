@@ -0,0 +1,69 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use near_sdk::test_utils::{get_logs, VMContextBuilder};
+use near_sdk::{env, log, testing_env};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// Collects the target, level and message of every event.
+#[derive(Clone, Default)]
+struct Collector(Arc<Mutex<Vec<(String, Level, String)>>>);
+
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+impl Subscriber for Collector {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = MessageVisitor(String::new());
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.0.lock().unwrap().push((metadata.target().to_string(), *metadata.level(), visitor.0));
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test_logs_are_traced() {
+    testing_env!(VMContextBuilder::new().build());
+    let collector = Collector::default();
+
+    tracing::subscriber::with_default(collector.clone(), || {
+        env::log_str("hello");
+        log!("number: {}", 5);
+    });
+
+    let events = collector.0.lock().unwrap().clone();
+    assert_eq!(
+        events,
+        [
+            ("near_sdk::log".to_string(), Level::INFO, "hello".to_string()),
+            ("near_sdk::log".to_string(), Level::INFO, "number: 5".to_string()),
+        ]
+    );
+    // Logs are still recorded by the mocked blockchain.
+    assert_eq!(get_logs(), ["hello", "number: 5"]);
+}